@@ -13,6 +13,10 @@
 // limitations under the License.
 
 //! Identifies legal game actions for a given game state.
+//!
+//! This module is the single authoritative implementation of legal action
+//! logic. The server's illegal action responses and the AI (via
+//! `ai_game_integration`, which re-exports this module) both use it.
 
 use std::iter;
 
@@ -51,12 +55,28 @@ pub fn evaluate<'a>(
 
     if let Some(prompt) = &game.player(side).prompt {
         return Ok(Box::new(
-            prompt.responses.iter().map(|prompt| UserAction::PromptAction(*prompt)),
+            prompt
+                .responses
+                .iter()
+                .map(|prompt| UserAction::PromptAction(*prompt))
+                .chain(prompt.cancellable.then_some(UserAction::CancelPrompt)),
         ));
     }
 
-    if let Some(actions) = raids::current_actions(game, side).expect("Current Actions") {
-        return Ok(Box::new(actions.into_iter().map(UserAction::PromptAction)));
+    if let Some(actions) = raids::current_actions(game, side)? {
+        let responses = flags::in_raid_activation_window(game, side).then(|| {
+            game.cards(side).iter().filter(|card| card.position().in_play()).flat_map(move |card| {
+                rules::card_definition(game, card.id)
+                    .ability_ids(card.id)
+                    .flat_map(move |ability_id| legal_ability_actions(game, side, ability_id))
+            })
+        });
+        return Ok(Box::new(
+            actions
+                .into_iter()
+                .map(UserAction::PromptAction)
+                .chain(responses.into_iter().flatten()),
+        ));
     }
 
     if flags::in_main_phase(game, side) {
@@ -72,8 +92,9 @@ pub fn evaluate<'a>(
                         .map(UserAction::LevelUpRoom),
                 )
                 .chain(game.hand(side).flat_map(move |c| legal_card_actions(game, side, c.id)))
-                .chain(flags::can_take_draw_card_action(game, side).then(|| UserAction::DrawCard))
-                .chain(flags::can_take_gain_mana_action(game, side).then(|| UserAction::GainMana)),
+                .chain(flags::can_take_draw_card_action(game, side).then_some(UserAction::DrawCard))
+                .chain(flags::can_take_gain_mana_action(game, side).then_some(UserAction::GainMana))
+                .chain(flags::can_take_end_turn_action(game, side).then_some(UserAction::EndTurn)),
         ))
     } else {
         fail!("Error: player cannot currently act")
@@ -103,6 +124,18 @@ fn legal_card_actions(
         None
     };
 
+    let play_on_card = if target_kind == CardTargetKind::Card {
+        Some(game.all_cards().filter_map(move |card| {
+            if flags::can_take_play_card_action(game, side, card_id, CardTarget::Card(card.id)) {
+                Some(UserAction::PlayCard(card_id, CardTarget::Card(card.id)))
+            } else {
+                None
+            }
+        }))
+    } else {
+        None
+    };
+
     let play_card = if target_kind == CardTargetKind::None
         && flags::can_take_play_card_action(game, side, card_id, CardTarget::None)
     {
@@ -115,7 +148,12 @@ fn legal_card_actions(
         .ability_ids(card_id)
         .flat_map(move |ability_id| legal_ability_actions(game, side, ability_id));
 
-    play_in_room.into_iter().flatten().chain(play_card.into_iter().flatten()).chain(activated)
+    play_in_room
+        .into_iter()
+        .flatten()
+        .chain(play_on_card.into_iter().flatten())
+        .chain(play_card.into_iter().flatten())
+        .chain(activated)
 }
 
 /// Builds an iterator over all possible 'activate ability' actions for the
@@ -128,6 +166,7 @@ fn legal_ability_actions(
     let ability = rules::ability_definition(game, ability_id);
     let mut activate = None;
     let mut target_rooms = None;
+    let mut target_cards = None;
 
     if let AbilityType::Activated(_, targeting) = &ability.ability_type {
         match targeting {
@@ -152,8 +191,26 @@ fn legal_ability_actions(
                     }
                 }))
             }
+            TargetRequirement::TargetCard(_) => {
+                target_cards = Some(game.all_cards().filter_map(move |card| {
+                    if flags::can_take_activate_ability_action(
+                        game,
+                        side,
+                        ability_id,
+                        CardTarget::Card(card.id),
+                    ) {
+                        Some(UserAction::ActivateAbility(ability_id, CardTarget::Card(card.id)))
+                    } else {
+                        None
+                    }
+                }))
+            }
         }
     }
 
-    activate.into_iter().flatten().chain(target_rooms.into_iter().flatten())
+    activate
+        .into_iter()
+        .flatten()
+        .chain(target_rooms.into_iter().flatten())
+        .chain(target_cards.into_iter().flatten())
 }
@@ -18,27 +18,85 @@
 
 pub mod legal_actions;
 
+use std::collections::HashSet;
+use std::mem;
+use std::time::Duration;
+
 use anyhow::Result;
 use data::card_definition::AbilityType;
 use data::card_state::CardPosition;
 use data::delegates::{
     AbilityActivated, ActivateAbilityEvent, CardPlayed, CastCardEvent, DrawCardActionEvent,
+    RaidOutcome, RoomChosen, RoomChosenEvent,
+};
+use data::game::{
+    GamePhase, GameState, ManaPayment, ManaSource, MulliganDecision, MAXIMUM_MULLIGAN_SELECTION,
+};
+use data::game_actions::{
+    CardTarget, CardTargetKind, DamageAllocation, GamePrompt, PendingPlay, PromptAction,
+    PromptContext, UserAction,
 };
-use data::game::{GamePhase, GameState, MulliganDecision};
-use data::game_actions::{CardTarget, PromptAction, UserAction};
-use data::primitives::{AbilityId, CardId, RoomId, Side};
-use data::updates::{GameUpdate, InitiatedBy};
+use data::primitives::{AbilityId, CardId, ManaValue, RoomId, Side};
+use data::updates::{GameUpdate, InitiatedBy, Updates};
 use raids::RaidDataExt;
 use rules::mana::ManaPurpose;
-use rules::{card_prompt, dispatch, flags, mana, mutations, queries};
+use rules::{card_prompt, constants, dispatch, flags, mana, mutations, queries};
 use tracing::{info, instrument};
 use with_error::{fail, verify, WithError};
 
+/// Largest value which can be chosen for X when playing a card with a variable
+/// mana cost.
+const MAXIMUM_X_VALUE: ManaValue = 10;
+
 /// Top level dispatch function responsible for mutating [GameState] in response
 /// to all [UserAction]s
+///
+/// Actions are transactional: if any step returns an error, the [GameState] is
+/// restored from a snapshot taken before the action. Simulations, which do not
+/// track updates, skip this copy and are expected to discard a game which
+/// returned an error.
+///
+/// Games which track updates for display keep the same snapshot after each
+/// successful action so that it can later be reverted via [UserAction::Undo].
+/// The undo history is cleared instead when an action ends the turn or
+/// reveals hidden information.
 pub fn handle_user_action(game: &mut GameState, user_side: Side, action: UserAction) -> Result<()> {
+    if game.updates.state == Updates::Ignore {
+        return apply_user_action(game, user_side, action);
+    }
+
+    let record_undo = !matches!(action, UserAction::Debug(_) | UserAction::Undo);
+    let snapshot = game.clone_for_simulation();
+    let update_count = game.updates.steps.len();
+    if let Err(error) = apply_user_action(game, user_side, action) {
+        // The snapshot omits undo history and updates, neither of which is
+        // modified by a failed action except for updates recorded since the
+        // snapshot was taken.
+        let history = mem::take(&mut game.undo_history);
+        let mut updates = mem::take(&mut game.updates);
+        updates.steps.truncate(update_count);
+        *game = snapshot;
+        game.undo_history = history;
+        game.updates = updates;
+        return Err(error);
+    }
+
+    if game.data.turn != snapshot.data.turn || queries::reveals_hidden_information(&snapshot, game)
+    {
+        // Earlier actions cannot be undone once a turn has ended or once
+        // hidden information has been revealed.
+        game.undo_history.clear();
+    } else if record_undo {
+        game.push_undo_step(user_side, snapshot);
+    }
+    Ok(())
+}
+
+fn apply_user_action(game: &mut GameState, user_side: Side, action: UserAction) -> Result<()> {
     match action {
         UserAction::Debug(_) => fail!("Rules engine does not handle debug actions!"),
+        UserAction::SideboardSwap(_) => fail!("Rules engine does not handle sideboard swaps!"),
+        UserAction::CancelNewGame => fail!("Rules engine does not handle new game requests!"),
         UserAction::PromptAction(prompt_action) => {
             handle_prompt_action(game, user_side, prompt_action)
         }
@@ -53,6 +111,10 @@ pub fn handle_user_action(game: &mut GameState, user_side: Side, action: UserAct
         }
         UserAction::LevelUpRoom(room_id) => level_up_room_action(game, user_side, room_id),
         UserAction::SpendActionPoint => spend_action_point_action(game, user_side),
+        UserAction::EndTurn => end_turn_action(game, user_side),
+        UserAction::CancelPrompt => cancel_prompt_action(game, user_side),
+        UserAction::Resign => resign_action(game, user_side),
+        UserAction::Undo => undo_action(game, user_side),
     }
 }
 
@@ -71,6 +133,182 @@ pub fn can_take_action(game: &GameState, side: Side) -> bool {
     }
 }
 
+/// Returns true if the indicated player's turn timer is currently counting
+/// down, i.e. if the game is underway and they are able to take an action.
+pub fn turn_timer_running(game: &GameState, side: Side) -> bool {
+    game.data.turn_timer.is_some()
+        && matches!(game.data.phase, GamePhase::Play)
+        && can_take_action(game, side)
+}
+
+/// Keeps the opening hand of any player who has not made a mulligan decision
+/// once [data::game::GameConfiguration::mulligan_timeout] has elapsed since
+/// opening hands were dealt. `now` is the time since the UNIX epoch.
+///
+/// Returns true if any decision was made on a player's behalf.
+pub fn handle_mulligan_timeout(game: &mut GameState, now: Duration) -> Result<bool> {
+    let deadline = match (&game.data.phase, game.data.config.mulligan_timeout) {
+        (GamePhase::ResolveMulligans(mulligans), Some(timeout)) => {
+            mulligans.started_at.map(|started_at| started_at + timeout)
+        }
+        _ => None,
+    };
+    if deadline.is_none_or(|deadline| now < deadline) {
+        return Ok(false);
+    }
+
+    let mut resolved = false;
+    for side in enum_iterator::all::<Side>() {
+        if flags::can_make_mulligan_decision(game, side) {
+            info!(?side, "mulligan_timeout");
+            handle_mulligan_decision(game, side, MulliganDecision::Keep)?;
+            resolved = true;
+        }
+    }
+    Ok(resolved)
+}
+
+/// Returns the player who is currently being asked to respond to a prompt,
+/// along with that prompt, if any.
+fn pending_prompt(game: &GameState) -> Result<Option<(Side, GamePrompt)>> {
+    if !matches!(game.data.phase, GamePhase::Play) {
+        return Ok(None);
+    }
+
+    for side in enum_iterator::all::<Side>() {
+        if let Some(prompt) = &game.player(side).prompt {
+            return Ok(Some((side, prompt.clone())));
+        }
+        if let Some(prompt) = raids::current_prompt(game, side)? {
+            return Ok(Some((side, prompt)));
+        }
+    }
+    Ok(None)
+}
+
+/// Restarts the [data::game::GameConfiguration::prompt_timeout] countdown for
+/// the currently pending prompt, if any. Invoked after each action so that
+/// every new prompt receives the full timeout.
+pub fn restart_prompt_timer(game: &mut GameState, now: Duration) -> Result<()> {
+    game.data.prompt_shown_at = pending_prompt(game)?.map(|_| now);
+    Ok(())
+}
+
+/// Resolves the pending prompt on a player's behalf once
+/// [data::game::GameConfiguration::prompt_timeout] has elapsed since it was
+/// shown. The prompt's [GamePrompt::safe_default] response is chosen if it has
+/// one, otherwise cancellable prompts are cancelled. All other prompts continue
+/// to wait for the player. `now` is the time since the UNIX epoch.
+///
+/// Returns true if a prompt was resolved on a player's behalf.
+pub fn handle_prompt_timeout(game: &mut GameState, now: Duration) -> Result<bool> {
+    let Some(timeout) = game.data.config.prompt_timeout else {
+        return Ok(false);
+    };
+    let Some((side, prompt)) = pending_prompt(game)? else {
+        game.data.prompt_shown_at = None;
+        return Ok(false);
+    };
+    let Some(shown_at) = game.data.prompt_shown_at else {
+        game.data.prompt_shown_at = Some(now);
+        return Ok(false);
+    };
+    if now < shown_at + timeout {
+        return Ok(false);
+    }
+
+    if let Some(action) = prompt.safe_default() {
+        info!(?side, ?action, "prompt_timeout");
+        handle_prompt_action(game, side, action)?;
+    } else if prompt.cancellable {
+        info!(?side, "prompt_timeout_cancel");
+        cancel_prompt_action(game, side)?;
+    } else {
+        return Ok(false);
+    }
+    restart_prompt_timer(game, now)?;
+    Ok(true)
+}
+
+/// Handles a player conceding the game. Legal at any point before the game
+/// has ended, including during mulligans, raids, and the opponent's turn.
+fn resign_action(game: &mut GameState, user_side: Side) -> Result<()> {
+    info!(?user_side, "resign_action");
+    verify!(
+        !matches!(game.data.phase, GamePhase::GameOver { .. }),
+        "Game has already ended for {:?}",
+        user_side
+    );
+    mutations::game_over(game, user_side.opponent())
+}
+
+/// Charges the time elapsed since the last action to the player whose clock is
+/// running, if [data::game::GameConfiguration::turn_timer] is set. `now` is
+/// the time since the UNIX epoch and `acting_side` is the player about to take
+/// an action, if any, who receives the clock increment.
+///
+/// When a player's clock runs out, they lose the game if the timer is
+/// configured to resign them or if they are responding during the opponent's
+/// turn. Otherwise their turn ends. Returns true if a player ran out of time.
+pub fn handle_turn_timer(
+    game: &mut GameState,
+    acting_side: Option<Side>,
+    now: Duration,
+) -> Result<bool> {
+    let running = enum_iterator::all::<Side>().find(|side| turn_timer_running(game, *side));
+    let Some(timer) = &mut game.data.turn_timer else {
+        return Ok(false);
+    };
+    let Some(side) = running else {
+        timer.last_action_at = None;
+        return Ok(false);
+    };
+
+    let elapsed = timer.last_action_at.map_or(Duration::ZERO, |last| now.saturating_sub(last));
+    let remaining = timer.remaining(side).saturating_sub(elapsed);
+    timer.last_action_at = Some(now);
+    if !remaining.is_zero() {
+        let increment = if acting_side == Some(side) { timer.increment } else { Duration::ZERO };
+        timer.remaining.insert(side, remaining + increment);
+        return Ok(false);
+    }
+
+    info!(?side, "turn_timer_expired");
+    timer.remaining.insert(side, timer.increment);
+    let resign = game.data.config.turn_timer.is_some_and(|config| config.resign_on_timeout);
+    if resign || side != game.data.turn.side {
+        mutations::game_over(game, side.opponent())?;
+    } else {
+        game.player_mut(side).prompt = None;
+        mutations::forfeit_action_points(game, side);
+        if game.data.raid.is_some() {
+            mutations::end_raid(game, RaidOutcome::Failure)?;
+        } else {
+            mutations::check_end_turn(game)?;
+        }
+    }
+    Ok(true)
+}
+
+/// Restores the game to its state before the `user_side` player's most recent
+/// action, if permitted by [flags::can_undo].
+fn undo_action(game: &mut GameState, user_side: Side) -> Result<()> {
+    info!(?user_side, "undo_action");
+    verify!(flags::can_undo(game, user_side), "Cannot undo for {:?}", user_side);
+    let step = game.undo_history.pop().with_error(|| "Expected undo step")?;
+    let history = mem::take(&mut game.undo_history);
+    let updates = mem::take(&mut game.updates);
+    // Keep the current random number generator so that undoing and repeating
+    // an action cannot be used to reroll its random outcome.
+    let rng = game.rng.take();
+    *game = *step.snapshot;
+    game.undo_history = history;
+    game.updates = updates;
+    game.rng = rng;
+    dispatch::populate_delegate_cache(game);
+    Ok(())
+}
+
 /// Handles a choice to keep or mulligan an opening hand
 fn handle_mulligan_decision(
     game: &mut GameState,
@@ -83,7 +321,7 @@ fn handle_mulligan_decision(
         "Cannot make mulligan decision for {:?}",
         user_side
     );
-    let mut mulligans = match &mut game.data.phase {
+    let mulligans = match &mut game.data.phase {
         GamePhase::ResolveMulligans(mulligans) => mulligans,
         _ => fail!("Incorrect game phase"),
     };
@@ -92,13 +330,30 @@ fn handle_mulligan_decision(
         Side::Overlord => mulligans.overlord = Some(decision),
         Side::Champion => mulligans.champion = Some(decision),
     }
+    mulligans.selected_cards.retain(|card_id| card_id.side != user_side);
 
     let hand = game.hand(user_side).map(|c| c.id).collect::<Vec<_>>();
     match decision {
         MulliganDecision::Keep => {}
         MulliganDecision::Mulligan => {
             mutations::shuffle_into_deck(game, user_side, &hand)?;
-            mutations::draw_cards(game, user_side, 5)?;
+            let count =
+                game.data.config.mulligan_draw_size.unwrap_or(constants::STARTING_HAND_SIZE);
+            mutations::draw_cards(game, user_side, count)?;
+        }
+        MulliganDecision::Partial(selection) => {
+            let selected = selection.cards().collect::<Vec<_>>();
+            verify!(
+                selected.iter().all(|card_id| hand.contains(card_id)),
+                "Selected cards must be in the hand of {:?}",
+                user_side
+            );
+            verify!(
+                selected.iter().collect::<HashSet<_>>().len() == selected.len(),
+                "Cannot select the same card twice"
+            );
+            mutations::place_on_bottom_of_deck(game, user_side, &selected)?;
+            mutations::draw_cards(game, user_side, selected.len() as u32)?;
         }
     }
 
@@ -107,6 +362,34 @@ fn handle_mulligan_decision(
     Ok(())
 }
 
+/// Marks or unmarks a card in the `user_side` player's opening hand to be
+/// replaced when they confirm a [MulliganDecision::Partial].
+fn toggle_mulligan_card(game: &mut GameState, user_side: Side, card_id: CardId) -> Result<()> {
+    info!(?user_side, ?card_id, "toggle_mulligan_card");
+    verify!(
+        flags::can_make_mulligan_decision(game, user_side)
+            && game.card(card_id).position() == CardPosition::Hand(user_side),
+        "Cannot select {:?} for mulligan by {:?}",
+        card_id,
+        user_side
+    );
+    let GamePhase::ResolveMulligans(mulligans) = &mut game.data.phase else {
+        fail!("Incorrect game phase");
+    };
+
+    if let Some(index) = mulligans.selected_cards.iter().position(|c| *c == card_id) {
+        mulligans.selected_cards.remove(index);
+    } else {
+        verify!(
+            mulligans.can_select_more(user_side),
+            "Cannot select more than {} cards for mulligan",
+            MAXIMUM_MULLIGAN_SELECTION
+        );
+        mulligans.selected_cards.push(card_id);
+    }
+    Ok(())
+}
+
 /// The basic game action to draw a card during your turn by spending one
 /// action.
 #[instrument(skip(game))]
@@ -119,7 +402,7 @@ fn draw_card_action(game: &mut GameState, user_side: Side) -> Result<()> {
     );
     mutations::spend_action_points(game, user_side, 1)?;
     let cards = mutations::draw_cards(game, user_side, 1)?;
-    if let Some(card_id) = cards.get(0) {
+    if let Some(card_id) = cards.first() {
         dispatch::invoke_event(game, DrawCardActionEvent(*card_id))?;
     }
 
@@ -131,6 +414,10 @@ fn draw_card_action(game: &mut GameState, user_side: Side) -> Result<()> {
 /// cost for a card, resolves its effects, and then moves it to the appropriate
 /// new [CardPosition]. Spell, Weapon, and Artifact cards are immediately
 /// revealed when played.
+///
+/// If a card which requires a room or card target is played with no target,
+/// this instead begins a pending play via [begin_targeted_play]. Cards with a
+/// variable mana cost begin a pending play via [begin_variable_cost_play].
 #[instrument(skip(game))]
 fn play_card_action(
     game: &mut GameState,
@@ -139,6 +426,16 @@ fn play_card_action(
     target: CardTarget,
 ) -> Result<()> {
     info!(?user_side, ?card_id, ?target, "play_card_action");
+    if target == CardTarget::None && flags::has_variable_mana_cost(game, card_id) {
+        return begin_variable_cost_play(game, user_side, card_id);
+    }
+
+    if target == CardTarget::None
+        && queries::card_target_kind(game, card_id) != CardTargetKind::None
+    {
+        return begin_targeted_play(game, user_side, card_id);
+    }
+
     verify!(
         flags::can_take_play_card_action(game, user_side, card_id, target),
         "Cannot play card {:?}",
@@ -154,7 +451,229 @@ fn play_card_action(
     if flags::enters_play_face_up(game, card_id) {
         let amount = queries::mana_cost(game, card_id).with_error(|| "Card has no mana cost")?;
         mana::spend(game, user_side, ManaPurpose::PayForCard(card_id), amount)?;
-        if let Some(custom_cost) = &definition.cost.custom_cost {
+    }
+
+    complete_play(game, user_side, card_id, target)
+}
+
+/// Starts playing a card which requires a room or card target without a target
+/// having been selected. Action and mana costs are paid immediately and the
+/// player is given a cancellable prompt to pick a target. Cancelling via
+/// [cancel_prompt_action] refunds these costs.
+fn begin_targeted_play(game: &mut GameState, user_side: Side, card_id: CardId) -> Result<()> {
+    info!(?user_side, ?card_id, "begin_targeted_play");
+    let candidates: Vec<CardTarget> = match queries::card_target_kind(game, card_id) {
+        CardTargetKind::None => vec![],
+        CardTargetKind::Room => enum_iterator::all::<RoomId>().map(CardTarget::Room).collect(),
+        CardTargetKind::Card => game.all_cards().map(|card| CardTarget::Card(card.id)).collect(),
+    };
+    let responses = candidates
+        .into_iter()
+        .filter(|target| flags::can_take_play_card_action(game, user_side, card_id, *target))
+        .map(|target| PromptAction::SelectCardTarget(card_id, target))
+        .collect::<Vec<_>>();
+    verify!(!responses.is_empty(), "No valid targets for card {:?}", card_id);
+    begin_pending_play(game, user_side, card_id, responses)
+}
+
+/// Starts playing a card with a variable mana cost. Action costs are paid
+/// immediately and the player is given a cancellable prompt to choose a value
+/// for X, which is paid via [choose_number].
+fn begin_variable_cost_play(game: &mut GameState, user_side: Side, card_id: CardId) -> Result<()> {
+    info!(?user_side, ?card_id, "begin_variable_cost_play");
+    verify!(
+        flags::can_take_play_card_action(game, user_side, card_id, CardTarget::None),
+        "Cannot play card {:?}",
+        card_id
+    );
+    let available = mana::get(game, user_side, ManaPurpose::PayForCard(card_id));
+    let responses = (0..=available.min(MAXIMUM_X_VALUE))
+        .map(|x| PromptAction::ChooseNumber(card_id, x))
+        .collect();
+    begin_pending_play(game, user_side, card_id, responses)
+}
+
+/// Moves a card to the 'played' position and pays its costs, other than
+/// custom costs and variable mana costs, then shows a cancellable prompt
+/// with the provided `responses` to complete the play.
+fn begin_pending_play(
+    game: &mut GameState,
+    user_side: Side,
+    card_id: CardId,
+    responses: Vec<PromptAction>,
+) -> Result<()> {
+    let actions_spent = rules::get(game.card(card_id).name).cost.actions;
+    mutations::move_card(game, card_id, CardPosition::Played(user_side, CardTarget::None))?;
+    mutations::spend_action_points(game, user_side, actions_spent)?;
+
+    let mana_spent = if flags::enters_play_face_up(game, card_id)
+        && !flags::has_variable_mana_cost(game, card_id)
+    {
+        let amount = queries::mana_cost(game, card_id).with_error(|| "Card has no mana cost")?;
+        mana::spend(game, user_side, ManaPurpose::PayForCard(card_id), amount)?
+    } else {
+        ManaPayment::default()
+    };
+
+    game.player_mut(user_side).prompt = Some(GamePrompt {
+        context: Some(PromptContext::PlayCard(PendingPlay { card_id, actions_spent, mana_spent })),
+        responses,
+        cancellable: true,
+    });
+    Ok(())
+}
+
+/// Handles selection of a target for a card play started via
+/// [begin_targeted_play].
+fn select_card_target(
+    game: &mut GameState,
+    user_side: Side,
+    card_id: CardId,
+    target: CardTarget,
+) -> Result<()> {
+    info!(?user_side, ?card_id, ?target, "select_card_target");
+    verify!(
+        game.card(card_id).position() == CardPosition::Played(user_side, CardTarget::None),
+        "Card {:?} is not awaiting a target",
+        card_id
+    );
+    mutations::move_card(game, card_id, CardPosition::Played(user_side, target))?;
+    complete_play(game, user_side, card_id, target)
+}
+
+/// Handles choosing a value for X for a card play started via
+/// [begin_variable_cost_play]. Pays X mana and then completes the play.
+fn choose_number(
+    game: &mut GameState,
+    user_side: Side,
+    card_id: CardId,
+    x: ManaValue,
+) -> Result<()> {
+    info!(?user_side, ?card_id, ?x, "choose_number");
+    verify!(
+        game.card(card_id).position() == CardPosition::Played(user_side, CardTarget::None),
+        "Card {:?} is not awaiting a number",
+        card_id
+    );
+    mana::spend(game, user_side, ManaPurpose::PayForCard(card_id), x)?;
+    game.card_mut(card_id).data.x_value = Some(x);
+    complete_play(game, user_side, card_id, CardTarget::None)
+}
+
+/// Handles a [DamageAllocation] chosen in response to a
+/// [PromptContext::DistributeDamage] prompt, dealing the allocated damage. The
+/// allocation is validated by [GamePrompt::accepts].
+fn distribute_damage(
+    game: &mut GameState,
+    user_side: Side,
+    context: Option<PromptContext>,
+    allocation: DamageAllocation,
+) -> Result<()> {
+    info!(?user_side, ?allocation, "distribute_damage");
+    let Some(PromptContext::DistributeDamage { total, targets }) = context else {
+        fail!("Not expecting a damage distribution");
+    };
+    verify!(
+        allocation.is_valid(total, &targets),
+        "Expected {} total damage among {:?} but got {:?}",
+        total,
+        targets,
+        allocation
+    );
+
+    for (card_id, amount) in allocation.iter() {
+        if amount > 0 {
+            mutations::deal_minion_damage(game, card_id, amount)?;
+        }
+    }
+
+    mutations::check_end_turn(game)
+}
+
+/// Handles a room chosen in response to a [PromptContext::ChooseRoom] prompt,
+/// invoking the prompt's source ability with the chosen room.
+fn choose_room(
+    game: &mut GameState,
+    user_side: Side,
+    context: Option<PromptContext>,
+    room_id: RoomId,
+) -> Result<()> {
+    info!(?user_side, ?room_id, "choose_room");
+    let Some(PromptContext::ChooseRoom { source, valid }) = context else {
+        fail!("Not expecting a room choice");
+    };
+    verify!(valid.contains(&room_id), "Invalid room choice {:?}", room_id);
+    dispatch::invoke_event(game, RoomChosenEvent(RoomChosen { source, room_id }))?;
+    mutations::check_end_turn(game)
+}
+
+/// Marks or unmarks `card_id` to be discarded in response to a
+/// [PromptContext::DiscardToHandSize] prompt, re-prompting with the updated
+/// selection.
+fn toggle_discard_card(
+    game: &mut GameState,
+    user_side: Side,
+    context: Option<PromptContext>,
+    card_id: CardId,
+) -> Result<()> {
+    info!(?user_side, ?card_id, "toggle_discard_card");
+    let Some(PromptContext::DiscardToHandSize { count, mut selected }) = context else {
+        fail!("Not expecting a discard selection");
+    };
+    if let Some(index) = selected.iter().position(|c| *c == card_id) {
+        selected.remove(index);
+    } else {
+        selected.push(card_id);
+    }
+
+    let hand = game.card_list_for_position(user_side, CardPosition::Hand(user_side));
+    game.player_mut(user_side).prompt =
+        Some(GamePrompt::discard_to_hand_size(&hand, count, selected));
+    Ok(())
+}
+
+/// Discards the cards selected in response to a
+/// [PromptContext::DiscardToHandSize] prompt, after which the turn may end.
+fn confirm_discard(
+    game: &mut GameState,
+    user_side: Side,
+    context: Option<PromptContext>,
+) -> Result<()> {
+    info!(?user_side, "confirm_discard");
+    let Some(PromptContext::DiscardToHandSize { count, selected }) = context else {
+        fail!("Not expecting a discard confirmation");
+    };
+    verify!(selected.len() == count, "Expected {} cards to discard but got {:?}", count, selected);
+    mutations::discard_from_hand(game, user_side, &selected)?;
+    mutations::check_end_turn(game)
+}
+
+/// Handles a [ManaSource] chosen in response to a
+/// [PromptContext::ChooseManaSource] prompt, completing the prompt's weapon use.
+fn choose_mana_source(
+    game: &mut GameState,
+    user_side: Side,
+    context: Option<PromptContext>,
+    source: ManaSource,
+) -> Result<()> {
+    info!(?user_side, ?source, "choose_mana_source");
+    let Some(PromptContext::ChooseManaSource { weapon_id, target_id, .. }) = context else {
+        fail!("Not expecting a mana source choice");
+    };
+    raids::handle_mana_source_chosen(game, user_side, weapon_id, target_id, source)
+}
+
+/// Finishes playing a card once its action and mana costs have been paid,
+/// paying custom costs, resolving its effects, and moving it to its final
+/// position.
+fn complete_play(
+    game: &mut GameState,
+    user_side: Side,
+    card_id: CardId,
+    target: CardTarget,
+) -> Result<()> {
+    if flags::enters_play_face_up(game, card_id) {
+        if let Some(custom_cost) = &rules::card_definition(game, card_id).cost.custom_cost {
             (custom_cost.pay)(game, card_id)?;
         }
         game.card_mut(card_id).turn_face_up();
@@ -172,6 +691,25 @@ fn play_card_action(
     Ok(())
 }
 
+/// Dismisses the current prompt for the `user_side` player if it is
+/// cancellable. If the prompt was for a pending card play, the card is returned
+/// to its owner's hand and the costs paid for it are refunded.
+fn cancel_prompt_action(game: &mut GameState, user_side: Side) -> Result<()> {
+    info!(?user_side, "cancel_prompt_action");
+    let prompt = game.player(user_side).prompt.as_ref().with_error(|| "No active prompt")?;
+    verify!(prompt.cancellable, "Prompt cannot be cancelled");
+    let context = prompt.context.clone();
+    game.player_mut(user_side).prompt = None;
+
+    if let Some(PromptContext::PlayCard(pending)) = context {
+        mutations::move_card(game, pending.card_id, CardPosition::Hand(user_side))?;
+        mutations::gain_action_points(game, user_side, pending.actions_spent);
+        mana::refund(game, user_side, &pending.mana_spent);
+    }
+
+    Ok(())
+}
+
 /// The basic game action to activate an ability of a card in play.
 #[instrument(skip(game))]
 fn activate_ability_action(
@@ -248,14 +786,26 @@ fn spend_action_point_action(game: &mut GameState, user_side: Side) -> Result<()
     Ok(())
 }
 
+/// Ends the `user_side` player's turn, forfeiting their remaining actions. See
+/// [mutations::forfeit_action_points].
+fn end_turn_action(game: &mut GameState, user_side: Side) -> Result<()> {
+    info!(?user_side, "end_turn_action");
+    verify!(
+        flags::can_take_end_turn_action(game, user_side),
+        "Cannot end turn for {:?}",
+        user_side
+    );
+    mutations::forfeit_action_points(game, user_side);
+    mutations::check_end_turn(game)?;
+    Ok(())
+}
+
 /// Handles a [PromptAction] for the `user_side` player. Clears active prompts.
 fn handle_prompt_action(game: &mut GameState, user_side: Side, action: PromptAction) -> Result<()> {
+    let mut context = None;
     if let Some(prompt) = &game.player(user_side).prompt {
-        verify!(
-            prompt.responses.iter().any(|p| *p == action),
-            "Unexpected action {:?} received",
-            action
-        );
+        verify!(prompt.accepts(&action), "Unexpected action {:?} received", action);
+        context = prompt.context.clone();
         game.player_mut(user_side).prompt = None;
     }
 
@@ -263,7 +813,23 @@ fn handle_prompt_action(game: &mut GameState, user_side: Side, action: PromptAct
         PromptAction::MulliganDecision(mulligan) => {
             handle_mulligan_decision(game, user_side, mulligan)
         }
+        PromptAction::ToggleMulliganCard(card_id) => toggle_mulligan_card(game, user_side, card_id),
         PromptAction::CardAction(card_action) => card_prompt::handle(game, user_side, card_action),
+        PromptAction::SelectCardTarget(card_id, target) => {
+            select_card_target(game, user_side, card_id, target)
+        }
+        PromptAction::ChooseNumber(card_id, x) => choose_number(game, user_side, card_id, x),
+        PromptAction::DamageDistribution(allocation) => {
+            distribute_damage(game, user_side, context, allocation)
+        }
+        PromptAction::RoomChosen(room_id) => choose_room(game, user_side, context, room_id),
+        PromptAction::ToggleDiscardCard(card_id) => {
+            toggle_discard_card(game, user_side, context, card_id)
+        }
+        PromptAction::ConfirmDiscard => confirm_discard(game, user_side, context),
+        PromptAction::ManaSourceChosen(source) => {
+            choose_mana_source(game, user_side, context, source)
+        }
         _ => raids::handle_action(game, user_side, action),
     }
 }
@@ -19,10 +19,11 @@ pub mod response_builder;
 use anyhow::Result;
 use data::player_name::{NamedPlayer, PlayerId};
 use data::primitives::{
-    AbilityId, AbilityIndex, CardId, DeckId, GameId, GameObjectId, RoomId, Side, Sprite,
+    AbilityId, AbilityIndex, CardId, DeckId, GameId, GameObjectId, Locale, RoomId, Side, Sprite,
 };
 use protos::spelldawn::game_object_identifier::Id;
 use protos::spelldawn::player_identifier::PlayerIdentifierType;
+use protos::spelldawn::Locale as ClientLocale;
 use protos::spelldawn::{
     CardIdentifier, DeckIdentifier, GameIdentifier, GameObjectIdentifier, PlayerIdentifier,
     PlayerSide, RoomIdentifier, SpriteAddress, TimeValue,
@@ -43,7 +44,7 @@ pub fn game_id(game_id: GameIdentifier) -> GameId {
 pub fn card_identifier(card_id: CardId) -> CardIdentifier {
     // Maybe need to obfuscate this somehow?
     CardIdentifier {
-        side: player_side(card_id.side) as i32,
+        side: player_side(card_id.side),
         index: card_id.index as u32,
         ability_id: None,
     }
@@ -151,6 +152,15 @@ pub fn room_id(identifier: i32) -> Result<RoomId> {
     }
 }
 
+/// Converts a client locale into a [Locale]. Clients which do not specify a
+/// locale receive English text.
+pub fn locale(locale: i32) -> Locale {
+    match ClientLocale::from_i32(locale) {
+        Some(ClientLocale::Spanish) => Locale::Spanish,
+        _ => Locale::English,
+    }
+}
+
 /// Turns a [Sprite] into its protobuf equivalent
 pub fn sprite(sprite: &Sprite) -> SpriteAddress {
     SpriteAddress { address: sprite.address.clone() }
@@ -14,19 +14,62 @@
 
 use std::collections::HashMap;
 
-use data::primitives::Side;
+use data::card_state::CardState;
+use data::primitives::{Locale, Side};
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::{
     CardIdentifier, GameView, ObjectPosition, PlayerName, UpdateGameViewCommand,
 };
 
+/// Options which alter the contents of a response without affecting how it is
+/// animated.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResponseOptions {
+    /// If true, cards in a deck which are not revealed to the user receiving
+    /// this response are not synced individually. The client displays a deck
+    /// as a single object, so these cards are only needed once they are drawn,
+    /// at which point a subsequent update will include them.
+    pub omit_hidden_deck_cards: bool,
+
+    /// If true, an update which only changes the score, mana, actions, or
+    /// `can_take_action` state of the players is sent as an
+    /// `UpdatePlayerScalarsCommand` instead of a full `GameView`. Requires
+    /// the previous state of the game to be available.
+    pub scalar_deltas: bool,
+
+    /// If true, an update which changes individual cards, in addition to any
+    /// player scalar values, is sent as a `MoveGameObjectsCommand` for cards
+    /// which have only changed position and an `UpdateCardsCommand` for other
+    /// changed cards, instead of a full `GameView`. Requires the previous
+    /// state of the game to be available.
+    pub card_deltas: bool,
+
+    /// Language in which card titles and rules text are displayed. Text which
+    /// has not been translated into this locale is displayed in English.
+    pub locale: Locale,
+}
+
+/// Identifies who a response is being rendered for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Viewer {
+    /// A participant in the game playing the given side.
+    Player(Side),
+    /// An observer who is not a participant in the game. Spectators only see
+    /// cards which are revealed to both players and cannot take actions.
+    Spectator,
+}
+
 pub struct ResponseState {
     pub animate: bool,
     pub is_final_update: bool,
+    pub options: ResponseOptions,
 }
 
 pub struct ResponseBuilder {
+    /// Perspective from which the game is displayed. Spectators view the game
+    /// from the Overlord's side of the table.
     pub user_side: Side,
+    pub viewer: Viewer,
     pub state: ResponseState,
     pub commands: Vec<Command>,
 
@@ -37,7 +80,34 @@ pub struct ResponseBuilder {
 
 impl ResponseBuilder {
     pub fn new(user_side: Side, state: ResponseState) -> Self {
-        Self { user_side, state, commands: vec![], last_snapshot_positions: HashMap::default() }
+        Self {
+            user_side,
+            viewer: Viewer::Player(user_side),
+            state,
+            commands: vec![],
+            last_snapshot_positions: HashMap::default(),
+        }
+    }
+
+    /// Creates a builder for a response to a [Viewer::Spectator].
+    pub fn spectator(state: ResponseState) -> Self {
+        Self { viewer: Viewer::Spectator, ..Self::new(Side::Overlord, state) }
+    }
+
+    /// Returns true if this response is being rendered for a spectator.
+    pub fn is_spectator(&self) -> bool {
+        self.viewer == Viewer::Spectator
+    }
+
+    /// Returns true if the identity of `card` is visible to the viewer of this
+    /// response.
+    pub fn is_revealed(&self, card: &CardState) -> bool {
+        match self.viewer {
+            Viewer::Player(side) => card.is_revealed_to(side),
+            Viewer::Spectator => {
+                card.is_revealed_to(Side::Overlord) && card.is_revealed_to(Side::Champion)
+            }
+        }
     }
 
     pub fn push(&mut self, command: Command) {
@@ -57,6 +127,11 @@ impl ResponseBuilder {
         }));
     }
 
+    /// Converts a [Side] into the [PlayerName] it has from the perspective of
+    /// the user receiving this response.
+    ///
+    /// All player names sent to the client should be produced via this method,
+    /// since the client does not handle [PlayerName::Unspecified].
     pub fn to_player_name(&self, side: Side) -> i32 {
         if side == self.user_side {
             PlayerName::User as i32
@@ -24,6 +24,9 @@ use crate::state_evaluator::StateEvaluator;
 use crate::state_predictor::StatePredictor;
 use crate::{state_combiner, state_predictor};
 
+/// Default amount of wall-clock time an agent may spend selecting an action.
+pub const DEFAULT_TIME_BUDGET: Duration = Duration::from_secs(3);
+
 #[derive(Debug, Clone, Copy)]
 pub struct AgentConfig {
     /// Time at which the agent should complete its move selection.
@@ -35,10 +38,12 @@ pub struct AgentConfig {
 
 impl AgentConfig {
     pub fn with_deadline(seconds: u64) -> Self {
-        Self {
-            deadline: Instant::now() + Duration::from_secs(seconds),
-            panic_on_search_timeout: false,
-        }
+        Self::with_time_budget(Duration::from_secs(seconds))
+    }
+
+    /// Creates a config with a deadline `budget` from the current time.
+    pub fn with_time_budget(budget: Duration) -> Self {
+        Self { deadline: Instant::now() + budget, panic_on_search_timeout: false }
     }
 }
 
@@ -58,6 +63,25 @@ where
     /// Select an action for the current player to take in the `node` game
     /// state. Should attempt to return a result before time `deadline`.
     fn pick_action(&self, config: AgentConfig, node: &TNode) -> Result<TNode::Action>;
+
+    /// Amount of wall-clock time this agent should be given to select an
+    /// action, used to build its [AgentConfig].
+    fn time_budget(&self) -> Duration {
+        DEFAULT_TIME_BUDGET
+    }
+
+    /// Called with an `action` this agent selected for the `player` player in
+    /// `node` before the action is applied, allowing the agent to store state
+    /// in `node` which must persist between calls to [Self::pick_action]. Does
+    /// nothing by default.
+    fn record_action(
+        &self,
+        _node: &mut TNode,
+        _player: TNode::PlayerName,
+        _action: TNode::Action,
+    ) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// A tuple of various pieces needed to perform agent action selection.
@@ -85,6 +109,10 @@ where
     /// Given a set of *possible* game states from the predictor, selects an
     /// actual game state to run the selection algorithm form.
     pub combiner: StateCombiner<TNode, TEvaluator>,
+    /// Amount of wall-clock time this agent should be given to select an
+    /// action. Selection algorithms return the best action found so far once
+    /// this budget is exceeded.
+    pub time_budget: Duration,
 }
 
 impl<TSelector, TEvaluator, TNode> AgentData<TSelector, TEvaluator, TNode>
@@ -107,8 +135,14 @@ where
             selector,
             evaluator,
             combiner: state_combiner::worst_case,
+            time_budget: DEFAULT_TIME_BUDGET,
         }
     }
+
+    /// Returns this agent with its [Self::time_budget] set to `budget`.
+    pub fn with_time_budget(self, budget: Duration) -> Self {
+        Self { time_budget: budget, ..self }
+    }
 }
 
 impl<TSelector, TEvaluator, TNode> Agent<TNode> for AgentData<TSelector, TEvaluator, TNode>
@@ -129,4 +163,8 @@ where
         let node = (self.combiner)(node, self.predictor, &self.evaluator)?;
         self.selector.pick_action(deadline, &node, &self.evaluator, player)
     }
+
+    fn time_budget(&self) -> Duration {
+        self.time_budget
+    }
 }
@@ -12,21 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use ai_core::agent::{Agent, AgentConfig, AgentData};
 use ai_core::compound_evaluator::CompoundEvaluator;
+use ai_core::game_state_node::GameStateNode;
 use ai_monte_carlo::monte_carlo::{MonteCarloAlgorithm, RandomPlayoutEvaluator};
 use ai_monte_carlo::uct1::Uct1;
 use ai_tree_search::alpha_beta::AlphaBetaAlgorithm;
+use ai_tree_search::expectimax::ExpectimaxAgent;
 use ai_tree_search::minimax::MinimaxAlgorithm;
 use anyhow::Result;
+use data::agent_definition::{AgentData as AgentDefinition, AgentName, GameStatePredictorName};
 use data::game_actions::UserAction;
 use data::player_name::NamedPlayer;
-use with_error::fail;
+use data::primitives::Side;
+use rand::prelude::IteratorRandom;
+use tracing::warn;
+use with_error::{fail, WithError};
 
 use crate::evaluators::{
     CardsInHandEvaluator, CardsInPlayEvaluator, LevelCountersEvaluator, ManaDifferenceEvaluator,
     ScoreEvaluator,
 };
+use crate::predictors;
 use crate::state_node::SpelldawnState;
 
 pub fn get(name: NamedPlayer) -> Box<dyn Agent<SpelldawnState>> {
@@ -45,21 +54,36 @@ pub fn get(name: NamedPlayer) -> Box<dyn Agent<SpelldawnState>> {
         NamedPlayer::TestAlphaBetaHeuristics => Box::new(AgentData::omniscient(
             "ALPHA_BETA_HEURISTICS",
             AlphaBetaAlgorithm { search_depth: 4 },
-            CompoundEvaluator {
-                evaluators: vec![
-                    (100_000, Box::new(ScoreEvaluator {})),
-                    (10, Box::new(ManaDifferenceEvaluator {})),
-                    (5, Box::new(CardsInHandEvaluator {})),
-                    (15, Box::new(CardsInPlayEvaluator {})),
-                    (20, Box::new(LevelCountersEvaluator {})),
-                ],
-            },
+            heuristic_evaluator(),
         )),
         NamedPlayer::TestUct1 => Box::new(AgentData::omniscient(
             "UCT1",
             MonteCarloAlgorithm { child_score_algorithm: Uct1 {} },
             RandomPlayoutEvaluator {},
         )),
+        NamedPlayer::TestExpectimax => Box::new(ExpectimaxAgent::new(
+            "EXPECTIMAX",
+            predictors::determinized,
+            heuristic_evaluator(),
+            predictors::DETERMINIZED_SAMPLES,
+        )),
+        NamedPlayer::TestScripted => Box::new(ScriptedAgent::new(
+            vec![UserAction::GainMana, UserAction::DrawCard, UserAction::GainMana],
+            PickRandomAgent {},
+        )),
+    }
+}
+
+/// Evaluator which combines the score with heuristics for board position.
+fn heuristic_evaluator() -> CompoundEvaluator<SpelldawnState> {
+    CompoundEvaluator {
+        evaluators: vec![
+            (100_000, Box::new(ScoreEvaluator {})),
+            (10, Box::new(ManaDifferenceEvaluator {})),
+            (5, Box::new(CardsInHandEvaluator {})),
+            (15, Box::new(CardsInPlayEvaluator {})),
+            (20, Box::new(LevelCountersEvaluator {})),
+        ],
     }
 }
 
@@ -74,3 +98,91 @@ impl Agent<SpelldawnState> for NoActionAgent {
         fail!("No Action")
     }
 }
+
+/// Agent which selects a legal action uniformly at random.
+pub struct PickRandomAgent {}
+
+impl Agent<SpelldawnState> for PickRandomAgent {
+    fn name(&self) -> &'static str {
+        "PICK_RANDOM"
+    }
+
+    fn pick_action(&self, _: AgentConfig, node: &SpelldawnState) -> Result<UserAction> {
+        node.legal_actions(node.current_turn()?)?
+            .choose(&mut rand::thread_rng())
+            .with_error(|| "No legal actions")
+    }
+}
+
+/// Agent which plays a predefined sequence of actions, e.g. to provide a
+/// deterministic tutorial opponent.
+///
+/// Scripted actions which are not legal when reached are skipped. Once the
+/// script is exhausted, actions are selected by the `fallback` agent. The
+/// index of the next action to attempt is stored in the game via
+/// [Agent::record_action], since agents are recreated for each action.
+pub struct ScriptedAgent<T: Agent<SpelldawnState>> {
+    script: Vec<UserAction>,
+    fallback: T,
+}
+
+impl<T: Agent<SpelldawnState>> ScriptedAgent<T> {
+    pub fn new(script: Vec<UserAction>, fallback: T) -> Self {
+        Self { script, fallback }
+    }
+
+    /// Returns the index of the next scripted action to attempt for the `side`
+    /// player and the action at that index, skipping illegal actions.
+    fn next_scripted_action(
+        &self,
+        node: &SpelldawnState,
+        side: Side,
+    ) -> Result<Option<(usize, UserAction)>> {
+        let start = node.player(side).agent.map_or(0, |agent| agent.script_position);
+        for (index, action) in self.script.iter().enumerate().skip(start) {
+            if node.legal_actions(side)?.any(|legal| legal == *action) {
+                return Ok(Some((index, *action)));
+            }
+            warn!(?action, ?side, "Skipping illegal scripted action");
+        }
+        Ok(None)
+    }
+}
+
+impl<T: Agent<SpelldawnState>> Agent<SpelldawnState> for ScriptedAgent<T> {
+    fn name(&self) -> &'static str {
+        "SCRIPTED"
+    }
+
+    fn pick_action(&self, config: AgentConfig, node: &SpelldawnState) -> Result<UserAction> {
+        match self.next_scripted_action(node, node.current_turn()?)? {
+            Some((_, action)) => Ok(action),
+            None => self.fallback.pick_action(config, node),
+        }
+    }
+
+    fn time_budget(&self) -> Duration {
+        self.fallback.time_budget()
+    }
+
+    fn record_action(
+        &self,
+        node: &mut SpelldawnState,
+        side: Side,
+        action: UserAction,
+    ) -> Result<()> {
+        let position = match self.next_scripted_action(node, side)? {
+            Some((index, scripted)) if scripted == action => index + 1,
+            _ => self.script.len(),
+        };
+        node.player_mut(side)
+            .agent
+            .get_or_insert(AgentDefinition {
+                name: AgentName::Scripted,
+                state_predictor: GameStatePredictorName::Omniscient,
+                script_position: 0,
+            })
+            .script_position = position;
+        Ok(())
+    }
+}
@@ -14,6 +14,12 @@
 
 //! Implements generic game AI algorithms specifically for spelldawn
 
+/// Legal actions used by AI agents. This is a re-export of the implementation
+/// in the `actions` crate, so agents always see the same actions as the
+/// server.
+pub use actions::legal_actions;
+
 pub mod agents;
 pub mod evaluators;
+pub mod predictors;
 pub mod state_node;
@@ -0,0 +1,112 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! State predictors for Spelldawn games
+
+use std::collections::BTreeMap;
+
+use ai_core::game_state_node::GameStateNode;
+use ai_core::state_predictor;
+use ai_core::state_predictor::StatePredictor;
+use data::agent_definition::GameStatePredictorName;
+use data::card_state::{CardPosition, CardState};
+use data::primitives::{CardId, ItemLocation, RoomLocation, Side};
+use data::random;
+use rand::RngCore;
+use rules::dispatch;
+
+use crate::state_node::SpelldawnState;
+
+/// Number of game states returned by the [determinized] predictor.
+pub const DETERMINIZED_SAMPLES: usize = 5;
+
+/// Returns the [StatePredictor] identified by `name`.
+pub fn get(name: GameStatePredictorName) -> StatePredictor<SpelldawnState> {
+    match name {
+        GameStatePredictorName::Omniscient => state_predictor::omniscient,
+        GameStatePredictorName::Determinized => determinized,
+    }
+}
+
+/// A [StatePredictor] which does not rely on hidden information.
+///
+/// Returns [DETERMINIZED_SAMPLES] copies of the game in which the identities of
+/// cards that are hidden from the player whose turn it is have been randomly
+/// exchanged. Only card names change, so every card keeps its position and
+/// state. Cards which have been revealed to the player, including deck-top
+/// cards known via card effects, keep their identities.
+///
+/// Hidden cards in a player's hand or deck can be exchanged with each other.
+/// Hidden cards in play are only exchanged with hidden cards in the same kind
+/// of location, since this is all the viewer can see about them. For example, a
+/// face-down room occupant may be exchanged with any other face-down occupant
+/// regardless of its card type.
+///
+/// Each sample is reseeded from the game's random number generator, so that
+/// deterministic games produce the same predictions and the samples do not
+/// share the real game's future random outcomes.
+pub fn determinized(node: &SpelldawnState) -> Box<dyn Iterator<Item = SpelldawnState>> {
+    let Ok(viewer) = node.current_turn() else {
+        return state_predictor::omniscient(node);
+    };
+    let pools = hidden_card_pools(node, viewer);
+    let mut rng = node.rng.clone();
+    let samples = (0..DETERMINIZED_SAMPLES)
+        .map(|_| {
+            let mut state = node.make_copy();
+            let seed = rng.as_mut().map_or_else(rand::random, RngCore::next_u64);
+            random::reseed(&mut state, seed);
+            for pool in pools.values() {
+                let mut names =
+                    pool.iter().map(|card_id| state.card(*card_id).name).collect::<Vec<_>>();
+                random::shuffle(&mut state, &mut names);
+                for (card_id, name) in pool.iter().zip(names) {
+                    state.card_mut(*card_id).name = name;
+                }
+            }
+            dispatch::populate_delegate_cache(&mut state);
+            state
+        })
+        .collect::<Vec<_>>();
+    Box::new(samples.into_iter())
+}
+
+/// Groups of cards whose identities can be exchanged with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Pool {
+    HandOrDeck(Side),
+    Room(RoomLocation),
+    Item(ItemLocation),
+}
+
+/// Returns the cards which are hidden from the `viewer` player, grouped by the
+/// [Pool] they belong to.
+fn hidden_card_pools(game: &SpelldawnState, viewer: Side) -> BTreeMap<Pool, Vec<CardId>> {
+    let mut result = BTreeMap::<Pool, Vec<CardId>>::new();
+    for card in game.all_cards().filter(|c| !c.is_revealed_to(viewer) && !c.data.is_token) {
+        if let Some(pool) = pool(card) {
+            result.entry(pool).or_default().push(card.id);
+        }
+    }
+    result
+}
+
+fn pool(card: &CardState) -> Option<Pool> {
+    match card.position() {
+        position if position.in_hand() || position.in_deck() => Some(Pool::HandOrDeck(card.side())),
+        CardPosition::Room(_, location) => Some(Pool::Room(location)),
+        CardPosition::ArenaItem(location) => Some(Pool::Item(location)),
+        _ => None,
+    }
+}
@@ -87,15 +87,15 @@ type SearchGraph<TState> = Graph<SearchNode<TState>, SearchEdge<TState>>;
 /// connected by game actions. The search follows these three steps
 /// repeatedly:
 ///
-/// 1) **Tree Policy:** Find a node in the tree which has not previously
-/// been explored. The UCT algorithm is one mathematical heuristic
-/// for how to prioritize nodes to explore.
+/// 1) **Tree Policy:** Find a node in the tree which has not previously been
+///    explored. The UCT algorithm is one mathematical heuristic for how to
+///    prioritize nodes to explore.
 ///
-/// 2) **Default Policy:** Score this node to determine its reward value
-/// (∆), typically by playing random moves until the game terminates.
+/// 2) **Default Policy:** Score this node to determine its reward value (∆),
+///    typically by playing random moves until the game terminates.
 ///
-/// 3) **Backpropagation:** Walk back up the tree, adding the resulting
-/// reward value to each parent node.
+/// 3) **Backpropagation:** Walk back up the tree, adding the resulting reward
+///    value to each parent node.
 ///
 /// Pseudocode:
 /// ```text
@@ -125,12 +125,10 @@ impl<TScoreAlgorithm: ChildScoreAlgorithm> SelectionAlgorithm
         TStateNode: GameStateNode,
         TEvaluator: StateEvaluator<TStateNode>,
     {
-        self.run_search(
-            |i| i % 100 == 0 && config.deadline < Instant::now(),
-            node,
-            evaluator,
-            player,
-        )
+        // The deadline is checked before every iteration because a single random
+        // playout can be slow relative to short time budgets. At least one
+        // iteration is always run so that an action can be selected.
+        self.run_search(|i| i > 0 && config.deadline < Instant::now(), node, evaluator, player)
     }
 }
 
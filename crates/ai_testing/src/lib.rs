@@ -18,3 +18,4 @@ pub mod nim;
 pub mod nim_agents;
 pub mod run_matchup_impl;
 pub mod run_nim_impl;
+pub mod tournament;
@@ -71,7 +71,11 @@ pub fn main() -> Result<()> {
             GameId::new(0),
             decklists::deck_for_player(args.overlord, Side::Overlord),
             decklists::deck_for_player(args.champion, Side::Champion),
-            GameConfiguration { deterministic: args.deterministic, simulation: true },
+            GameConfiguration {
+                deterministic: args.deterministic,
+                simulation: true,
+                ..GameConfiguration::default()
+            },
         );
         dispatch::populate_delegate_cache(&mut game);
         mutations::deal_opening_hands(&mut game)?;
@@ -88,6 +92,7 @@ pub fn main() -> Result<()> {
                         deadline: Instant::now() + Duration::from_secs(args.move_time),
                     };
                     let action = agent.pick_action(config, &state)?;
+                    agent.record_action(&mut state, current_turn, action)?;
                     state.execute_action(current_turn, action)?;
                     clear_action_line(args.verbosity);
                     println!("{} performs action {:?}", agent.name(), action);
@@ -0,0 +1,141 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs many independent games between two agents to measure win rates.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+
+use ai_core::agent::{Agent, AgentConfig};
+use ai_core::game_state_node::{GameStateNode, GameStatus};
+use ai_game_integration::state_node::SpelldawnState;
+use anyhow::Result;
+use data::game::{GameState, DEFAULT_SEED};
+use data::game_actions::UserAction;
+use data::primitives::Side;
+use data::random;
+use with_error::WithError;
+
+/// Aggregate outcome of a set of games played via [run_games_parallel].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TournamentResult {
+    pub overlord_wins: u64,
+    pub champion_wins: u64,
+    /// Games which had not ended after the maximum number of actions.
+    pub draws: u64,
+}
+
+impl TournamentResult {
+    /// Total number of games played
+    pub fn games(&self) -> u64 {
+        self.overlord_wins + self.champion_wins + self.draws
+    }
+
+    fn record(&mut self, winner: Option<Side>) {
+        match winner {
+            Some(Side::Overlord) => self.overlord_wins += 1,
+            Some(Side::Champion) => self.champion_wins += 1,
+            None => self.draws += 1,
+        }
+    }
+}
+
+/// Plays `games` independent games between the `overlord` and `champion`
+/// agents concurrently, using at most one worker thread per available CPU.
+///
+/// Every game starts from a copy of `start`, reseeded with a distinct random
+/// seed if the game is deterministic. A game which has not ended after
+/// `max_actions` actions is counted as a draw.
+pub fn run_games_parallel<O, C>(
+    start: &GameState,
+    overlord: &O,
+    champion: &C,
+    games: u64,
+    max_actions: usize,
+) -> Result<TournamentResult>
+where
+    O: Agent<SpelldawnState> + Sync,
+    C: Agent<SpelldawnState> + Sync,
+{
+    let base_seed = start.data.config.seed.unwrap_or(DEFAULT_SEED);
+    let workers = thread::available_parallelism().map_or(1, |n| n.get() as u64).min(games);
+    let next_game = AtomicU64::new(0);
+    let winners = thread::scope(|scope| {
+        let handles = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut winners = vec![];
+                    loop {
+                        let i = next_game.fetch_add(1, Ordering::Relaxed);
+                        if i >= games {
+                            return Ok(winners);
+                        }
+                        let mut game = start.clone();
+                        random::reseed(&mut game, base_seed.wrapping_add(i));
+                        winners.push(run_game(game, overlord, champion, max_actions)?);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().ok().with_error(|| "Game thread panicked")?)
+            .collect::<Result<Vec<Vec<_>>>>()
+    })?;
+
+    let mut result = TournamentResult::default();
+    for winner in winners.into_iter().flatten() {
+        result.record(winner);
+    }
+    Ok(result)
+}
+
+/// Plays a single game to completion, returning the winner or None if the
+/// game did not end within `max_actions` actions.
+fn run_game(
+    game: GameState,
+    overlord: &impl Agent<SpelldawnState>,
+    champion: &impl Agent<SpelldawnState>,
+    max_actions: usize,
+) -> Result<Option<Side>> {
+    let mut state = SpelldawnState(game);
+    for _ in 0..max_actions {
+        let GameStatus::InProgress { current_turn } = state.status() else {
+            break;
+        };
+        let action = if current_turn == Side::Overlord {
+            pick_action(overlord, &mut state, current_turn)
+        } else {
+            pick_action(champion, &mut state, current_turn)
+        }?;
+        state.execute_action(current_turn, action)?;
+    }
+
+    match state.status() {
+        GameStatus::Completed { winner } => Ok(Some(winner)),
+        GameStatus::InProgress { .. } => Ok(None),
+    }
+}
+
+/// Selects an action for the `side` player via `agent`, allowing the agent to
+/// record any state it needs for its next action.
+fn pick_action(
+    agent: &impl Agent<SpelldawnState>,
+    state: &mut SpelldawnState,
+    side: Side,
+) -> Result<UserAction> {
+    let action = agent.pick_action(AgentConfig::with_time_budget(agent.time_budget()), state)?;
+    agent.record_action(state, side, action)?;
+    Ok(action)
+}
@@ -20,6 +20,7 @@ use ai_core::game_state_node::{GameStateNode, GameStatus};
 use ai_core::selection_algorithm::SelectionAlgorithm;
 use ai_core::state_evaluator::StateEvaluator;
 use anyhow::Result;
+use with_error::WithError;
 
 use crate::scored_action::ScoredAction;
 
@@ -28,6 +29,10 @@ use crate::scored_action::ScoredAction;
 /// This is a 'fail soft' implementation per wikipedia. I have not been able to
 /// detect any performance or gameplay difference with the 'fail hard' version.
 ///
+/// Searches are run via iterative deepening up to `search_depth`, so if the
+/// deadline is exceeded the action selected by the deepest completed search is
+/// returned.
+///
 /// See <https://en.wikipedia.org/wiki/Alpha-beta_pruning>
 pub struct AlphaBetaAlgorithm {
     pub search_depth: u32,
@@ -46,8 +51,17 @@ impl SelectionAlgorithm for AlphaBetaAlgorithm {
         E: StateEvaluator<N>,
     {
         assert!(matches!(node.status(), GameStatus::InProgress { .. }));
-        run_internal(config, node, evaluator, self.search_depth, player, i32::MIN, i32::MAX)?
-            .action()
+        let mut best = None;
+        for depth in 1..=self.search_depth {
+            let result = run_internal(config, node, evaluator, depth, player, i32::MIN, i32::MAX)?;
+            if best.is_some() && config.deadline < Instant::now() {
+                // Results from a search which was interrupted by the deadline are
+                // incomplete, prefer the previous depth's result.
+                break;
+            }
+            best = Some(result.action()?);
+        }
+        best.with_error(|| "Search depth must be positive")
     }
 }
 
@@ -0,0 +1,128 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{Duration, Instant};
+
+use ai_core::agent::{Agent, AgentConfig, DEFAULT_TIME_BUDGET};
+use ai_core::game_state_node::{GameStateNode, GameStatus};
+use ai_core::state_evaluator::StateEvaluator;
+use ai_core::state_predictor::StatePredictor;
+use anyhow::Result;
+use with_error::{fail, verify, WithError};
+
+/// An agent for games with hidden information which selects the action with
+/// the highest expected value across the possible states of the game.
+///
+/// Up to `samples` possible game states are requested from the `predictor`.
+/// Each legal action is applied to every sampled state and the resulting
+/// `evaluator` scores are averaged. This is a single level of expectimax
+/// search in which the chance node is the hidden game state.
+pub struct ExpectimaxAgent<TEvaluator, TNode>
+where
+    TEvaluator: StateEvaluator<TNode>,
+    TNode: GameStateNode + 'static,
+{
+    /// Name of this agent, used for debugging.
+    pub name: &'static str,
+    /// Produces the possible game states to average over.
+    pub predictor: StatePredictor<TNode>,
+    /// Produces a numeric score ranking the desirability of a game state.
+    pub evaluator: TEvaluator,
+    /// Maximum number of predicted game states to evaluate each action in.
+    pub samples: usize,
+    /// Amount of wall-clock time this agent should be given to select an
+    /// action. The best action found so far is returned once this budget is
+    /// exceeded.
+    pub time_budget: Duration,
+}
+
+impl<TEvaluator, TNode> ExpectimaxAgent<TEvaluator, TNode>
+where
+    TEvaluator: StateEvaluator<TNode>,
+    TNode: GameStateNode + 'static,
+{
+    pub const fn new(
+        name: &'static str,
+        predictor: StatePredictor<TNode>,
+        evaluator: TEvaluator,
+        samples: usize,
+    ) -> Self {
+        Self { name, predictor, evaluator, samples, time_budget: DEFAULT_TIME_BUDGET }
+    }
+
+    /// Returns the average evaluation for `player` of taking `action` in each
+    /// of the `states`, or None if the action could not be applied to any of
+    /// them.
+    fn expected_value(
+        &self,
+        states: &[TNode],
+        player: TNode::PlayerName,
+        action: TNode::Action,
+    ) -> Result<Option<f64>> {
+        let mut total = 0.0;
+        let mut count = 0_u32;
+        for state in states {
+            let mut child = state.make_copy();
+            if child.execute_action(player, action).is_err() {
+                // The action may be illegal in a predicted state which differs
+                // from the actual one.
+                continue;
+            }
+            total += f64::from(self.evaluator.evaluate(&child, player)?);
+            count += 1;
+        }
+        Ok((count > 0).then(|| total / f64::from(count)))
+    }
+}
+
+impl<TEvaluator, TNode> Agent<TNode> for ExpectimaxAgent<TEvaluator, TNode>
+where
+    TEvaluator: StateEvaluator<TNode>,
+    TNode: GameStateNode + 'static,
+{
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn pick_action(&self, config: AgentConfig, node: &TNode) -> Result<TNode::Action> {
+        let player = match node.status() {
+            GameStatus::InProgress { current_turn } => current_turn,
+            _ => fail!("Game is over"),
+        };
+        let states = (self.predictor)(node).take(self.samples).collect::<Vec<_>>();
+        verify!(!states.is_empty(), "No predicted states");
+
+        let mut best: Option<(f64, TNode::Action)> = None;
+        for action in node.legal_actions(player)? {
+            if best.is_some() && config.deadline < Instant::now() {
+                if config.panic_on_search_timeout {
+                    panic!("Search deadline exceeded!");
+                }
+                break;
+            }
+
+            if let Some(value) = self.expected_value(&states, player, action)? {
+                if best.is_none_or(|(best_value, _)| value > best_value) {
+                    best = Some((value, action));
+                }
+            }
+        }
+
+        best.map(|(_, action)| action).with_error(|| "No legal actions found")
+    }
+
+    fn time_budget(&self) -> Duration {
+        self.time_budget
+    }
+}
@@ -17,6 +17,7 @@
 extern crate core;
 
 pub mod alpha_beta;
+pub mod expectimax;
 pub mod minimax;
 pub mod scored_action;
 pub mod single_level;
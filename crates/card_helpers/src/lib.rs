@@ -20,12 +20,13 @@ pub mod text_macro;
 
 use anyhow::Result;
 use data::card_definition::{
-    Ability, AbilityType, AttackBoost, CardStats, Cost, CustomCost, SchemePoints, SpecialEffects,
+    Ability, AbilityType, AttackBoost, CardStats, Cost, CustomCost, ManaCost, SchemePoints,
+    SpecialEffects,
 };
 use data::card_state::CardPosition;
 use data::delegates::{
-    AbilityActivated, CardPlayed, Delegate, EventDelegate, MutationFn, QueryDelegate, RaidEnded,
-    RaidStart, RequirementFn, Scope, TransformationFn, UsedWeapon,
+    AbilityActivated, CardPlayed, CardRevealed, Delegate, EventDelegate, MutationFn, QueryDelegate,
+    RaidEnded, RaidStart, RequirementFn, RoomChosen, Scope, TransformationFn, UsedWeapon,
 };
 use data::game::GameState;
 use data::game_actions::{CardPromptAction, CardTarget};
@@ -63,13 +64,13 @@ pub fn text_only_ability(text: AbilityText) -> Ability {
 
 /// A [Cost] which requires no mana and `actions` action points.
 pub fn actions(actions: ActionCount) -> Cost<AbilityId> {
-    Cost { mana: None, actions, custom_cost: None }
+    Cost { mana: ManaCost::None, actions, custom_cost: None }
 }
 
 /// Provides the cost for a card, with 1 action point required and `mana` mana
 /// points
 pub fn cost(mana: ManaValue) -> Cost<CardId> {
-    Cost { mana: Some(mana), actions: 1, custom_cost: None }
+    Cost { mana: ManaCost::Fixed(mana), actions: 1, custom_cost: None }
 }
 
 /// [Cost] for an identity card
@@ -79,7 +80,13 @@ pub fn identity_cost() -> Cost<CardId> {
 
 /// [Cost] for a scheme card
 pub fn scheme_cost() -> Cost<CardId> {
-    Cost { mana: None, actions: 1, custom_cost: None }
+    Cost { mana: ManaCost::None, actions: 1, custom_cost: None }
+}
+
+/// Provides the cost for a card which costs 'X' mana and 1 action point, where
+/// X is chosen by the player when the card is played
+pub fn variable_cost() -> Cost<CardId> {
+    Cost { mana: ManaCost::Variable, actions: 1, custom_cost: None }
 }
 
 /// A [CustomCost] which allows an ability to be activated once per turn.
@@ -173,6 +180,18 @@ pub fn on_activated(mutation: MutationFn<AbilityActivated>) -> Delegate {
     Delegate::ActivateAbility(EventDelegate { requirement: this_ability, mutation })
 }
 
+/// A [Delegate] which triggers when a room is chosen for this ability via a
+/// [data::game_actions::PromptContext::ChooseRoom] prompt
+pub fn on_room_chosen(mutation: MutationFn<RoomChosen>) -> Delegate {
+    Delegate::RoomChosen(EventDelegate { requirement: this_ability, mutation })
+}
+
+/// A [Delegate] which triggers when this card is revealed to a player via
+/// `mutations::reveal_card`
+pub fn when_revealed(mutation: MutationFn<CardRevealed>) -> Delegate {
+    Delegate::RevealCard(EventDelegate { requirement: this_card, mutation })
+}
+
 pub fn when_unveiled(mutation: MutationFn<CardId>) -> Delegate {
     Delegate::UnveilProject(EventDelegate { requirement: this_card, mutation })
 }
@@ -17,7 +17,7 @@
 use card_helpers::text_macro::text;
 use card_helpers::{abilities, *};
 use data::card_definition::{
-    Ability, AbilityType, CardConfig, CardDefinition, Cost, TargetRequirement,
+    Ability, AbilityType, CardConfig, CardDefinition, Cost, ManaCost, TargetRequirement,
 };
 use data::card_name::CardName;
 use data::delegates::{Delegate, EventDelegate};
@@ -194,7 +194,7 @@ pub fn magical_resonator() -> CardDefinition {
                     "Use this ability only once per turn."
                 ],
                 ability_type: AbilityType::Activated(
-                    Cost { mana: None, actions: 1, custom_cost: once_per_turn_cost() },
+                    Cost { mana: ManaCost::None, actions: 1, custom_cost: once_per_turn_cost() },
                     TargetRequirement::None,
                 ),
                 delegates: vec![on_activated(|g, _s, activated| {
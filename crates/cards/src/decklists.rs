@@ -12,17 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use actions;
 use anyhow::Result;
 use data::card_name::CardName;
 use data::deck::Deck;
 use data::game::{GameConfiguration, GameState, MulliganDecision};
 use data::game_actions::{PromptAction, UserAction};
+use data::player_data::SideboardSwap;
 use data::player_name::{NamedPlayer, PlayerId};
-use data::primitives::{GameId, Side};
+use data::primitives::{CardType, DeckId, GameId, Side};
 use maplit::hashmap;
 use once_cell::sync::Lazy;
 use rules::{dispatch, mutations};
+use with_error::{verify, WithError};
 
 /// Standard Overlord deck for use in tests
 pub static CANONICAL_OVERLORD: Lazy<Deck> = Lazy::new(|| Deck {
@@ -83,6 +87,79 @@ pub fn canonical_deck(player_id: PlayerId, side: Side) -> Deck {
     }
 }
 
+/// Returns the starter deck given to players who have no saved decks. By
+/// convention, deck ID 0 is an Overlord deck and deck ID 1 is a Champion deck.
+/// Returns None for any other [DeckId].
+pub fn starter_deck(player_id: PlayerId, deck_id: DeckId) -> Option<Deck> {
+    let side = match deck_id.value {
+        0 => Side::Overlord,
+        1 => Side::Champion,
+        _ => return None,
+    };
+    Some(canonical_deck(player_id, side))
+}
+
+/// Maximum number of copies of a card which can be included in a deck.
+pub const MAXIMUM_COPIES: u32 = 3;
+
+/// Checks that a deck can be used to play a game: its identity must be an
+/// identity card for the deck's side, and every other card must be a
+/// non-identity card for that side with at most [MAXIMUM_COPIES] copies.
+pub fn validate(deck: &Deck) -> Result<()> {
+    let identity = rules::get(deck.identity);
+    verify!(identity.card_type == CardType::Identity, "Invalid identity {:?}", deck.identity);
+    verify!(identity.side == deck.side, "Identity {:?} has wrong side", deck.identity);
+    verify!(!deck.cards.is_empty(), "Deck has no cards");
+    for (name, count) in &deck.cards {
+        let definition = rules::get(*name);
+        verify!(definition.card_type != CardType::Identity, "Extra identity {:?}", name);
+        verify!(definition.side == deck.side, "Card {:?} has wrong side", name);
+        verify!(*count <= MAXIMUM_COPIES, "Too many copies of {:?}", name);
+    }
+    Ok(())
+}
+
+/// Returns a copy of the `deck_id` deck with each of the provided
+/// [SideboardSwap]s for that deck applied, exchanging cards with `sideboard`.
+///
+/// Returns an error if a swap removes a card which is not in the deck, adds a
+/// card which is not available in the sideboard, or produces a deck which
+/// fails [validate]. The deck is returned unchanged if there are no swaps for
+/// it.
+pub fn apply_sideboard_swaps(
+    deck: &Deck,
+    deck_id: DeckId,
+    sideboard: &HashMap<CardName, u32>,
+    swaps: &[SideboardSwap],
+) -> Result<Deck> {
+    let mut result = deck.clone();
+    let mut swaps = swaps.iter().filter(|swap| swap.deck_id == deck_id).peekable();
+    if swaps.peek().is_none() {
+        return Ok(result);
+    }
+
+    let mut sideboard = sideboard.clone();
+    for swap in swaps {
+        remove_copy(&mut result.cards, swap.remove)
+            .with_error(|| format!("Deck does not contain {:?}", swap.remove))?;
+        remove_copy(&mut sideboard, swap.add)
+            .with_error(|| format!("Sideboard does not contain {:?}", swap.add))?;
+        *result.cards.entry(swap.add).or_insert(0) += 1;
+        *sideboard.entry(swap.remove).or_insert(0) += 1;
+    }
+    validate(&result)?;
+    Ok(result)
+}
+
+fn remove_copy(cards: &mut HashMap<CardName, u32>, name: CardName) -> Option<()> {
+    let count = cards.get_mut(&name).filter(|count| **count > 0)?;
+    *count -= 1;
+    if *count == 0 {
+        cards.remove(&name);
+    }
+    Some(())
+}
+
 /// Creates a new deterministic game using the canonical decklists, deals
 /// opening hands and resolves mulligans.
 pub fn canonical_game() -> Result<GameState> {
@@ -90,7 +167,7 @@ pub fn canonical_game() -> Result<GameState> {
         GameId::new(0),
         CANONICAL_OVERLORD.clone(),
         CANONICAL_CHAMPION.clone(),
-        GameConfiguration { deterministic: true, simulation: true },
+        GameConfiguration { deterministic: true, simulation: true, ..GameConfiguration::default() },
     );
 
     dispatch::populate_delegate_cache(&mut game);
@@ -64,6 +64,20 @@ pub fn run() {
     DEFINITIONS.insert(test_cards::test_0_cost_champion_spell);
     DEFINITIONS.insert(test_cards::test_1_cost_champion_spell);
     DEFINITIONS.insert(test_cards::test_attack_weapon);
+    DEFINITIONS.insert(test_cards::test_champion_spell_extra_turn);
+    DEFINITIONS.insert(test_cards::test_mana_battery);
+    DEFINITIONS.insert(test_cards::test_champion_spell_banish_discard);
+    DEFINITIONS.insert(test_cards::test_variable_cost_spell);
+    DEFINITIONS.insert(test_cards::test_champion_spell_distribute_damage);
+    DEFINITIONS.insert(test_cards::test_champion_spell_cast_fails);
+    DEFINITIONS.insert(test_cards::test_variable_cost_spell_cast_fails);
+    DEFINITIONS.insert(test_cards::test_champion_spell_requires_minion);
+    DEFINITIONS.insert(test_cards::test_champion_spell_reveal_hand);
+    DEFINITIONS.insert(test_cards::test_overlord_spell_draw_revealed);
+    DEFINITIONS.insert(test_cards::test_unique_artifact);
+    DEFINITIONS.insert(test_cards::test_project_raid_response);
+    DEFINITIONS.insert(test_cards::test_overlord_spell_choose_room);
+    DEFINITIONS.insert(test_cards::test_minion_gain_mana_when_revealed);
     DEFINITIONS.insert(weapons::marauders_axe);
     DEFINITIONS.insert(weapons::keen_halberd);
     DEFINITIONS.insert(weapons::ethereal_blade);
@@ -169,7 +169,7 @@ pub fn sphinx_of_winters_breath() -> CardDefinition {
                     requirement: |g, s, data| {
                         s.ability_id() == data.source
                             && data.discarded.iter().any(|card_id| {
-                                queries::mana_cost(g, *card_id).unwrap_or(0) % 2 != 0
+                                !queries::mana_cost(g, *card_id).unwrap_or(0).is_multiple_of(2)
                             })
                     },
                     mutation: |g, _, _| mutations::end_raid(g, RaidOutcome::Failure),
@@ -14,15 +14,19 @@
 
 use card_helpers::{abilities, text, *};
 use data::card_definition::{
-    Ability, AbilityType, AttackBoost, CardConfig, CardDefinition, CardStats, SchemePoints,
-    SpecialEffects,
+    Ability, AbilityType, AttackBoost, CardConfig, CardDefinition, CardStats, Cost, CustomCost,
+    ManaCost, SchemePoints, SpecialEffects, TargetRequirement,
 };
 use data::card_name::CardName;
-use data::primitives::{CardType, HealthValue, Lineage, ManaValue, Rarity, School, Side, Sprite};
+use data::game_actions::{GamePrompt, MAXIMUM_DAMAGE_TARGETS};
+use data::primitives::{
+    CardType, HealthValue, Lineage, ManaValue, Rarity, RoomId, School, Side, Sprite,
+};
 use data::special_effects::{Projectile, TimedEffect};
 use data::text::{Keyword, Sentence};
-use rules::mutations;
 use rules::mutations::OnZeroStored;
+use rules::{mana, mutations, queries};
+use with_error::fail;
 
 pub const MINION_COST: ManaValue = 3;
 pub const WEAPON_COST: ManaValue = 3;
@@ -442,3 +446,235 @@ pub fn test_attack_weapon() -> CardDefinition {
         ..test_champion_spell()
     }
 }
+
+pub fn test_champion_spell_extra_turn() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestChampionSpellExtraTurn,
+        cost: cost(0),
+        abilities: vec![simple_ability(
+            text!("Take an extra turn after this one"),
+            on_cast(|g, s, _| mutations::grant_extra_turn(g, s.side())),
+        )],
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_mana_battery() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestManaBattery,
+        cost: cost(ARTIFACT_COST),
+        card_type: CardType::Artifact,
+        abilities: vec![
+            simple_ability(
+                text![Keyword::Dawn, Keyword::Store(Sentence::Start, 1)],
+                at_dawn(|g, s, _| mutations::store_mana(g, s.card_id(), 1).map(|_| ())),
+            ),
+            Ability {
+                text: text!["Take all stored mana"],
+                ability_type: AbilityType::Activated(actions(1), TargetRequirement::None),
+                delegates: vec![on_activated(|g, _s, activated| {
+                    let stored = queries::stored_mana(g, activated.card_id());
+                    mutations::withdraw_mana(g, activated.card_id(), stored).map(|_| ())
+                })],
+            },
+        ],
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_champion_spell_banish_discard() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestChampionSpellBanishDiscard,
+        cost: cost(0),
+        abilities: vec![simple_ability(
+            text!("Banish a card in the Overlord's discard pile"),
+            on_cast(|g, _, played| mutations::banish(g, played.target.card_id()?)),
+        )],
+        config: CardConfig {
+            custom_targeting: Some(TargetRequirement::TargetCard(|g, card_id, target| {
+                queries::opponent_discard(g, card_id.side).any(|c| c.id == target)
+            })),
+            ..CardConfig::default()
+        },
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_variable_cost_spell() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestVariableCostSpell,
+        cost: variable_cost(),
+        abilities: vec![simple_ability(
+            text!("Draw X cards"),
+            on_cast(|g, s, _| {
+                mutations::draw_cards(g, s.side(), queries::x_value(g, s.card_id())).map(|_| ())
+            }),
+        )],
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_champion_spell_distribute_damage() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestChampionSpellDistributeDamage,
+        cost: cost(0),
+        abilities: vec![simple_ability(
+            text!("Deal 3 damage divided among up to 4 minions"),
+            on_cast(|g, s, _| {
+                let targets =
+                    g.minions().map(|c| c.id).take(MAXIMUM_DAMAGE_TARGETS).collect::<Vec<_>>();
+                if !targets.is_empty() {
+                    g.player_mut(s.side()).prompt =
+                        Some(GamePrompt::distribute_damage(3, targets)?);
+                }
+                Ok(())
+            }),
+        )],
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_champion_spell_cast_fails() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestChampionSpellCastFails,
+        cost: cost(2),
+        abilities: vec![simple_ability(
+            text!("This spell cannot be cast"),
+            on_cast(|_, _, _| fail!("Cast event failed")),
+        )],
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_variable_cost_spell_cast_fails() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestVariableCostSpellCastFails,
+        cost: variable_cost(),
+        ..test_champion_spell_cast_fails()
+    }
+}
+
+pub fn test_champion_spell_requires_minion() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestChampionSpellRequiresMinion,
+        cost: Cost {
+            custom_cost: Some(CustomCost {
+                can_pay: |g, _| g.minions().next().is_some(),
+                pay: |_, _| Ok(()),
+            }),
+            ..cost(1)
+        },
+        abilities: vec![text_only_ability(text!("Play only if the Overlord has a minion in play"))],
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_champion_spell_reveal_hand() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestChampionSpellRevealHand,
+        cost: cost(0),
+        abilities: vec![simple_ability(
+            text!("Reveal the Overlord's hand"),
+            on_cast(|g, s, _| {
+                let hand = g.hand(Side::Overlord).map(|c| c.id).collect::<Vec<_>>();
+                for card_id in hand {
+                    mutations::reveal_card(g, card_id, s.side())?;
+                }
+                Ok(())
+            }),
+        )],
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_overlord_spell_draw_revealed() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestOverlordSpellDrawRevealed,
+        cost: cost(0),
+        abilities: vec![simple_ability(
+            text!("Draw a card and reveal it. If it is a minion, gain 2 mana"),
+            on_cast(|g, s, _| {
+                if let Some(card_id) = mutations::draw_revealed(g, s.side())? {
+                    if rules::get(g.card(card_id).name).card_type == CardType::Minion {
+                        mana::gain(g, s.side(), 2);
+                    }
+                }
+                Ok(())
+            }),
+        )],
+        ..test_overlord_spell()
+    }
+}
+
+pub fn test_unique_artifact() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestUniqueArtifact,
+        cost: cost(0),
+        card_type: CardType::Artifact,
+        config: CardConfig { unique: true, ..CardConfig::default() },
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_project_raid_response() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestProjectRaidResponse,
+        cost: cost(UNVEIL_COST),
+        card_type: CardType::Project,
+        abilities: vec![Ability {
+            text: text!["Gain", mana_text(2)],
+            ability_type: AbilityType::Activated(
+                Cost { mana: ManaCost::Fixed(1), ..actions(0) },
+                TargetRequirement::None,
+            ),
+            delegates: vec![on_activated(|g, s, _| {
+                mana::gain(g, s.side(), 2);
+                Ok(())
+            })],
+        }],
+        ..test_overlord_spell()
+    }
+}
+
+pub fn test_overlord_spell_choose_room() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestOverlordSpellChooseRoom,
+        cost: cost(0),
+        abilities: vec![Ability {
+            text: text!("Choose an outer room. Level up its occupants"),
+            ability_type: AbilityType::Standard,
+            delegates: vec![
+                on_cast(|g, s, _| {
+                    let valid = vec![
+                        RoomId::RoomA,
+                        RoomId::RoomB,
+                        RoomId::RoomC,
+                        RoomId::RoomD,
+                        RoomId::RoomE,
+                    ];
+                    g.player_mut(s.side()).prompt =
+                        Some(GamePrompt::choose_room(s.ability_id(), valid));
+                    Ok(())
+                }),
+                on_room_chosen(|g, _, data| mutations::level_up_room(g, data.room_id)),
+            ],
+        }],
+        ..test_overlord_spell()
+    }
+}
+
+pub fn test_minion_gain_mana_when_revealed() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestMinionGainManaWhenRevealed,
+        abilities: vec![simple_ability(
+            text!("When revealed to the Champion, gain", mana_text(1)),
+            when_revealed(|g, s, revealed| {
+                if revealed.to == Side::Champion {
+                    mana::gain(g, s.side(), 1);
+                }
+                Ok(())
+            }),
+        )],
+        ..test_minion_end_raid()
+    }
+}
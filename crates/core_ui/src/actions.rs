@@ -14,7 +14,7 @@
 
 use std::fmt::Debug;
 
-use data::game_actions::{DebugAction, PromptAction, UserAction};
+use data::game_actions::{DebugAction, PromptAction, UserAction, USER_ACTION_PAYLOAD_VERSION};
 use protos::spelldawn::game_action::Action;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::{CommandList, GameCommand, StandardAction};
@@ -91,8 +91,11 @@ impl InterfaceAction for Vec<Command> {
     }
 }
 
+/// Serializes a [UserAction], prefixed with the [USER_ACTION_PAYLOAD_VERSION].
 fn payload(action: UserAction) -> Vec<u8> {
-    ser::to_vec(&action).expect("Serialization failed")
+    let mut result = vec![USER_ACTION_PAYLOAD_VERSION];
+    result.extend(ser::to_vec(&action).expect("Serialization failed"));
+    result
 }
 
 fn command_list(commands: Vec<Command>) -> CommandList {
@@ -0,0 +1,101 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protos::spelldawn::{FlexAlign, FlexJustify, FlexWrap};
+
+use crate::actions::InterfaceAction;
+use crate::prelude::*;
+
+/// Lays out a list of cells, typically cards, in a wrapping grid with a fixed
+/// number of columns. Used for displaying card collections such as a deck
+/// browser.
+#[derive(Debug)]
+pub struct CardGrid {
+    name: String,
+    columns: u32,
+    layout: Layout,
+    cells: Vec<GridCell>,
+}
+
+#[derive(Debug)]
+struct GridCell {
+    content: Box<dyn Component>,
+    on_click: Option<Box<dyn InterfaceAction>>,
+}
+
+impl CardGrid {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), columns: 4, layout: Layout::default(), cells: vec![] }
+    }
+
+    /// Number of cells to display in each row of the grid. Defaults to 4.
+    pub fn columns(mut self, columns: u32) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Adds a new cell to the grid displaying `content`.
+    pub fn cell(mut self, content: impl Component + 'static) -> Self {
+        self.cells.push(GridCell { content: Box::new(content), on_click: None });
+        self
+    }
+
+    /// Adds a new cell to the grid displaying `content` which invokes
+    /// `on_click` when clicked.
+    pub fn clickable_cell(
+        mut self,
+        content: impl Component + 'static,
+        on_click: impl InterfaceAction + 'static,
+    ) -> Self {
+        self.cells
+            .push(GridCell { content: Box::new(content), on_click: Some(Box::new(on_click)) });
+        self
+    }
+}
+
+impl Component for CardGrid {
+    fn build(self) -> RenderResult {
+        let cell_width = 100.0 / self.columns.max(1) as f32;
+        Row::new(self.name)
+            .style(
+                self.layout
+                    .to_style()
+                    .wrap(FlexWrap::Wrap)
+                    .justify_content(FlexJustify::FlexStart)
+                    .align_items(FlexAlign::FlexStart),
+            )
+            .children(self.cells.into_iter().enumerate().map(|(i, cell)| {
+                let column = Column::new(format!("Cell{}", i))
+                    .style(
+                        Style::new()
+                            .width(cell_width.pct())
+                            .flex_shrink(0.0)
+                            .align_items(FlexAlign::Center)
+                            .justify_content(FlexJustify::Center),
+                    )
+                    .child_boxed(cell.content);
+                if let Some(action) = cell.on_click {
+                    column.on_click(action)
+                } else {
+                    column
+                }
+            }))
+            .build()
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protos::spelldawn::{FlexAlign, FlexJustify, TextAlign};
+
+use crate::actions::{InterfaceAction, NoAction};
+use crate::button::{Button, ButtonType};
+use crate::design::{FontColor, FontSize};
+use crate::prelude::*;
+use crate::text::Text;
+
+/// Asks the user to confirm or cancel an action, e.g. before resigning a game.
+///
+/// Renders a title and message above a primary 'confirm' button and a
+/// secondary 'cancel' button.
+#[derive(Debug)]
+pub struct ConfirmationPrompt {
+    title: String,
+    message: String,
+    confirm_label: String,
+    cancel_label: String,
+    confirm_action: Box<dyn InterfaceAction>,
+    cancel_action: Box<dyn InterfaceAction>,
+    layout: Layout,
+}
+
+impl ConfirmationPrompt {
+    pub fn new(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            confirm_label: "Confirm".to_string(),
+            cancel_label: "Cancel".to_string(),
+            confirm_action: Box::new(NoAction {}),
+            cancel_action: Box::new(NoAction {}),
+            layout: Layout::default(),
+        }
+    }
+
+    pub fn confirm_label(mut self, label: impl Into<String>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    pub fn cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    pub fn confirm_action(mut self, action: impl InterfaceAction + 'static) -> Self {
+        self.confirm_action = Box::new(action);
+        self
+    }
+
+    pub fn cancel_action(mut self, action: impl InterfaceAction + 'static) -> Self {
+        self.cancel_action = Box::new(action);
+        self
+    }
+
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+}
+
+impl Component for ConfirmationPrompt {
+    fn build(self) -> RenderResult {
+        Column::new(format!("ConfirmationPrompt {}", self.title))
+            .style(self.layout.to_style().align_items(FlexAlign::Center))
+            .child(
+                Text::new(self.title, FontSize::Headline)
+                    .color(FontColor::PanelTitle)
+                    .text_align(TextAlign::MiddleCenter)
+                    .layout(Layout::new().margin(Edge::Bottom, 16.px())),
+            )
+            .child(
+                Text::new(self.message, FontSize::SupplementalInfo)
+                    .text_align(TextAlign::MiddleCenter)
+                    .layout(Layout::new().margin(Edge::Bottom, 32.px())),
+            )
+            .child(
+                Row::new("ConfirmationButtons")
+                    .style(Style::new().justify_content(FlexJustify::Center))
+                    .child(
+                        Button::new(self.cancel_label)
+                            .button_type(ButtonType::Secondary)
+                            .action(self.cancel_action)
+                            .layout(Layout::new().margin(Edge::Horizontal, 16.px())),
+                    )
+                    .child(
+                        Button::new(self.confirm_label)
+                            .action(self.confirm_action)
+                            .layout(Layout::new().margin(Edge::Horizontal, 16.px())),
+                    ),
+            )
+            .build()
+    }
+}
@@ -16,7 +16,9 @@
 
 pub mod actions;
 pub mod button;
+pub mod card_grid;
 pub mod component;
+pub mod confirmation;
 pub mod design;
 pub mod flexbox;
 pub mod icons;
@@ -21,6 +21,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Hash)]
 pub enum GameStatePredictorName {
     Omniscient,
+    Determinized,
 }
 
 /// Identifies different possible Agents. See the 'agents' crate for more
@@ -31,6 +32,8 @@ pub enum AgentName {
     PickRandom,
     AlphaBeta,
     MonteCarlo,
+    Scripted,
+    Expectimax,
 }
 
 /// Primary configuration for an AI Agent. See the 'agents' crate for more
@@ -39,4 +42,8 @@ pub enum AgentName {
 pub struct AgentData {
     pub name: AgentName,
     pub state_predictor: GameStatePredictorName,
+    /// Index of the next scripted action to attempt for an
+    /// [AgentName::Scripted] agent.
+    #[serde(default)]
+    pub script_position: usize,
 }
@@ -57,11 +57,33 @@ impl<T> Debug for CustomCost<T> {
     }
 }
 
+/// Mana portion of a [Cost]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ManaCost {
+    /// No mana cost, e.g. for schemes
+    None,
+    /// A fixed amount of mana
+    Fixed(ManaValue),
+    /// 'X' mana, where X is chosen by the player when paying this cost
+    Variable,
+}
+
+impl ManaCost {
+    /// Returns the amount of mana for a [ManaCost::Fixed] cost, or None
+    /// otherwise.
+    pub fn value(self) -> Option<ManaValue> {
+        match self {
+            Self::Fixed(mana) => Some(mana),
+            _ => None,
+        }
+    }
+}
+
 /// Cost to play, unveil, or summon a card, or activate an ability
 #[derive(Debug, Clone)]
 pub struct Cost<T> {
     /// Cost in mana
-    pub mana: Option<ManaValue>,
+    pub mana: ManaCost,
     /// Cost in action points
     pub actions: ActionCount,
     /// A custom cost or requirement to play this card/activate this ability.
@@ -71,7 +93,7 @@ pub struct Cost<T> {
 
 impl<T> Default for Cost<T> {
     fn default() -> Self {
-        Self { mana: None, actions: 1, custom_cost: None }
+        Self { mana: ManaCost::None, actions: 1, custom_cost: None }
     }
 }
 
@@ -114,6 +136,8 @@ pub struct CardStats {
 
 pub type RoomPredicate<T> = fn(&GameState, T, RoomId) -> bool;
 
+pub type CardPredicate<T> = fn(&GameState, T, CardId) -> bool;
+
 /// Allows cards and abilities to provide special targeting behavior.
 #[derive(Clone, EnumKind)]
 #[enum_kind(TargetRequirementKind)]
@@ -123,6 +147,9 @@ pub enum TargetRequirement<T> {
     /// Target a specific room when played. Only rooms for which the provided
     /// [RoomPredicate] returns true are considered valid targets.
     TargetRoom(RoomPredicate<T>),
+    /// Target a specific card when played. Only cards for which the provided
+    /// [CardPredicate] returns true are considered valid targets.
+    TargetCard(CardPredicate<T>),
 }
 
 impl<T> Debug for TargetRequirement<T> {
@@ -177,6 +204,9 @@ pub struct CardConfig {
     pub subtypes: Vec<CardSubtype>,
     pub custom_targeting: Option<TargetRequirement<CardId>>,
     pub special_effects: SpecialEffects,
+    /// If true, a player can only have one copy of this card in play. When a
+    /// second copy enters play, the older copy is discarded.
+    pub unique: bool,
 }
 
 /// The fundamental object defining the behavior of a given card in Spelldawn
@@ -207,4 +237,18 @@ impl CardDefinition {
     pub fn ability_ids(&self, card_id: CardId) -> impl Iterator<Item = AbilityId> {
         (0..self.abilities.len()).map(move |i| AbilityId::new(card_id, i))
     }
+
+    /// Returns true if this card is revealed to both players when played and
+    /// pays its costs immediately. Cards which enter play face-down remain
+    /// hidden from the opponent until they are unveiled or summoned.
+    pub fn enters_face_up(&self) -> bool {
+        match self.card_type {
+            CardType::ChampionSpell
+            | CardType::Weapon
+            | CardType::Artifact
+            | CardType::OverlordSpell
+            | CardType::Identity => true,
+            CardType::Minion | CardType::Project | CardType::Scheme => false,
+        }
+    }
 }
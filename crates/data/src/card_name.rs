@@ -82,6 +82,42 @@ pub enum CardName {
     TestMinionDealDamageEndRaid,
     TestCardStoredMana,
     TestAttackWeapon,
+    /// Champion spell which grants an extra turn after the current one
+    TestChampionSpellExtraTurn,
+    /// Artifact which stores 1 mana at dawn, with the activated ability to
+    /// withdraw all stored mana
+    TestManaBattery,
+    /// Champion spell which banishes a target card from the Overlord's discard
+    /// pile
+    TestChampionSpellBanishDiscard,
+    /// Champion spell with a cost of X mana which draws X cards
+    TestVariableCostSpell,
+    /// Champion spell which deals 3 damage divided among minions
+    TestChampionSpellDistributeDamage,
+    /// Champion spell whose cast event handler always returns an error
+    TestChampionSpellCastFails,
+    /// Champion spell with a cost of X mana whose cast event handler always
+    /// returns an error
+    TestVariableCostSpellCastFails,
+    /// Champion spell which can only be played while the Overlord has a minion
+    /// in play
+    TestChampionSpellRequiresMinion,
+    /// Champion spell which reveals the cards in the Overlord's hand
+    TestChampionSpellRevealHand,
+    /// Overlord spell which draws a revealed card and gains mana if it is a
+    /// minion
+    TestOverlordSpellDrawRevealed,
+    /// Unique Champion artifact with no abilities
+    TestUniqueArtifact,
+    /// Overlord project with an ability which costs no actions and can be
+    /// activated in response to a raid
+    TestProjectRaidResponse,
+    /// Overlord spell which levels up the occupants of an outer room of the
+    /// player's choice
+    TestOverlordSpellChooseRoom,
+    /// Minion which gains the Overlord 1 mana each time it is revealed to the
+    /// Champion
+    TestMinionGainManaWhenRevealed,
 
     // Playtest 0
     ArcaneRecovery,
@@ -26,7 +26,8 @@ use crate::card_name::CardName;
 use crate::game::TurnData;
 use crate::game_actions::CardTarget;
 use crate::primitives::{
-    BoostCount, CardId, ItemLocation, LevelValue, ManaValue, RaidId, RoomId, RoomLocation, Side,
+    BoostCount, CardId, HealthValue, ItemLocation, LevelValue, ManaValue, RaidId, RoomId,
+    RoomLocation, Side, SortingKey,
 };
 
 /// State for an ability within a game
@@ -50,6 +51,9 @@ pub enum CardPosition {
     DeckUnknown(Side),
     /// A card which is known to at least one player to be on the top of a deck
     DeckTop(Side),
+    /// A card which has been placed on the bottom of a deck. These cards are
+    /// only drawn once no other cards remain in the deck.
+    DeckBottom(Side),
     Hand(Side),
     Room(RoomId, RoomLocation),
     ArenaItem(ItemLocation),
@@ -65,6 +69,10 @@ pub enum CardPosition {
     /// Marks the identity card for a side. The first identity (by sorting key)
     /// is the primary identity for a player.
     Identity(Side),
+    /// Card has been removed from the game and cannot be returned by effects
+    /// which retrieve cards from the discard pile. The [Side] is the card's
+    /// owner, regardless of which player's effect banished it.
+    Banished(Side),
 }
 
 impl CardPosition {
@@ -95,7 +103,12 @@ impl CardPosition {
 
     /// Returns true if this card is in a known or unknown deck position
     pub fn in_deck(&self) -> bool {
-        matches!(self.kind(), CardPositionKind::DeckUnknown | CardPositionKind::DeckTop)
+        matches!(
+            self.kind(),
+            CardPositionKind::DeckUnknown
+                | CardPositionKind::DeckTop
+                | CardPositionKind::DeckBottom
+        )
     }
 
     /// Returns true if this card is in a user's discard pile
@@ -121,6 +134,11 @@ impl CardPosition {
     pub fn is_identity(&self) -> bool {
         self.kind() == CardPositionKind::Identity
     }
+
+    /// Returns true if this card has been removed from the game
+    pub fn is_banished(&self) -> bool {
+        self.kind() == CardPositionKind::Banished
+    }
 }
 
 /// Optional card state, properties which are not universal
@@ -133,8 +151,19 @@ pub struct CardData {
     pub boost_count: BoostCount,
     /// How much mana is stored in this card?
     pub stored_mana: ManaValue,
+    /// Value chosen for X the last time this card was played, if it has a
+    /// variable mana cost.
+    #[serde(default)]
+    pub x_value: Option<ManaValue>,
+    /// Damage dealt to this minion, which reduces its health.
+    #[serde(default)]
+    pub damage: HealthValue,
     /// When was the last time this card entered the arena, if ever?
     pub last_entered_play: Option<TurnData>,
+    /// True if this card is a temporary copy of another card created by an
+    /// effect. Tokens are banished when they leave play.
+    #[serde(default)]
+    pub is_token: bool,
     /// Is this card face-up?
     is_face_up: bool,
     /// Is this card revealed to the [CardId.side] user?
@@ -156,7 +185,7 @@ pub struct CardState {
     pub data: CardData,
     /// Opaque value identifying this card's sort order within its CardPosition.
     /// Higher sorting keys are closer to the 'top' or 'front' of the position.
-    pub sorting_key: u32,
+    pub sorting_key: SortingKey,
     position: CardPosition,
 }
 
@@ -173,7 +202,7 @@ impl CardState {
             } else {
                 CardPosition::DeckUnknown(id.side)
             },
-            sorting_key: 0,
+            sorting_key: SortingKey::default(),
             data: CardData {
                 revealed_to_owner: is_identity,
                 revealed_to_opponent: is_identity,
@@ -182,6 +211,19 @@ impl CardState {
         }
     }
 
+    /// Creates a new token card state. Tokens begin outside of the game, in
+    /// the [CardPosition::Banished] position, until they are moved to their
+    /// initial position.
+    pub fn new_token(id: CardId, name: CardName) -> Self {
+        Self {
+            id,
+            name,
+            position: CardPosition::Banished(id.side),
+            sorting_key: SortingKey::default(),
+            data: CardData { is_token: true, ..CardData::default() },
+        }
+    }
+
     pub fn side(&self) -> Side {
         self.id.side
     }
@@ -193,7 +235,7 @@ impl CardState {
 
     /// Sets the position of this card. Please use `mutations::move_card`
     /// instead of invoking this directly.
-    pub fn set_position_internal(&mut self, sorting_key: u32, position: CardPosition) {
+    pub fn set_position_internal(&mut self, sorting_key: SortingKey, position: CardPosition) {
         self.sorting_key = sorting_key;
         self.position = position;
     }
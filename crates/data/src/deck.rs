@@ -65,7 +65,7 @@ impl Deck {
         let mut result = self
             .cards
             .iter()
-            .flat_map(|(name, count)| iter::repeat(*name).take(*count as usize))
+            .flat_map(|(name, count)| iter::repeat_n(*name, *count as usize))
             .collect::<Vec<_>>();
         result.sort();
         result
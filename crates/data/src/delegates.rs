@@ -63,6 +63,7 @@
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Formatter;
+use std::sync::Arc;
 
 use anyhow::Result;
 use enum_kinds::EnumKind;
@@ -294,6 +295,20 @@ impl HasCardId for ScoreCard {
     }
 }
 
+/// Event data for when a card is revealed to a player
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct CardRevealed {
+    pub card_id: CardId,
+    /// Player the card was revealed to
+    pub to: Side,
+}
+
+impl HasCardId for CardRevealed {
+    fn card_id(&self) -> CardId {
+        self.card_id
+    }
+}
+
 /// Result of a raid
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
 pub enum RaidOutcome {
@@ -327,6 +342,19 @@ impl HasAbilityId for DealtDamage {
     }
 }
 
+/// Event data when a room is chosen for an ability
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct RoomChosen {
+    pub source: AbilityId,
+    pub room_id: RoomId,
+}
+
+impl HasAbilityId for RoomChosen {
+    fn ability_id(&self) -> AbilityId {
+        self.source
+    }
+}
+
 /// Actions to show the Champion during combat in addition to their weapon
 /// actions
 #[derive(Clone, Debug)]
@@ -351,7 +379,8 @@ pub enum Delegate {
     Dusk(EventDelegate<TurnNumber>),
     /// A card is moved from a Deck position to a Hand position
     DrawCard(EventDelegate<CardId>),
-    /// A user takes the explicit 'draw card' game action
+    /// A user takes the explicit 'draw card' game action, or a card is drawn
+    /// via `mutations::draw_revealed`
     DrawCardAction(EventDelegate<CardId>),
     /// A card has been played via the Play Card action and has had its costs
     /// paid
@@ -408,6 +437,12 @@ pub enum Delegate {
     /// Damage has been dealt to the Champion player (in the form of discarded
     /// cards).
     DealtDamage(EventDelegate<DealtDamage>),
+    /// A room has been chosen in response to a
+    /// [crate::game_actions::PromptContext::ChooseRoom] prompt.
+    RoomChosen(EventDelegate<RoomChosen>),
+    /// A card has been revealed to a player who could not previously see it,
+    /// without changing its face-up state.
+    RevealCard(EventDelegate<CardRevealed>),
 
     /// Query whether the indicated player can currently take the basic game
     /// action to spend an action point to draw a card.
@@ -501,9 +536,13 @@ pub struct DelegateContext {
 }
 
 /// Caches delegates in a given game for faster lookup
+///
+/// The lookup table is derived only from the names of the cards in a game and
+/// is never mutated in place, so it is shared between clones of a game. Cards
+/// created during a game, such as tokens, are added to a copy of the table.
 #[derive(Clone, Debug, Default)]
 pub struct DelegateCache {
-    pub lookup: HashMap<DelegateKind, Vec<DelegateContext>>,
+    pub lookup: Arc<HashMap<DelegateKind, Vec<DelegateContext>>>,
 }
 
 impl DelegateCache {
@@ -16,33 +16,71 @@
 
 #![allow(clippy::use_self)] // Required to use EnumKind
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
 
 use anyhow::Result;
 use rand_xoshiro::rand_core::SeedableRng;
 use rand_xoshiro::Xoshiro256StarStar;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
-use with_error::WithError;
+use with_error::{verify, WithError};
 
+use crate::agent_definition::AgentData;
 use crate::card_state::{AbilityState, CardPosition, CardPositionKind, CardState};
 use crate::deck::Deck;
 use crate::delegates::DelegateCache;
 use crate::game_actions::GamePrompt;
+use crate::player_data::PlayerCosmetics;
 use crate::player_name::PlayerId;
 use crate::primitives::{
-    AbilityId, ActionCount, CardId, GameId, HasAbilityId, ItemLocation, ManaValue, PointsValue,
-    RaidId, RoomId, RoomLocation, Side, TurnNumber,
+    AbilityId, ActionCount, CardId, CardType, GameId, HasAbilityId, ItemLocation, ManaValue,
+    PointsValue, RaidId, RoomId, RoomLocation, Side, SortingKey, TurnNumber,
 };
 use crate::updates::{GameUpdate, UpdateStep, UpdateTracker, Updates};
 
 /// Mana to be spent only during the `raid_id` raid
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SpecificRaidMana {
     pub raid_id: RaidId,
     pub mana: ManaValue,
 }
 
+/// Identifies a pool of unrestricted mana within a player's [ManaState]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ManaSource {
+    /// General mana, see [ManaState::base_mana]
+    Base,
+    /// Mana for the current raid, see [ManaState::specific_raid_mana]
+    RaidSpecific,
+}
+
+/// Describes a limitation on the purposes for which a pool of mana can be spent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ManaRestriction {
+    /// Mana which can only be used to pay for cards of the given [CardType]
+    OnlyForCardType(CardType),
+}
+
+/// Mana to be spent only on purposes allowed by its [ManaRestriction]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestrictedMana {
+    pub restriction: ManaRestriction,
+    pub mana: ManaValue,
+}
+
+/// Mana taken from each of a player's pools by a single payment, so that the
+/// payment can be refunded to the same pools.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManaPayment {
+    /// Amount paid from [ManaState::base_mana]
+    pub base_mana: ManaValue,
+    /// Amount paid from [ManaState::specific_raid_mana]
+    pub specific_raid_mana: Option<SpecificRaidMana>,
+    /// Amounts paid from [ManaState::restricted_mana]
+    pub restricted_mana: Vec<RestrictedMana>,
+}
+
 /// Stores a player's mana, both a general-purpose pool and various
 /// restricted-purpose pools.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -52,6 +90,10 @@ pub struct ManaState {
 
     /// Mana which can be used only during a specific raid.
     pub specific_raid_mana: Option<SpecificRaidMana>,
+
+    /// Mana which can be used only for purposes matching a [ManaRestriction].
+    #[serde(default)]
+    pub restricted_mana: Vec<RestrictedMana>,
 }
 
 /// State of a player within a game, containing their score and available
@@ -63,15 +105,39 @@ pub struct PlayerState {
     pub actions: ActionCount,
     pub score: PointsValue,
 
+    /// Unused actions this player forfeited when their last turn ended, to be
+    /// added to their actions for their next turn. Only recorded if
+    /// [GameConfiguration::carry_over_actions] is set.
+    #[serde(default)]
+    pub carried_actions: ActionCount,
+
     /// A choice this player is facing in resolving a card ability. Takes
     /// precedence over other choices such as raid actions.
     pub prompt: Option<GamePrompt>,
+
+    /// Cosmetic options this player selected when the game was created.
+    #[serde(default)]
+    pub cosmetics: PlayerCosmetics,
+
+    /// State of the AI agent controlling this player, if it needs to persist
+    /// any between actions.
+    #[serde(default)]
+    pub agent: Option<AgentData>,
 }
 
 impl PlayerState {
     /// Create an empty player state.
     pub fn new(id: PlayerId) -> Self {
-        Self { id, mana_state: ManaState::default(), actions: 0, score: 0, prompt: None }
+        Self {
+            id,
+            mana_state: ManaState::default(),
+            actions: 0,
+            score: 0,
+            carried_actions: 0,
+            prompt: None,
+            cosmetics: PlayerCosmetics::default(),
+            agent: None,
+        }
     }
 }
 
@@ -81,6 +147,10 @@ impl PlayerState {
 pub enum InternalRaidPhase {
     /// Raid has been created
     Begin,
+    /// The Overlord may activate abilities in response to the raid before the
+    /// Champion encounters any defenders. Skipped if the Overlord has no such
+    /// abilities available.
+    Activation,
     /// The defender with the provided index position is currently being
     /// encountered. The Champion is deciding which weapons, if any, to employ.
     ///
@@ -103,7 +173,18 @@ pub enum InternalRaidPhase {
 /// Only one jump request is supported at a time, on a 'last write wins' basis.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum RaidJumpRequest {
+    /// Encounters the given minion next, retargeting the raid to its room if
+    /// it is not in the room currently being encountered.
     EncounterMinion(CardId),
+    /// Adds a room's defenders to the raid. They are encountered after those
+    /// of the room currently being encountered, before the raid's target is
+    /// accessed. Has no effect once the raid has reached its access phase.
+    AddRoom(RoomId),
+    /// Ends the current encounter, e.g. because the minion being encountered
+    /// has left `room_id`. The raid continues with the next eligible defender
+    /// of `room_id` positioned below `less_than`, if it is still encountering
+    /// that room.
+    SkipEncounter { room_id: RoomId, less_than: usize },
 }
 
 /// Data about an active raid
@@ -122,8 +203,30 @@ pub struct RaidData {
     pub accessed: Vec<CardId>,
     /// Requested new state for this raid. See [RaidJumpRequest] for details.
     pub jump_request: Option<RaidJumpRequest>,
+    /// Additional rooms whose defenders will be encountered during this raid,
+    /// in order, once no eligible defenders remain in the current room. See
+    /// [RaidJumpRequest::AddRoom].
+    #[serde(default)]
+    pub queued_rooms: Vec<RoomId>,
+    /// Room whose defenders are currently being encountered, if this is not
+    /// [Self::target]. Please use [Self::encounter_room] instead of examining
+    /// this directly.
+    #[serde(default)]
+    pub current_room: Option<RoomId>,
+}
+
+impl RaidData {
+    /// Room containing the defenders encountered in the current phase of this
+    /// raid.
+    pub fn encounter_room(&self) -> RoomId {
+        self.current_room.unwrap_or(self.target)
+    }
 }
 
+/// Seed used for deterministic games which do not specify a
+/// [GameConfiguration::seed].
+pub const DEFAULT_SEED: u64 = 314159265358979323;
+
 /// Describes options for this game & the set of rules it is using.
 #[derive(Debug, Clone, Default, Copy, Serialize, Deserialize)]
 pub struct GameConfiguration {
@@ -131,8 +234,92 @@ pub struct GameConfiguration {
     /// deterministically using a seeded random number generator. Useful for
     /// e.g. unit tests.
     pub deterministic: bool,
+    /// Seed for the random number generator used when [Self::deterministic]
+    /// is true. Defaults to [DEFAULT_SEED].
+    #[serde(default)]
+    pub seed: Option<u64>,
     /// Whether to run in simulation mode and thus disable update tracking
     pub simulation: bool,
+    /// If set, actions a player has left over when their turn ends early are
+    /// added to their actions for their next turn, up to this maximum number
+    /// of carried actions. By default, no actions carry over.
+    pub carry_over_actions: Option<ActionCount>,
+    /// If true, all cards are revealed to both players when the game ends so
+    /// that complete information can be shown for post-game review.
+    #[serde(default)]
+    pub disclose_on_game_over: bool,
+    /// If set, a player who has not made a mulligan decision within this
+    /// duration of the opening hands being dealt automatically keeps their
+    /// hand. By default, players may take as long as they like.
+    #[serde(default)]
+    pub mulligan_timeout: Option<Duration>,
+    /// If set, each player has a chess-style clock which counts down while
+    /// they are able to act. By default, turns are untimed.
+    #[serde(default)]
+    pub turn_timer: Option<TurnTimerConfiguration>,
+    /// If set, a prompt which has a safe default response (see
+    /// [crate::game_actions::GamePrompt::safe_default]) is automatically
+    /// resolved once it has been waiting on a player for this duration. By
+    /// default, prompts wait indefinitely.
+    #[serde(default)]
+    pub prompt_timeout: Option<Duration>,
+    /// Number of cards each player draws for their opening hand. Defaults to
+    /// `rules::constants::STARTING_HAND_SIZE`.
+    #[serde(default)]
+    pub mulligan_hand_size: Option<u32>,
+    /// Number of cards a player draws after choosing to mulligan their entire
+    /// opening hand. Defaults to `rules::constants::STARTING_HAND_SIZE`.
+    #[serde(default)]
+    pub mulligan_draw_size: Option<u32>,
+    /// Maximum number of cards a player may hold at the end of their turn,
+    /// before card effects are applied. Defaults to
+    /// `rules::constants::STARTING_MAXIMUM_HAND_SIZE`.
+    #[serde(default)]
+    pub maximum_hand_size: Option<u32>,
+}
+
+/// Options for a game's [TurnTimer].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TurnTimerConfiguration {
+    /// Time each player starts the game with.
+    pub initial: Duration,
+    /// Time added to a player's clock after each action they take.
+    pub increment: Duration,
+    /// If true, a player whose clock runs out loses the game. Otherwise, their
+    /// turn ends and their clock is reset to the increment.
+    pub resign_on_timeout: bool,
+}
+
+/// Tracks the remaining time for each player in a game with a turn timer.
+///
+/// Timestamps are stored as the time since the UNIX epoch so that they remain
+/// meaningful after the game is written to the database.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnTimer {
+    /// Time left on each player's clock.
+    #[serde_as(as = "Vec<(_, _)>")]
+    pub remaining: HashMap<Side, Duration>,
+    /// Time added to a player's clock after each action they take.
+    pub increment: Duration,
+    /// Time at which the clock last started counting down, or None if no
+    /// player's clock is currently running.
+    pub last_action_at: Option<Duration>,
+}
+
+impl TurnTimer {
+    pub fn new(config: TurnTimerConfiguration) -> Self {
+        Self {
+            remaining: enum_iterator::all::<Side>().map(|side| (side, config.initial)).collect(),
+            increment: config.increment,
+            last_action_at: None,
+        }
+    }
+
+    /// Time left on the `side` player's clock.
+    pub fn remaining(&self, side: Side) -> Duration {
+        self.remaining.get(&side).copied().unwrap_or_default()
+    }
 }
 
 /// Mulligan decision a player made for their opening hand
@@ -142,6 +329,40 @@ pub enum MulliganDecision {
     Keep,
     /// The player has elected to draw a new hand of 5 cards
     Mulligan,
+    /// The player keeps their hand except for the selected cards, which are
+    /// placed on the bottom of their deck and replaced with new draws.
+    Partial(MulliganSelection),
+}
+
+/// Maximum number of cards which can be selected for a
+/// [MulliganDecision::Partial]. Opening hands may be larger than this if
+/// [GameConfiguration::mulligan_hand_size] is set, in which case only this many
+/// cards can be replaced.
+pub const MAXIMUM_MULLIGAN_SELECTION: usize = 5;
+
+/// Cards chosen from an opening hand to be replaced. Stored as a fixed-size
+/// array so that [MulliganDecision] remains `Copy`.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, Default)]
+pub struct MulliganSelection([Option<CardId>; MAXIMUM_MULLIGAN_SELECTION]);
+
+impl MulliganSelection {
+    pub fn new(cards: &[CardId]) -> Result<Self> {
+        verify!(
+            cards.len() <= MAXIMUM_MULLIGAN_SELECTION,
+            "Cannot select {} cards for mulligan",
+            cards.len()
+        );
+        let mut result = Self::default();
+        for (slot, card_id) in result.0.iter_mut().zip(cards) {
+            *slot = Some(*card_id);
+        }
+        Ok(result)
+    }
+
+    /// Selected cards, in the order in which they were selected.
+    pub fn cards(&self) -> impl Iterator<Item = CardId> + '_ {
+        self.0.iter().flatten().copied()
+    }
 }
 
 /// [MulliganDecision]s for both players.
@@ -153,6 +374,13 @@ pub struct MulliganData {
     /// The mulligan decision for the Champion player, or None if no decision
     /// has been made.
     pub champion: Option<MulliganDecision>,
+    /// Time since the UNIX epoch at which opening hands were dealt, if known.
+    #[serde(default)]
+    pub started_at: Option<Duration>,
+    /// Cards which either player has marked to be replaced via a
+    /// [MulliganDecision::Partial] but not yet confirmed.
+    #[serde(default)]
+    pub selected_cards: Vec<CardId>,
 }
 
 impl MulliganData {
@@ -163,6 +391,19 @@ impl MulliganData {
         }
         .as_ref()
     }
+
+    /// Whether the `side` player can mark another card to be replaced without
+    /// exceeding [MAXIMUM_MULLIGAN_SELECTION].
+    pub fn can_select_more(&self, side: Side) -> bool {
+        self.selected_cards.iter().filter(|c| c.side == side).count() < MAXIMUM_MULLIGAN_SELECTION
+    }
+
+    /// Cards the `side` player has currently marked to be replaced.
+    pub fn selection(&self, side: Side) -> Result<MulliganSelection> {
+        MulliganSelection::new(
+            &self.selected_cards.iter().filter(|c| c.side == side).copied().collect::<Vec<_>>(),
+        )
+    }
 }
 
 /// Identifies the player whose turn it is
@@ -194,10 +435,39 @@ pub struct GameData {
     pub raid: Option<RaidData>,
     /// Counter to create unique IDs for raids within this game
     pub next_raid_id: u32,
+    /// Extra turns which have been granted by card effects, in the order in
+    /// which they should be taken. The first entry is used when that player's
+    /// turn ends.
+    #[serde(default)]
+    pub extra_turns: VecDeque<Side>,
+    /// Turns which have been completed in this game, in the order in which
+    /// they were taken.
+    #[serde(default)]
+    pub turn_history: Vec<TurnData>,
+    /// Chess-style clocks for each player, if
+    /// [GameConfiguration::turn_timer] is set.
+    #[serde(default)]
+    pub turn_timer: Option<TurnTimer>,
+    /// Time at which this game ended, measured as time elapsed since the UNIX
+    /// epoch. Used to clean up finished games.
+    #[serde(default)]
+    pub completed_at: Option<Duration>,
+    /// Time at which the currently pending prompt was shown, measured as time
+    /// elapsed since the UNIX epoch. Used to enforce
+    /// [GameConfiguration::prompt_timeout].
+    #[serde(default)]
+    pub prompt_shown_at: Option<Duration>,
+    /// Human-readable descriptions of recent game events, oldest first. Holds
+    /// at most [MAXIMUM_GAME_LOG] entries.
+    #[serde(default)]
+    pub log: VecDeque<String>,
     /// Game options
     pub config: GameConfiguration,
 }
 
+/// Maximum number of entries stored in [GameData::log].
+pub const MAXIMUM_GAME_LOG: usize = 50;
+
 /// State for an individual room
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RoomState {
@@ -205,6 +475,18 @@ pub struct RoomState {
     pub last_raided: Option<TurnData>,
 }
 
+/// Maximum number of [UndoStep]s stored in [GameState::undo_history].
+pub const MAXIMUM_UNDO_HISTORY: usize = 3;
+
+/// State of a game before a user action, used to undo that action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UndoStep {
+    /// Player who took the action
+    pub side: Side,
+    /// Game state before the action was taken, without its own undo history.
+    pub snapshot: Box<GameState>,
+}
+
 /// Stores the primary state for an ongoing game
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -246,6 +528,12 @@ pub struct GameState {
     /// order to improve performance
     #[serde(skip)]
     pub delegate_cache: DelegateCache,
+    /// Snapshots of this game taken before recent user actions, most recent
+    /// last, see [crate::game_actions::UserAction::Undo]. Holds at most
+    /// [MAXIMUM_UNDO_HISTORY] entries, and snapshots never contain their own
+    /// history, which bounds the size of a stored game.
+    #[serde(default)]
+    pub undo_history: Vec<UndoStep>,
 }
 
 impl GameState {
@@ -267,6 +555,12 @@ impl GameState {
                 turn: TurnData { side: Side::Overlord, turn_number: 0 },
                 raid: None,
                 next_raid_id: 1,
+                extra_turns: VecDeque::new(),
+                turn_history: vec![],
+                turn_timer: config.turn_timer.map(TurnTimer::new),
+                completed_at: None,
+                prompt_shown_at: None,
+                log: VecDeque::new(),
                 config,
             },
             overlord_cards: Self::make_deck(&overlord_deck, Side::Overlord),
@@ -282,8 +576,9 @@ impl GameState {
             }),
             next_sorting_key: 1,
             delegate_cache: DelegateCache::default(),
+            undo_history: vec![],
             rng: if config.deterministic {
-                Some(Xoshiro256StarStar::seed_from_u64(314159265358979323))
+                Some(Xoshiro256StarStar::seed_from_u64(config.seed.unwrap_or(DEFAULT_SEED)))
             } else {
                 None
             },
@@ -307,9 +602,17 @@ impl GameState {
                 next_sorting_key: self.next_sorting_key,
                 rng: None,
                 delegate_cache: DelegateCache::default(),
+                undo_history: vec![],
             };
 
-            self.updates.steps.push(UpdateStep { snapshot: clone, update: update() });
+            let update = update();
+            if let Some(entry) = update.log_entry(self) {
+                if self.data.log.len() >= MAXIMUM_GAME_LOG {
+                    self.data.log.pop_front();
+                }
+                self.data.log.push_back(entry);
+            }
+            self.updates.steps.push(UpdateStep { snapshot: clone, update });
         }
     }
 
@@ -329,9 +632,45 @@ impl GameState {
             next_sorting_key: self.next_sorting_key,
             rng: self.rng.clone(),
             delegate_cache: self.delegate_cache.clone(),
+            undo_history: self.undo_history.clone(),
+        }
+    }
+
+    /// Makes a copy of the game state for use in AI simulations.
+    ///
+    /// Update tracking is disabled in the copy, and its [DelegateCache] is
+    /// shared with this game instead of being rebuilt. The random number
+    /// generator state is copied, so a simulation produces the same results as
+    /// a full clone of this game.
+    pub fn clone_for_simulation(&self) -> Self {
+        Self {
+            id: self.id,
+            data: self.data.clone(),
+            updates: UpdateTracker::new(Updates::Ignore),
+            overlord_cards: self.overlord_cards.clone(),
+            champion_cards: self.champion_cards.clone(),
+            overlord: self.overlord.clone(),
+            champion: self.champion.clone(),
+            ability_state: self.ability_state.clone(),
+            room_state: self.room_state.clone(),
+            next_sorting_key: self.next_sorting_key,
+            rng: self.rng.clone(),
+            delegate_cache: self.delegate_cache.clone(),
+            undo_history: vec![],
         }
     }
 
+    /// Adds a snapshot of this game taken before the `side` player's most
+    /// recent action to [Self::undo_history], discarding the oldest entry if
+    /// the history is full.
+    pub fn push_undo_step(&mut self, side: Side, mut snapshot: GameState) {
+        snapshot.undo_history.clear();
+        if self.undo_history.len() >= MAXIMUM_UNDO_HISTORY {
+            self.undo_history.remove(0);
+        }
+        self.undo_history.push(UndoStep { side, snapshot: Box::new(snapshot) });
+    }
+
     /// Returns identity cards for the provided Side
     pub fn identities(&self, side: Side) -> impl Iterator<Item = &CardState> {
         self.cards(side).iter().filter(|c| c.position().kind() == CardPositionKind::Identity)
@@ -393,12 +732,36 @@ impl GameState {
 
     /// Returns a monotonically-increasing sorting key for object positions in
     /// this game.
-    pub fn next_sorting_key(&mut self) -> u32 {
-        let result = self.next_sorting_key;
+    ///
+    /// If the next key would exceed [SortingKey::MAX], the sorting keys of all
+    /// cards are first renumbered via [Self::compact_sorting_keys].
+    pub fn next_sorting_key(&mut self) -> SortingKey {
+        if self.next_sorting_key > SortingKey::MAX {
+            self.compact_sorting_keys();
+        }
+        let result = SortingKey::new(self.next_sorting_key).expect("Sorting key out of range");
         self.next_sorting_key += 1;
         result
     }
 
+    /// Renumbers the sorting keys of all cards in this game to consecutive
+    /// values starting from 1, preserving their relative order. Cards which
+    /// previously shared a sorting key continue to do so.
+    fn compact_sorting_keys(&mut self) {
+        let mut cards = self.all_cards_mut().collect::<Vec<_>>();
+        cards.sort_by_key(|card| card.sorting_key);
+        let mut next = 0;
+        let mut previous = None;
+        for card in cards {
+            if previous != Some(card.sorting_key) {
+                previous = Some(card.sorting_key);
+                next += 1;
+            }
+            card.sorting_key = SortingKey::new(next).expect("Sorting key out of range");
+        }
+        self.next_sorting_key = next + 1;
+    }
+
     /// Moves a card to a new [CardPosition], updating its sorting key.
     ///
     /// Generally use `mutations::move_card` instead of calling this method
@@ -466,6 +829,12 @@ impl GameState {
         self.cards(side).iter().filter(|c| c.position().in_discard_pile())
     }
 
+    /// Cards a player owns which have been removed from the game, in an
+    /// unspecified order
+    pub fn banished(&self, side: Side) -> impl Iterator<Item = &CardState> {
+        self.cards(side).iter().filter(|c| c.position().is_banished())
+    }
+
     /// Returns Overlord cards defending a given room in an unspecified order
     pub fn defenders_unordered(&self, room_id: RoomId) -> impl Iterator<Item = &CardState> {
         self.cards_in_position(Side::Overlord, CardPosition::Room(room_id, RoomLocation::Defender))
@@ -530,6 +899,11 @@ impl GameState {
         self.overlord_cards.iter().chain(self.champion_cards.iter())
     }
 
+    /// Mutable equivalent of [Self::all_cards].
+    pub fn all_cards_mut(&mut self) -> impl Iterator<Item = &mut CardState> {
+        self.overlord_cards.iter_mut().chain(self.champion_cards.iter_mut())
+    }
+
     /// Helper method to return the current [RaidData] or an error when one is
     /// expected to exist.
     pub fn raid(&self) -> Result<&RaidData> {
@@ -552,7 +926,7 @@ impl GameState {
     /// being encountered.
     pub fn raid_defender(&self) -> Result<CardId> {
         Ok(*self
-            .defender_list(self.raid()?.target)
+            .defender_list(self.raid()?.encounter_room())
             .get(self.raid_encounter()?)
             .with_error(|| "Defender Not Found")?)
     }
@@ -565,7 +939,7 @@ impl GameState {
     /// Returns a mutable [AbilityState] for an [AbilityId], creating a new one
     /// if one has not previously been set
     pub fn ability_state_mut(&mut self, ability_id: impl HasAbilityId) -> &mut AbilityState {
-        self.ability_state.entry(ability_id.ability_id()).or_insert_with(AbilityState::default)
+        self.ability_state.entry(ability_id.ability_id()).or_default()
     }
 
     /// Create card states for a deck
@@ -636,6 +1010,35 @@ mod tests {
         assert_eq!(vec![mortal, infernal, abyssal], hand(&g));
     }
 
+    #[test]
+    fn compact_sorting_keys() {
+        let (abyssal, infernal, mortal) = (
+            CardId::new(Side::Overlord, 1),
+            CardId::new(Side::Overlord, 2),
+            CardId::new(Side::Overlord, 3),
+        );
+        let mut g = test_game(
+            vec![
+                CardName::TestAbyssalMinion,
+                CardName::TestInfernalMinion,
+                CardName::TestMortalMinion,
+            ],
+            vec![],
+        );
+
+        for _ in 0..15_000 {
+            g.move_card_internal(abyssal, CardPosition::Hand(Side::Overlord));
+            g.move_card_internal(infernal, CardPosition::Hand(Side::Overlord));
+            g.move_card_internal(mortal, CardPosition::Hand(Side::Overlord));
+        }
+
+        assert!(g.all_cards().all(|card| card.sorting_key.value() <= SortingKey::MAX));
+        assert_eq!(
+            vec![abyssal, infernal, mortal],
+            g.card_list_for_position(Side::Overlord, CardPosition::Hand(Side::Overlord))
+        );
+    }
+
     fn test_game(overlord: Vec<CardName>, champion: Vec<CardName>) -> GameState {
         GameState::new(
             GameId::new(0),
@@ -16,11 +16,14 @@
 
 #![allow(clippy::use_self)] // Required to use EnumKind
 
+use std::collections::HashSet;
+
 use anyhow::{anyhow, Result};
 use enum_kinds::EnumKind;
 use serde::{Deserialize, Serialize};
 
-use crate::game::MulliganDecision;
+use crate::game::{ManaPayment, ManaSource, MulliganDecision};
+use crate::player_data::SideboardSwap;
 use crate::player_name::NamedPlayer;
 use crate::primitives::{AbilityId, ActionCount, CardId, ManaValue, PointsValue, RoomId, Side};
 
@@ -34,6 +37,14 @@ pub enum EncounterAction {
     CardAction(CardPromptAction),
 }
 
+/// Overlord action during the raid [crate::game::InternalRaidPhase::Activation]
+/// phase. Abilities are activated via [UserAction::ActivateAbility].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub enum ActivationPhaseAction {
+    /// Stop responding and allow the raid to continue.
+    ContinueRaid,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
 pub enum AccessPhaseAction {
     ScoreCard(CardId),
@@ -41,9 +52,106 @@ pub enum AccessPhaseAction {
     EndRaid,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+/// Records the costs paid for a card play which is waiting on its target to be
+/// selected, so that they can be refunded if the play is cancelled.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct PendingPlay {
+    pub card_id: CardId,
+    pub actions_spent: ActionCount,
+    pub mana_spent: ManaPayment,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PromptContext {
     RaidAdvance,
+    /// A target is being selected for the [PendingPlay] card.
+    PlayCard(PendingPlay),
+    /// `total` damage is being divided among the `targets` cards, see
+    /// [PromptAction::DamageDistribution].
+    DistributeDamage {
+        total: u32,
+        targets: Vec<CardId>,
+    },
+    /// The `source` ability is asking its controller to choose one of the
+    /// `valid` rooms, see [PromptAction::RoomChosen].
+    ChooseRoom {
+        source: AbilityId,
+        valid: Vec<RoomId>,
+    },
+    /// The current player has ended their turn above their maximum hand size
+    /// and must choose `count` cards to discard. Cards are marked via
+    /// [PromptAction::ToggleDiscardCard] and the `selected` cards are discarded
+    /// via [PromptAction::ConfirmDiscard].
+    DiscardToHandSize {
+        count: usize,
+        selected: Vec<CardId>,
+    },
+    /// The Champion is using the `weapon_id` weapon against the `target_id`
+    /// minion and must choose which [ManaSource] to pay its `cost` from, see
+    /// [PromptAction::ManaSourceChosen].
+    ChooseManaSource {
+        weapon_id: CardId,
+        target_id: CardId,
+        cost: ManaValue,
+    },
+}
+
+impl PromptContext {
+    /// Whether the current turn cannot end until a prompt with this context
+    /// has been answered, i.e. whether it resolves an effect of the current
+    /// turn.
+    pub fn blocks_end_turn(&self) -> bool {
+        matches!(
+            self,
+            Self::DistributeDamage { .. }
+                | Self::ChooseRoom { .. }
+                | Self::DiscardToHandSize { .. }
+        )
+    }
+}
+
+/// Maximum number of cards which damage can be divided among via
+/// [PromptAction::DamageDistribution].
+pub const MAXIMUM_DAMAGE_TARGETS: usize = 4;
+
+/// Assigns an amount of damage to each of up to [MAXIMUM_DAMAGE_TARGETS]
+/// cards. Stored as a fixed-size array so that [PromptAction] can remain
+/// `Copy`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub struct DamageAllocation([Option<(CardId, u32)>; MAXIMUM_DAMAGE_TARGETS]);
+
+impl DamageAllocation {
+    /// Creates a new allocation from `(card, amount)` pairs, or returns an
+    /// error if more than [MAXIMUM_DAMAGE_TARGETS] pairs are provided. Pairs
+    /// are sorted by card so that equivalent allocations compare equal.
+    pub fn new(entries: impl IntoIterator<Item = (CardId, u32)>) -> Result<Self> {
+        let mut entries = entries.into_iter().collect::<Vec<_>>();
+        entries.sort();
+        let mut result = Self::default();
+        for (i, entry) in entries.into_iter().enumerate() {
+            *result.0.get_mut(i).ok_or_else(|| anyhow!("Too many damage targets"))? = Some(entry);
+        }
+        Ok(result)
+    }
+
+    /// Iterates over the `(card, amount)` pairs in this allocation
+    pub fn iter(&self) -> impl Iterator<Item = (CardId, u32)> + '_ {
+        self.0.iter().flatten().copied()
+    }
+
+    /// Total damage assigned across all cards
+    pub fn total(&self) -> u32 {
+        self.iter().map(|(_, amount)| amount).sum()
+    }
+
+    /// Whether this allocation assigns exactly `total` damage, only to cards
+    /// in `targets`, and to each card at most once.
+    pub fn is_valid(&self, total: u32, targets: &[CardId]) -> bool {
+        let cards = self.iter().map(|(card_id, _)| card_id).collect::<Vec<_>>();
+        self.total() == total
+            && cards.iter().all(|card_id| targets.contains(card_id))
+            && cards.iter().collect::<HashSet<_>>().len() == cards.len()
+    }
 }
 
 /// A choice which can be made as part of an ability of an individual card
@@ -63,6 +171,14 @@ pub enum CardPromptAction {
     TakeDamageEndRaid(AbilityId, u32),
 }
 
+/// Version of the serialized [UserAction] format, written as the first byte of
+/// every `StandardAction` payload.
+///
+/// This must be incremented whenever the shape of [UserAction] changes, so
+/// that actions sent by out-of-date clients are rejected with a clear error
+/// instead of failing to deserialize.
+pub const USER_ACTION_PAYLOAD_VERSION: u8 = 13;
+
 /// An action which can be taken in the user interface, typically embedded
 /// inside the `GameAction::StandardAction` protobuf message type when sent to
 /// the client.
@@ -70,12 +186,54 @@ pub enum CardPromptAction {
 pub enum PromptAction {
     /// Action to keep or mulligan opening hand
     MulliganDecision(MulliganDecision),
+    /// Mark or unmark a card in an opening hand to be replaced via
+    /// [MulliganDecision::Partial]
+    ToggleMulliganCard(CardId),
+    /// Overlord action while responding to a raid
+    ActivationPhaseAction(ActivationPhaseAction),
     /// Champion action in response to a raid encounter
     EncounterAction(EncounterAction),
     /// Action to target & destroy an accessed card
     AccessPhaseAction(AccessPhaseAction),
     /// Action to take as part of a card ability
     CardAction(CardPromptAction),
+    /// Select the target for a card which is currently being played
+    SelectCardTarget(CardId, CardTarget),
+    /// Choose the value of X for a card with a variable mana cost which is
+    /// currently being played
+    ChooseNumber(CardId, ManaValue),
+    /// Divide damage among cards in response to a
+    /// [PromptContext::DistributeDamage] prompt
+    DamageDistribution(DamageAllocation),
+    /// Choose a room in response to a [PromptContext::ChooseRoom] prompt
+    RoomChosen(RoomId),
+    /// Mark or unmark a card in hand to be discarded in response to a
+    /// [PromptContext::DiscardToHandSize] prompt
+    ToggleDiscardCard(CardId),
+    /// Discard the cards selected for a [PromptContext::DiscardToHandSize]
+    /// prompt
+    ConfirmDiscard,
+    /// Choose a pool of mana to pay from in response to a
+    /// [PromptContext::ChooseManaSource] prompt
+    ManaSourceChosen(ManaSource),
+    /// Champion action to retreat from a raid during a minion encounter,
+    /// ending it without accessing the target room
+    AbortRaid,
+}
+
+impl PromptAction {
+    /// Returns true if this response can be chosen on a player's behalf when
+    /// they fail to respond to a prompt in time, i.e. if it declines an
+    /// optional effect or allows a raid to continue without making any other
+    /// decision.
+    pub fn is_safe_default(&self) -> bool {
+        matches!(
+            self,
+            Self::ActivationPhaseAction(ActivationPhaseAction::ContinueRaid)
+                | Self::EncounterAction(EncounterAction::NoWeapon)
+                | Self::AccessPhaseAction(AccessPhaseAction::EndRaid)
+        )
+    }
 }
 
 /// Presents a choice to a user, typically communicated via a series of buttons
@@ -86,6 +244,9 @@ pub struct GamePrompt {
     pub context: Option<PromptContext>,
     /// Possible responses to this prompt
     pub responses: Vec<PromptAction>,
+    /// Whether this prompt can be dismissed via [UserAction::CancelPrompt],
+    /// reverting the action which caused it to be shown.
+    pub cancellable: bool,
 }
 
 impl GamePrompt {
@@ -93,6 +254,90 @@ impl GamePrompt {
         Self {
             context: None,
             responses: actions.into_iter().map(PromptAction::CardAction).collect(),
+            cancellable: false,
+        }
+    }
+
+    /// Response to choose on a player's behalf if they do not respond to this
+    /// prompt in time, or None if this prompt must wait for the player.
+    pub fn safe_default(&self) -> Option<PromptAction> {
+        self.responses.iter().copied().find(PromptAction::is_safe_default)
+    }
+
+    /// Whether `action` is a legal response to this prompt. In addition to the
+    /// listed `responses`, a [PromptAction::DamageDistribution] is accepted for
+    /// any [DamageAllocation] which is valid for this prompt's
+    /// [PromptContext::DistributeDamage] context.
+    pub fn accepts(&self, action: &PromptAction) -> bool {
+        self.responses.contains(action)
+            || match (action, &self.context) {
+                (
+                    PromptAction::DamageDistribution(allocation),
+                    Some(PromptContext::DistributeDamage { total, targets }),
+                ) => allocation.is_valid(*total, targets),
+                _ => false,
+            }
+    }
+
+    /// Prompt to divide `total` damage among the `targets` cards. A response
+    /// assigning all of the damage to each target is offered, and any other
+    /// split is accepted via [Self::accepts].
+    pub fn distribute_damage(total: u32, targets: Vec<CardId>) -> Result<Self> {
+        let responses = targets
+            .iter()
+            .map(|card_id| {
+                DamageAllocation::new([(*card_id, total)]).map(PromptAction::DamageDistribution)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            context: Some(PromptContext::DistributeDamage { total, targets }),
+            responses,
+            cancellable: false,
+        })
+    }
+
+    /// Prompt to choose `count` of the `hand` cards to discard, where the
+    /// `selected` cards have already been marked. Unselected cards can only be
+    /// marked while fewer than `count` cards are selected, and the discard can
+    /// only be confirmed once exactly `count` are.
+    pub fn discard_to_hand_size(hand: &[CardId], count: usize, selected: Vec<CardId>) -> Self {
+        let mut responses = vec![];
+        if selected.len() == count {
+            responses.push(PromptAction::ConfirmDiscard);
+        }
+        responses.extend(
+            hand.iter()
+                .filter(|card_id| selected.len() < count || selected.contains(card_id))
+                .map(|card_id| PromptAction::ToggleDiscardCard(*card_id)),
+        );
+        Self {
+            context: Some(PromptContext::DiscardToHandSize { count, selected }),
+            responses,
+            cancellable: false,
+        }
+    }
+
+    /// Prompt for the Champion to choose whether to pay `cost` to use the
+    /// `weapon_id` weapon against `target_id` from their raid-specific mana or
+    /// their base mana.
+    pub fn choose_mana_source(weapon_id: CardId, target_id: CardId, cost: ManaValue) -> Self {
+        Self {
+            context: Some(PromptContext::ChooseManaSource { weapon_id, target_id, cost }),
+            responses: vec![
+                PromptAction::ManaSourceChosen(ManaSource::RaidSpecific),
+                PromptAction::ManaSourceChosen(ManaSource::Base),
+            ],
+            cancellable: false,
+        }
+    }
+
+    /// Prompt for the `source` ability's controller to choose one of the
+    /// `valid` rooms.
+    pub fn choose_room(source: AbilityId, valid: Vec<RoomId>) -> Self {
+        Self {
+            responses: valid.iter().copied().map(PromptAction::RoomChosen).collect(),
+            context: Some(PromptContext::ChooseRoom { source, valid }),
+            cancellable: false,
         }
     }
 }
@@ -119,6 +364,10 @@ pub enum DebugAction {
     SaveState(u64),
     LoadState(u64),
     SetNamedPlayer(Side, NamedPlayer),
+
+    // Directly sets the resources of the [Side] player in the current game. Only available in
+    // debug builds.
+    SetResources { side: Side, mana: ManaValue, actions: ActionCount, score: PointsValue },
 }
 
 /// Possible targets for the 'play card' action. Note that many types of targets
@@ -131,6 +380,7 @@ pub enum DebugAction {
 pub enum CardTarget {
     None,
     Room(RoomId),
+    Card(CardId),
 }
 
 impl CardTarget {
@@ -142,6 +392,27 @@ impl CardTarget {
             _ => Err(anyhow!("Expected a RoomId to be provided but got {:?}", self)),
         }
     }
+
+    /// Gets the CardId targeted by a player, or returns an error if no target
+    /// was provided.
+    pub fn card_id(&self) -> Result<CardId> {
+        match self {
+            CardTarget::Card(card_id) => Ok(*card_id),
+            _ => Err(anyhow!("Expected a CardId to be provided but got {:?}", self)),
+        }
+    }
+}
+
+/// A reactive action which the opponent of a player could conceivably take,
+/// determined only from information that player can see.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub enum PossibleResponse {
+    /// A face-up card in play has this ability, which costs no actions and
+    /// which the opponent can currently afford.
+    ActivateAbility(AbilityId),
+    /// This card in play is hidden from the viewing player, and so could be a
+    /// minion to summon or a card with a response ability.
+    HiddenCard(CardId),
 }
 
 /// All possible actions a player can take during a game.
@@ -156,4 +427,23 @@ pub enum UserAction {
     InitiateRaid(RoomId),
     LevelUpRoom(RoomId),
     SpendActionPoint,
+    /// End the current turn without spending the player's remaining actions.
+    /// See [crate::game::GameConfiguration::carry_over_actions].
+    EndTurn,
+    /// Dismiss the current prompt if it is cancellable, see
+    /// [GamePrompt::cancellable].
+    CancelPrompt,
+    /// Concede the game, immediately ending it as a victory for the opponent.
+    Resign,
+    /// Revert the game to its state before this player's most recent action.
+    /// See [crate::game::GameState::undo_history].
+    Undo,
+    /// Queue a change to this player's deck for the remaining games of their
+    /// current match. Handled by the server rather than within a game, see
+    /// [crate::player_data::MatchData::sideboard_swaps].
+    SideboardSwap(SideboardSwap),
+    /// Withdraw this player's pending invitations to start a new game. Handled
+    /// by the server rather than within a game, see
+    /// [crate::player_data::PlayerData::pending_invitations].
+    CancelNewGame,
 }
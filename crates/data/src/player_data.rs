@@ -20,7 +20,17 @@ use serde_with::serde_as;
 use crate::card_name::CardName;
 use crate::deck::Deck;
 use crate::player_name::PlayerId;
-use crate::primitives::{DeckId, GameId};
+use crate::primitives::{DeckId, GameId, Locale, Sprite};
+
+/// An invitation a player has sent to start a new game against a specific
+/// opponent, which is waiting for that opponent to submit a matching request.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GameInvitation {
+    /// Player who has been invited to the game
+    pub opponent_id: PlayerId,
+    /// Deck the inviting player would like to use for this game
+    pub deck_id: DeckId,
+}
 
 /// Data for a player's request to create a new game
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,12 +41,78 @@ pub struct NewGameRequest {
 /// Represents the state of a game the player is participating in.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CurrentGame {
-    /// The player has initiated a request to create a game
+    /// The player had initiated a request to create a game. Requests are now
+    /// stored as [PlayerData::pending_invitations], so this state is no longer
+    /// created, but it is retained so that stored players can still be read.
+    /// A player in this state is treated as not being in a game.
     Requested(NewGameRequest),
     /// The player is currently playing in the [GameId] game.
     Playing(GameId),
 }
 
+/// Cosmetic choices a player has made which change how they appear to both
+/// players during a game. Fields which are `None` use the default appearance
+/// for the player's identity.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayerCosmetics {
+    /// Image to display on the back of this player's cards.
+    pub card_back: Option<Sprite>,
+    /// Frame to display around this player's portrait.
+    pub portrait_frame: Option<Sprite>,
+}
+
+/// Number of games a player must win to win a match.
+pub const MATCH_GAMES_TO_WIN: u32 = 2;
+
+/// Exchanges one copy of a card in a player's deck for one copy of a card from
+/// their sideboard.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct SideboardSwap {
+    /// Deck to modify
+    pub deck_id: DeckId,
+    /// Card to move from the deck to the sideboard
+    pub remove: CardName,
+    /// Card to move from the sideboard to the deck
+    pub add: CardName,
+}
+
+/// State for a best-of-three match against a single opponent. Each game in the
+/// match is created as a normal game, with [Self::sideboard_swaps] applied to
+/// the player's deck.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchData {
+    /// Opponent in this match
+    pub opponent_id: PlayerId,
+    /// Number of games in this match the player has won
+    pub games_won: u32,
+    /// Number of games in this match the player has lost
+    pub games_lost: u32,
+    /// Swaps to apply to the player's deck for subsequent games in this match,
+    /// in the order they were requested.
+    pub sideboard_swaps: Vec<SideboardSwap>,
+    /// Games whose results have already been counted in this match, so that a
+    /// result is not counted twice if recording it is retried.
+    #[serde(default)]
+    pub recorded_games: Vec<GameId>,
+}
+
+impl MatchData {
+    pub fn new(opponent_id: PlayerId) -> Self {
+        Self {
+            opponent_id,
+            games_won: 0,
+            games_lost: 0,
+            sideboard_swaps: vec![],
+            recorded_games: vec![],
+        }
+    }
+
+    /// True if either player has won [MATCH_GAMES_TO_WIN] games.
+    pub fn is_complete(&self) -> bool {
+        self.games_won >= MATCH_GAMES_TO_WIN || self.games_lost >= MATCH_GAMES_TO_WIN
+    }
+}
+
 /// Represents a player's stored data.
 ///
 /// For a player's state *within a given game* see `PlayerState`.
@@ -52,15 +128,37 @@ pub struct PlayerData {
     /// Cards owned by this player
     #[serde_as(as = "Vec<(_, _)>")]
     pub collection: HashMap<CardName, u32>,
+    /// Cosmetic options this player has selected.
+    #[serde(default)]
+    pub cosmetics: PlayerCosmetics,
+    /// Cards which can be swapped into this player's decks between games of a
+    /// match, see [SideboardSwap].
+    #[serde_as(as = "Vec<(_, _)>")]
+    #[serde(default)]
+    pub sideboard: HashMap<CardName, u32>,
+    /// Match this player is currently participating in, if any.
+    #[serde(default)]
+    pub current_match: Option<MatchData>,
+    /// Language in which this player's game text is displayed, as requested
+    /// when they most recently connected.
+    #[serde(default)]
+    pub locale: Locale,
+    /// Invitations this player has sent to start a new game which have not yet
+    /// been accepted, at most one per opponent.
+    #[serde(default)]
+    pub pending_invitations: Vec<GameInvitation>,
 }
 
 impl PlayerData {
-    /// Returns the [DeckId] this player requested to use for a new game.
-    pub fn requested_deck_id(&self) -> Option<DeckId> {
-        match &self.current_game {
-            Some(CurrentGame::Requested(request)) => Some(request.deck_id),
-            _ => None,
-        }
+    /// Returns this player's pending [GameInvitation] to the `opponent_id`
+    /// player, if any.
+    pub fn invitation_to(&self, opponent_id: PlayerId) -> Option<GameInvitation> {
+        self.pending_invitations.iter().find(|i| i.opponent_id == opponent_id).copied()
+    }
+
+    /// Retrieves one of a player's decks based on its [DeckId], if it exists.
+    pub fn find_deck(&self, deck_id: DeckId) -> Option<&Deck> {
+        self.decks.get(deck_id.value as usize)
     }
 
     /// Retrieves one of a player's decks based on its [DeckId].
@@ -76,8 +174,8 @@ impl PlayerData {
 /// Returns the [GameId] an optional [PlayerData] is currently playing in, if
 /// any.
 pub fn current_game_id(data: Option<PlayerData>) -> Option<GameId> {
-    match data.as_ref().and_then(|player| player.current_game.as_ref()) {
-        Some(CurrentGame::Playing(id)) => Some(*id),
+    match data.and_then(|player| player.current_game) {
+        Some(CurrentGame::Playing(id)) => Some(id),
         _ => None,
     }
 }
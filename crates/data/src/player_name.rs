@@ -52,6 +52,8 @@ pub enum NamedPlayer {
     TestAlphaBetaScores,
     TestAlphaBetaHeuristics,
     TestUct1,
+    TestExpectimax,
+    TestScripted,
 }
 
 impl NamedPlayer {
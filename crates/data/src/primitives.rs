@@ -233,6 +233,41 @@ impl fmt::Debug for RaidId {
     }
 }
 
+/// Opaque value identifying a card's sort order within its position. Higher
+/// sorting keys are closer to the 'top' or 'front' of the position.
+///
+/// The client truncates sorting keys to 16 bits, so values are restricted to
+/// the range `0..=SortingKey::MAX`. See
+/// [crate::game::GameState::next_sorting_key].
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Default, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct SortingKey(u32);
+
+impl SortingKey {
+    /// Largest valid sorting key. Keys are offset by 1 when rendered, which
+    /// must still fit within a signed 16-bit integer.
+    pub const MAX: u32 = i16::MAX as u32 - 1;
+
+    /// Creates a new sorting key, or returns `None` if `value` exceeds
+    /// [Self::MAX].
+    pub fn new(value: u32) -> Option<Self> {
+        if value <= Self::MAX {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    pub fn value(self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Debug for SortingKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// Contains the URL of an image asset within a game
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
 pub struct Sprite {
@@ -289,6 +324,16 @@ pub enum RoomLocation {
     Occupant,
 }
 
+/// Where a card is placed when it is added to a room's list of defenders.
+/// Defenders at the front of a room are encountered first during a raid.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Serialize, Deserialize)]
+pub enum DefenderPosition {
+    /// Outermost position, encountered before all other defenders
+    Front,
+    /// Innermost position, encountered after all other defenders
+    Back,
+}
+
 /// Used to control where an item is rendered within the Champion's item display
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Serialize, Deserialize, Ord, PartialOrd)]
 pub enum ItemLocation {
@@ -359,3 +404,11 @@ impl HasCardId for BoostData {
         self.card_id
     }
 }
+
+/// Language in which user-visible game text is displayed
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Default, Serialize, Deserialize, Sequence)]
+pub enum Locale {
+    #[default]
+    English,
+    Spanish,
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use rand::prelude::{IteratorRandom, SliceRandom};
+use rand_xoshiro::rand_core::SeedableRng;
+use rand_xoshiro::Xoshiro256StarStar;
 
 use crate::card_state::CardPosition;
 use crate::game::GameState;
@@ -20,7 +22,7 @@ use crate::primitives::{CardId, Side};
 
 /// Return a randomly-selected [CardId] of cards owned by the `side` player in
 /// the given [CardPosition], or None if no such card exists.
-#[allow(clippy::needless_collect)] // Invalid clippy warning
+#[allow(clippy::needless_collect, clippy::unnecessary_unwrap)] // Invalid clippy warnings
 pub fn card_in_position(
     game: &mut GameState,
     side: Side,
@@ -36,7 +38,7 @@ pub fn card_in_position(
 
 /// Return a vector of up to `count` randomly-selected [CardId]s of cards owned
 /// by the `side` player in the given [CardPosition].
-#[allow(clippy::needless_collect)] // Invalid clippy warning
+#[allow(clippy::needless_collect, clippy::unnecessary_unwrap)] // Invalid clippy warnings
 pub fn cards_in_position(
     game: &mut GameState,
     side: Side,
@@ -71,9 +73,28 @@ pub fn choose<I>(game: &mut GameState, iterator: I) -> Option<I::Item>
 where
     I: Iterator,
 {
-    if game.rng.is_some() {
-        iterator.choose(game.rng.as_mut().expect("rng"))
+    if let Some(rng) = game.rng.as_mut() {
+        iterator.choose(rng)
     } else {
         iterator.choose(&mut rand::thread_rng())
     }
 }
+
+/// Shuffles `slice` in place using the game random number generator.
+pub fn shuffle<T>(game: &mut GameState, slice: &mut [T]) {
+    if let Some(rng) = game.rng.as_mut() {
+        slice.shuffle(rng);
+    } else {
+        slice.shuffle(&mut rand::thread_rng());
+    }
+}
+
+/// Replaces the seed used by this game's random number generator, e.g. so that
+/// copies of a deterministic game can diverge. Has no effect on games which are
+/// not deterministic.
+pub fn reseed(game: &mut GameState, seed: u64) {
+    game.data.config.seed = Some(seed);
+    if game.rng.is_some() {
+        game.rng = Some(Xoshiro256StarStar::seed_from_u64(seed));
+    }
+}
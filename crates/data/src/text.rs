@@ -127,7 +127,7 @@ impl From<Keyword> for TextToken {
 impl<T> From<Cost<T>> for TextToken {
     fn from(cost: Cost<T>) -> Self {
         let mut result = vec![];
-        if let Some(mana) = cost.mana {
+        if let Some(mana) = cost.mana.value() {
             result.push(Self::Mana(mana))
         }
 
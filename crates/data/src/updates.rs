@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::game::GameState;
+use crate::game::{GameState, TurnData};
 use crate::primitives::{AbilityId, CardId, GameObjectId, RoomId, Side};
 
 /// Indicates one game object targeted another with an effect.
@@ -37,8 +37,9 @@ pub enum InitiatedBy {
 /// into a client animation
 #[derive(Debug, Clone)]
 pub enum GameUpdate {
-    /// Indicates that a player's turn has started
-    StartTurn(Side),
+    /// Indicates that a new turn has started. Recorded exactly once per turn
+    /// transition, this drives the Dawn and Dusk messages in the UI.
+    TurnChanged(TurnData),
     /// A player has played a card which entered play face-up.
     PlayCardFaceUp(Side, CardId),
     /// A player has activated an ability of a card
@@ -53,18 +54,59 @@ pub enum GameUpdate {
     UnveilProject(CardId),
     /// A minion card has been turned face-up.
     SummonMinion(CardId),
+    /// A previously-hidden card has been revealed to the [Side] player.
+    CardRevealed(Side, CardId),
+    /// The amount of mana stored in a card has changed.
+    StoredManaChanged(CardId),
+    /// The level of a card has been set directly by a card effect.
+    CardLevelChanged(CardId),
+    /// A card has been added as a defender of a room by a card effect.
+    DefenderAdded(CardId),
     /// The Overlord has leveled up a room
     LevelUpRoom(RoomId, InitiatedBy),
     /// The Champion has initiated a raid on a room
     InitiateRaid(RoomId, InitiatedBy),
+    /// The Champion has retreated from a raid on a room without accessing it
+    AbortRaid(RoomId),
     /// See [TargetedInteraction].
     TargetedInteraction(TargetedInteraction),
     /// A player has scored a card
     ScoreCard(Side, CardId),
+    /// A player's score has been changed by an effect other than scoring a
+    /// card.
+    PointsChanged(Side),
+    /// A token copy of the first card has been created with the second
+    /// [CardId].
+    CreateTokenCopy(CardId, CardId),
     /// The game has ended and the indicated player has won
     GameOver(Side),
 }
 
+impl GameUpdate {
+    /// Human-readable description of this update for the game log, if it
+    /// should appear there.
+    pub fn log_entry(&self, game: &GameState) -> Option<String> {
+        let name = |card_id: CardId| game.card(card_id).name.displayed_name();
+        Some(match self {
+            Self::TurnChanged(turn) => format!("{:?} started turn {}", turn.side, turn.turn_number),
+            Self::PlayCardFaceUp(side, card_id) => format!("{:?} played {}", side, name(*card_id)),
+            Self::AbilityActivated(side, ability_id) => {
+                format!("{:?} activated {}", side, name(ability_id.card_id))
+            }
+            Self::DrawCards(side, cards) if cards.len() == 1 => format!("{:?} drew a card", side),
+            Self::DrawCards(side, cards) => format!("{:?} drew {} cards", side, cards.len()),
+            Self::UnveilProject(card_id) => format!("Overlord unveiled {}", name(*card_id)),
+            Self::SummonMinion(card_id) => format!("Overlord summoned {}", name(*card_id)),
+            Self::LevelUpRoom(room_id, _) => format!("Overlord leveled up {:?}", room_id),
+            Self::InitiateRaid(room_id, _) => format!("Champion initiated raid on {:?}", room_id),
+            Self::AbortRaid(room_id) => format!("Champion aborted raid on {:?}", room_id),
+            Self::ScoreCard(side, card_id) => format!("{:?} scored {}", side, name(*card_id)),
+            Self::GameOver(side) => format!("{:?} won the game", side),
+            _ => return None,
+        })
+    }
+}
+
 /// A step in the animation process
 #[derive(Debug, Clone)]
 pub struct UpdateStep {
@@ -27,10 +27,10 @@ use protos::spelldawn::game_command::Command;
 use protos::spelldawn::object_position::Position;
 use protos::spelldawn::play_effect_position::EffectPosition;
 use protos::spelldawn::{
-    CreateTokenCardCommand, DelayCommand, DisplayGameMessageCommand, DisplayRewardsCommand,
-    FireProjectileCommand, GameMessageType, GameObjectMove, MoveGameObjectsCommand, MusicState,
-    PlayEffectCommand, PlayEffectPosition, PlaySoundCommand, RoomVisitType,
-    SetGameObjectsEnabledCommand, SetMusicCommand, TimeValue, VisitRoomCommand,
+    CreateTokenCardCommand, DelayCommand, DestroyCardCommand, DisplayGameMessageCommand,
+    DisplayRewardsCommand, FireProjectileCommand, GameMessageType, GameObjectMove,
+    MoveGameObjectsCommand, MusicState, PlayEffectCommand, PlayEffectPosition, PlaySoundCommand,
+    RoomVisitType, SetGameObjectsEnabledCommand, SetMusicCommand, TimeValue, VisitRoomCommand,
 };
 
 use crate::{assets, card_sync, positions};
@@ -41,15 +41,16 @@ pub fn render(
     snapshot: &GameState,
 ) -> Result<()> {
     match update {
-        GameUpdate::StartTurn(side) => start_turn(builder, *side),
+        GameUpdate::TurnChanged(turn) => start_turn(builder, turn.side),
         GameUpdate::PlayCardFaceUp(side, card_id) => {
             if builder.user_side == side.opponent() {
-                show_cards(builder, &vec![*card_id])
+                show_cards(builder, &[*card_id])
             }
         }
         GameUpdate::AbilityActivated(side, ability_id) => {
             if *side != builder.user_side {
                 show_ability(builder, snapshot, *ability_id);
+                return_ability_to_card(builder, snapshot, *ability_id);
             }
         }
         GameUpdate::AbilityTriggered(ability_id) => show_ability(builder, snapshot, *ability_id),
@@ -58,17 +59,27 @@ pub fn render(
                 show_cards(builder, cards)
             }
         }
-        GameUpdate::ShuffleIntoDeck => {
+        GameUpdate::ShuffleIntoDeck
+        | GameUpdate::StoredManaChanged(_)
+        | GameUpdate::CardLevelChanged(_)
+        | GameUpdate::DefenderAdded(_)
+        | GameUpdate::AbortRaid(_)
+        | GameUpdate::PointsChanged(_) => {
             // No animation, just acts as a snapshot point.
         }
         GameUpdate::UnveilProject(card_id) => {
             if builder.user_side == Side::Champion {
-                show_cards(builder, &vec![*card_id])
+                show_cards(builder, &[*card_id])
             }
         }
         GameUpdate::SummonMinion(card_id) => {
             if builder.user_side == Side::Champion {
-                show_cards(builder, &vec![*card_id])
+                show_cards(builder, &[*card_id])
+            }
+        }
+        GameUpdate::CardRevealed(side, card_id) => {
+            if builder.user_side == *side {
+                show_cards(builder, &[*card_id])
             }
         }
         GameUpdate::LevelUpRoom(room_id, initiated_by) => {
@@ -89,11 +100,20 @@ pub fn render(
             targeted_interaction(builder, snapshot, interaction)
         }
         GameUpdate::ScoreCard(_, card_id) => score_card(builder, *card_id),
+        GameUpdate::CreateTokenCopy(source_id, token_id) => {
+            create_token_copy(builder, snapshot, *source_id, *token_id)?
+        }
         GameUpdate::GameOver(side) => game_over(builder, snapshot, *side)?,
     }
     Ok(())
 }
 
+/// Displays the Dawn or Dusk message for a new turn.
+///
+/// This is the first step of the turn handoff. The turn's draw is recorded as
+/// a subsequent update and the new action count is only granted after that
+/// draw, so the client shows this message, then the draw, then the updated
+/// action tracker.
 fn start_turn(builder: &mut ResponseBuilder, side: Side) {
     builder.push(Command::DisplayGameMessage(DisplayGameMessageCommand {
         message_type: match side {
@@ -103,7 +123,7 @@ fn start_turn(builder: &mut ResponseBuilder, side: Side) {
     }))
 }
 
-fn show_cards(builder: &mut ResponseBuilder, cards: &Vec<CardId>) {
+fn show_cards(builder: &mut ResponseBuilder, cards: &[CardId]) {
     let is_large = cards.len() >= 4;
     builder.push(Command::MoveGameObjects(MoveGameObjectsCommand {
         moves: cards
@@ -149,6 +169,55 @@ fn show_ability(builder: &mut ResponseBuilder, snapshot: &GameState, ability_id:
     builder.push(delay(1500));
 }
 
+/// Creates a token copy of the `source_id` card on top of it and displays it
+/// in the staging area. The snapshot for this update does not yet contain the
+/// token, so its appearance is taken from the source card.
+fn create_token_copy(
+    builder: &mut ResponseBuilder,
+    snapshot: &GameState,
+    source_id: CardId,
+    token_id: CardId,
+) -> Result<()> {
+    let source = snapshot.card(source_id);
+    let mut card = card_sync::card_view(builder, snapshot, source)?;
+    card.card_id = Some(adapters::card_identifier(token_id));
+    card.create_position = Some(positions::convert(builder, snapshot, source)?);
+    card.card_position = Some(positions::for_sorting_key(0, positions::staging()));
+
+    builder.push(Command::CreateTokenCard(CreateTokenCardCommand {
+        card: Some(card),
+        animate: builder.state.animate,
+    }));
+
+    builder.push(delay(1000));
+    Ok(())
+}
+
+/// Animates an ability token created via [show_ability] moving back into its
+/// parent card, after which the token is destroyed.
+fn return_ability_to_card(
+    builder: &mut ResponseBuilder,
+    snapshot: &GameState,
+    ability_id: AbilityId,
+) {
+    builder.push(Command::MoveGameObjects(MoveGameObjectsCommand {
+        moves: vec![GameObjectMove {
+            id: Some(adapters::game_object_identifier(builder, ability_id)),
+            position: Some(positions::for_ability(
+                snapshot,
+                ability_id,
+                positions::parent_card(ability_id),
+            )),
+        }],
+        disable_animation: !builder.state.animate,
+        delay: None,
+    }));
+    builder.push(Command::DestroyCard(DestroyCardCommand {
+        card_id: Some(adapters::ability_card_identifier(ability_id)),
+        animate: builder.state.animate,
+    }));
+}
+
 fn level_up_room(commands: &mut ResponseBuilder, target: RoomId) {
     commands.push(Command::VisitRoom(VisitRoomCommand {
         initiator: commands.to_player_name(Side::Overlord),
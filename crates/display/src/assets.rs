@@ -14,7 +14,9 @@
 
 //! Helper functions for constructing resource URLs used during a game
 
+use anyhow::Result;
 use core_ui::design::FontColor;
+use data::card_definition::CardDefinition;
 use data::primitives::{CardType, Lineage, Rarity, School, Side};
 use data::special_effects::{
     FantasyEventSounds, FireworksSound, Projectile, SoundEffect, TimedEffect,
@@ -22,6 +24,11 @@ use data::special_effects::{
 use protos::spelldawn::{
     AudioClipAddress, EffectAddress, FlexColor, ProjectileAddress, SpriteAddress,
 };
+use with_error::verify;
+
+/// Top-level asset directories which sprite addresses are expected to
+/// reference.
+const SPRITE_DIRECTORIES: &[&str] = &["Enixion/", "LittleSweetDaemon/", "Rexard/", "SpriteWay/"];
 
 /// Possible types of icons which can appear on a card
 pub enum CardIconType {
@@ -262,3 +269,43 @@ pub fn sound_effect(effect: SoundEffect) -> AudioClipAddress {
         ),
     }
 }
+
+/// Checks that every registered [CardDefinition] produces valid sprite
+/// addresses, returning an error describing each card which does not.
+///
+/// The client silently fails to display sprites with invalid addresses, so
+/// this should be invoked on startup to catch typos in card definitions.
+pub fn validate_card_definitions() -> Vec<anyhow::Error> {
+    rules::CARDS
+        .values()
+        .filter_map(|definition| {
+            validate_card_definition(definition)
+                .err()
+                .map(|error| error.context(format!("Invalid assets for {:?}", definition.name)))
+        })
+        .collect()
+}
+
+/// Checks that the sprite addresses used to render a [CardDefinition] are
+/// valid. See [validate_sprite].
+pub fn validate_card_definition(definition: &CardDefinition) -> Result<()> {
+    validate_sprite(&SpriteAddress { address: definition.image.address.clone() })?;
+    validate_sprite(&card_back(definition.school))?;
+    validate_sprite(&card_frame(definition.school))?;
+    validate_sprite(&jewel(definition.rarity))?;
+    validate_sprite(&title_background(definition.config.lineage))?;
+    validate_sprite(&arena_frame(definition.side, definition.card_type, definition.config.lineage))
+}
+
+/// Returns an error if a [SpriteAddress] is empty or does not reference a
+/// known asset directory.
+pub fn validate_sprite(sprite: &SpriteAddress) -> Result<()> {
+    verify!(!sprite.address.is_empty(), "Sprite address is empty");
+    verify!(
+        SPRITE_DIRECTORIES.iter().any(|directory| sprite.address.starts_with(directory)),
+        "Sprite address {:?} does not reference a known asset directory",
+        sprite.address
+    );
+    verify!(sprite.address.ends_with(".png"), "Sprite address {:?} is not a PNG", sprite.address);
+    Ok(())
+}
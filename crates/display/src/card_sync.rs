@@ -19,7 +19,7 @@ use data::card_definition::{AbilityType, CardDefinition, TargetRequirement};
 use data::card_state::CardState;
 use data::game::GameState;
 use data::game_actions::CardTarget;
-use data::primitives::{AbilityId, CardType, ItemLocation, ManaValue, RoomId, RoomLocation};
+use data::primitives::{AbilityId, CardType, ItemLocation, ManaValue, RoomId};
 use protos::spelldawn::card_targeting::Targeting;
 use protos::spelldawn::{
     ArrowTargetRoom, CardIcon, CardIcons, CardPrefab, CardTargeting, CardTitle, CardView,
@@ -28,7 +28,7 @@ use protos::spelldawn::{
 use rules::{flags, queries};
 
 use crate::assets::CardIconType;
-use crate::{assets, positions, rules_text};
+use crate::{assets, localization, positions, rules_text};
 
 pub fn card_view(
     builder: &ResponseBuilder,
@@ -36,12 +36,12 @@ pub fn card_view(
     card: &CardState,
 ) -> Result<CardView> {
     let definition = rules::get(card.name);
-    let revealed = card.is_revealed_to(builder.user_side);
+    let revealed = builder.is_revealed(card);
     Ok(CardView {
         card_id: Some(adapters::card_identifier(card.id)),
         card_position: Some(positions::convert(builder, game, card)?),
         prefab: CardPrefab::Standard.into(),
-        revealed_to_viewer: card.is_revealed_to(builder.user_side),
+        revealed_to_viewer: revealed,
         is_face_up: card.is_face_up(),
         card_icons: Some(card_icons(game, card, definition, revealed)),
         arena_frame: Some(assets::arena_frame(
@@ -66,7 +66,7 @@ pub fn activated_ability_cards(
     card: &CardState,
 ) -> Vec<Result<CardView>> {
     let mut result = vec![];
-    if card.side() != builder.user_side || !card.position().in_play() {
+    if builder.is_spectator() || card.side() != builder.user_side || !card.position().in_play() {
         return result;
     }
 
@@ -128,26 +128,26 @@ fn revealed_card_view(
         jewel: Some(assets::jewel(definition.rarity)),
         image: Some(adapters::sprite(&definition.image)),
         title: Some(CardTitle {
-            text: definition.name.displayed_name(),
+            text: localization::STRINGS.title(definition.name, builder.state.options.locale),
             text_color: Some(assets::title_color(definition.config.lineage)),
         }),
-        rules_text: Some(rules_text::build(game, card, definition)),
+        rules_text: Some(rules_text::build(game, card, definition, builder.state.options.locale)),
         targeting: Some(card_targeting(
+            game,
             definition.config.custom_targeting.as_ref(),
             flags::enters_play_in_room(game, card.id),
-            |target| flags::can_take_play_card_action(game, builder.user_side, card.id, target),
+            |target| {
+                !builder.is_spectator()
+                    && flags::can_take_play_card_action(game, builder.user_side, card.id, target)
+            },
         )),
         on_release_position: Some(positions::for_sorting_key(
             positions::RELEASE_SORTING_KEY,
-            match definition.card_type {
-                CardType::Weapon => positions::item(ItemLocation::Weapons),
-                CardType::Artifact => positions::item(ItemLocation::Artifacts),
-                CardType::OverlordSpell => positions::staging(),
-                CardType::ChampionSpell => positions::staging(),
-                CardType::Minion => positions::unspecified_room(RoomLocation::Defender),
-                CardType::Project => positions::unspecified_room(RoomLocation::Occupant),
-                CardType::Scheme => positions::unspecified_room(RoomLocation::Occupant),
-                CardType::Identity => positions::staging(),
+            match (definition.card_type, queries::play_location(definition.card_type)) {
+                (_, Some(location)) => positions::unspecified_room(location),
+                (CardType::Weapon, _) => positions::item(ItemLocation::Weapons),
+                (CardType::Artifact, _) => positions::item(ItemLocation::Artifacts),
+                _ => positions::staging(),
             },
         )),
         supplemental_info: rules_text::build_supplemental_info(game, card, None),
@@ -155,7 +155,7 @@ fn revealed_card_view(
 }
 
 fn revealed_ability_card_view(
-    _builder: &ResponseBuilder,
+    builder: &ResponseBuilder,
     game: &GameState,
     ability_id: AbilityId,
     target_requirement: Option<&TargetRequirement<AbilityId>>,
@@ -169,11 +169,11 @@ fn revealed_ability_card_view(
         jewel: None,
         image: Some(adapters::sprite(&definition.image)),
         title: Some(CardTitle {
-            text: definition.name.displayed_name(),
+            text: localization::STRINGS.title(definition.name, builder.state.options.locale),
             text_color: Some(assets::title_color(None)),
         }),
         rules_text: Some(RulesText { text: rules_text::ability_text(game, ability_id, ability) }),
-        targeting: Some(card_targeting(target_requirement, false, |target| {
+        targeting: Some(card_targeting(game, target_requirement, false, |target| {
             flags::can_take_activate_ability_action(game, ability_id.side(), ability_id, target)
         })),
         on_release_position: Some(positions::for_ability(game, ability_id, positions::staging())),
@@ -182,6 +182,7 @@ fn revealed_ability_card_view(
 }
 
 fn card_targeting<T>(
+    game: &GameState,
     requirement: Option<&TargetRequirement<T>>,
     play_in_room: bool,
     can_play: impl Fn(CardTarget) -> bool,
@@ -191,6 +192,12 @@ fn card_targeting<T>(
             (None, false) | (Some(TargetRequirement::None), _) => {
                 Targeting::NoTargeting(NoTargeting { can_play: can_play(CardTarget::None) })
             }
+            (Some(TargetRequirement::TargetCard(_)), _) => {
+                // Card targets are selected via a follow-up prompt after playing
+                Targeting::NoTargeting(NoTargeting {
+                    can_play: game.all_cards().any(|card| can_play(CardTarget::Card(card.id))),
+                })
+            }
             (None, true) | (Some(TargetRequirement::TargetRoom(_)), _) => {
                 let valid = enum_iterator::all::<RoomId>()
                     .filter(|room_id| can_play(CardTarget::Room(*room_id)))
@@ -235,7 +242,12 @@ fn card_icons(
     }
 
     if revealed {
-        icons.top_left_icon = queries::mana_cost(game, card.id).map(mana_card_icon).or_else(|| {
+        icons.top_left_icon = if flags::has_variable_mana_cost(game, card.id) {
+            Some(variable_mana_card_icon())
+        } else {
+            queries::mana_cost(game, card.id).map(mana_card_icon)
+        }
+        .or_else(|| {
             definition.config.stats.scheme_points.map(|points| CardIcon {
                 background: Some(assets::card_icon(CardIconType::LevelRequirement)),
                 text: Some(points.level_requirement.to_string()),
@@ -278,6 +290,14 @@ fn card_icons(
     icons
 }
 
+fn variable_mana_card_icon() -> CardIcon {
+    CardIcon {
+        background: Some(assets::card_icon(CardIconType::Mana)),
+        text: Some("X".to_string()),
+        background_scale: assets::background_scale(CardIconType::Mana),
+    }
+}
+
 fn mana_card_icon(value: ManaValue) -> CardIcon {
     CardIcon {
         background: Some(assets::card_icon(CardIconType::Mana)),
@@ -0,0 +1,152 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Computes compact updates between two GameViews
+
+use std::collections::HashMap;
+
+use adapters::response_builder::ResponseOptions;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::game_object_identifier::Id;
+use protos::spelldawn::{
+    CardView, GameObjectIdentifier, GameObjectMove, GameView, MoveGameObjectsCommand,
+    PlayerScalarsUpdate, PlayerView, UpdateCardsCommand, UpdatePlayerScalarsCommand,
+};
+
+/// Returns commands which transform `previous` into `next` without resending
+/// the full [GameView].
+///
+/// Changes to the scalar fields of the players (score, mana, actions,
+/// `can_take_action`, and `available_undo_count`) are sent as an [UpdatePlayerScalarsCommand]. If
+/// [ResponseOptions::card_deltas] is set, cards which have only changed
+/// position are moved via a [MoveGameObjectsCommand] and other changed cards
+/// are sent via an [UpdateCardsCommand], omitting their revealed content if it
+/// has not changed.
+///
+/// Returns None if anything else has changed, in which case the caller should
+/// send a full [GameView] instead.
+pub fn execute(
+    previous: &GameView,
+    next: &GameView,
+    options: ResponseOptions,
+    animate: bool,
+) -> Option<Vec<Command>> {
+    let cards = options.card_deltas;
+    if !(options.scalar_deltas || cards)
+        || without_deltas(previous, cards) != without_deltas(next, cards)
+    {
+        return None;
+    }
+
+    let mut result = vec![Command::UpdatePlayerScalars(UpdatePlayerScalarsCommand {
+        user: player_update(previous.user.as_ref(), next.user.as_ref()),
+        opponent: player_update(previous.opponent.as_ref(), next.opponent.as_ref()),
+        animate,
+    })];
+    if cards {
+        result.extend(card_updates(&previous.cards, &next.cards, animate)?);
+    }
+    Some(result)
+}
+
+fn without_deltas(view: &GameView, cards: bool) -> GameView {
+    let mut result = view.clone();
+    for player in [&mut result.user, &mut result.opponent].into_iter().flatten() {
+        player.score = None;
+        player.mana = None;
+        player.action_tracker = None;
+        player.can_take_action = false;
+        player.available_undo_count = 0;
+    }
+    if cards {
+        result.cards.clear();
+    }
+    result
+}
+
+fn player_update(
+    previous: Option<&PlayerView>,
+    next: Option<&PlayerView>,
+) -> Option<PlayerScalarsUpdate> {
+    let (previous, next) = (previous?, next?);
+    let result = PlayerScalarsUpdate {
+        score: changed(&previous.score, &next.score),
+        mana: changed(&previous.mana, &next.mana),
+        action_tracker: changed(&previous.action_tracker, &next.action_tracker),
+        can_take_action: (previous.can_take_action != next.can_take_action)
+            .then_some(next.can_take_action),
+        available_undo_count: (previous.available_undo_count != next.available_undo_count)
+            .then_some(next.available_undo_count),
+    };
+    (result != PlayerScalarsUpdate::default()).then_some(result)
+}
+
+/// Returns commands to update the cards in `previous` to match `next`, or None
+/// if any card has been created or destroyed.
+fn card_updates(previous: &[CardView], next: &[CardView], animate: bool) -> Option<Vec<Command>> {
+    let previous = previous.iter().map(|card| (card.card_id, card)).collect::<HashMap<_, _>>();
+    if previous.len() != next.len() {
+        return None;
+    }
+
+    let mut moves = vec![];
+    let mut updates = vec![];
+    for card in next {
+        let old = previous.get(&card.card_id)?;
+        if *old == card {
+            continue;
+        }
+
+        if without_position(old) == without_position(card) {
+            moves.push(GameObjectMove {
+                id: Some(GameObjectIdentifier { id: Some(Id::CardId(card.card_id?)) }),
+                position: card.card_position.clone(),
+            });
+        } else {
+            let mut update = card.clone();
+            if old.revealed_card == card.revealed_card {
+                update.revealed_card = None;
+            }
+            updates.push(update);
+        }
+    }
+
+    let mut result = vec![];
+    if !updates.is_empty() {
+        result.push(Command::UpdateCards(UpdateCardsCommand { cards: updates, animate }));
+    }
+    if !moves.is_empty() {
+        result.push(Command::MoveGameObjects(MoveGameObjectsCommand {
+            moves,
+            disable_animation: !animate,
+            delay: None,
+        }));
+    }
+    Some(result)
+}
+
+/// Clears the position of `card`, along with its create and destroy positions,
+/// which are only used when a card enters or leaves the game and change along
+/// with the sorting keys of other positions.
+fn without_position(card: &CardView) -> CardView {
+    CardView { card_position: None, create_position: None, destroy_position: None, ..card.clone() }
+}
+
+fn changed<T: Clone + PartialEq>(previous: &Option<T>, next: &Option<T>) -> Option<T> {
+    if previous == next {
+        None
+    } else {
+        next.clone()
+    }
+}
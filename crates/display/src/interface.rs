@@ -12,12 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
+use core_ui::design::FontSize;
+use core_ui::icons;
+use core_ui::prelude::*;
+use core_ui::rendering;
+use core_ui::text::Text;
 use data::game::{GamePhase, GameState, MulliganDecision};
-use data::game_actions::{GamePrompt, PromptAction};
+use data::game_actions::{EncounterAction, GamePrompt, PromptAction};
 use data::primitives::Side;
 use prompts::prompts;
-use protos::spelldawn::InterfaceMainControls;
+use protos::spelldawn::{
+    AnchorCorner, CardAnchor, CardAnchorNode, FlexAlign, FlexJustify, InterfaceMainControls, Node,
+};
+use rules::queries;
 
 /// Returns a [InterfaceMainControls] to render the interface state for the
 /// provided `game`.
@@ -25,22 +35,116 @@ pub fn render(game: &GameState, side: Side) -> Result<Option<InterfaceMainContro
     if let Some(prompt) = &game.player(side).prompt {
         return prompts::action_prompt(game, side, prompt);
     } else if let Some(prompt) = raids::current_prompt(game, side)? {
-        return prompts::action_prompt(game, side, &prompt);
+        let mut controls = prompts::action_prompt(game, side, &prompt)?;
+        if let Some(controls) = &mut controls {
+            controls.card_anchor_nodes.extend(combat_preview(game, &prompt));
+        }
+        return Ok(controls);
     } else if let GamePhase::ResolveMulligans(data) = &game.data.phase {
         if data.decision(side).is_none() {
+            let mut responses = vec![
+                PromptAction::MulliganDecision(MulliganDecision::Keep),
+                PromptAction::MulliganDecision(MulliganDecision::Mulligan),
+            ];
+            let selection = data.selection(side)?;
+            if selection.cards().next().is_some() {
+                responses
+                    .push(PromptAction::MulliganDecision(MulliganDecision::Partial(selection)));
+            }
+            responses.extend(
+                game.hand(side)
+                    .filter(|card| {
+                        data.can_select_more(side) || data.selected_cards.contains(&card.id)
+                    })
+                    .map(|card| PromptAction::ToggleMulliganCard(card.id)),
+            );
+
             return prompts::action_prompt(
                 game,
                 side,
-                &GamePrompt {
-                    context: None,
-                    responses: vec![
-                        PromptAction::MulliganDecision(MulliganDecision::Keep),
-                        PromptAction::MulliganDecision(MulliganDecision::Mulligan),
-                    ],
-                },
+                &GamePrompt { context: None, responses, cancellable: false },
             );
         }
     }
 
     Ok(None)
 }
+
+/// Renders a banner indicating whether the `side` player is currently able to
+/// act. During a raid this is the player who can act in the current raid
+/// phase, otherwise it is the player whose turn it is.
+///
+/// Returns `None` outside of the main game phase.
+pub fn turn_banner(game: &GameState, side: Side) -> Option<Node> {
+    if !matches!(game.data.phase, GamePhase::Play) {
+        return None;
+    }
+
+    let active_side = raids::active_side(game).unwrap_or(game.data.turn.side);
+    let label = if active_side == side { "Your turn" } else { "Opponent's turn" };
+    rendering::component(
+        Row::new("TurnBanner")
+            .style(Style::new().justify_content(FlexJustify::Center).align_items(FlexAlign::Center))
+            .child(Text::new(label, FontSize::PromptContext)),
+    )
+}
+
+/// Builds card anchor nodes previewing the outcome of using each of the
+/// Champion's weapons against the minion they are currently encountering,
+/// including the mana cost to defeat it.
+///
+/// Returns no nodes if `prompt` is not an encounter prompt.
+fn combat_preview(game: &GameState, prompt: &GamePrompt) -> Vec<CardAnchorNode> {
+    if !prompt.responses.iter().any(|response| matches!(response, PromptAction::EncounterAction(_)))
+    {
+        return vec![];
+    }
+
+    let options = prompt
+        .responses
+        .iter()
+        .filter_map(|response| match response {
+            PromptAction::EncounterAction(EncounterAction::UseWeaponAbility(weapon, minion)) => {
+                Some((*weapon, *minion))
+            }
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    game.weapons()
+        .map(|weapon| {
+            let cost = options
+                .get(&weapon.id)
+                .and_then(|minion| queries::cost_to_defeat_target(game, weapon.id, *minion));
+            let label = match cost {
+                Some(0) => "Defeat".to_string(),
+                Some(cost) => format!("Defeat for {}{}", cost, icons::MANA),
+                None => "Cannot defeat".to_string(),
+            };
+
+            CardAnchorNode {
+                card_id: Some(adapters::card_identifier(weapon.id)),
+                node: rendering::component(
+                    Row::new("CombatPreview")
+                        .style(
+                            Style::new()
+                                .padding(Edge::Bottom, 8.px())
+                                .justify_content(FlexJustify::Center)
+                                .align_items(FlexAlign::Center),
+                        )
+                        .child(Text::new(label, FontSize::SupplementalInfo)),
+                ),
+                anchors: vec![
+                    CardAnchor {
+                        node_corner: AnchorCorner::BottomLeft as i32,
+                        card_corner: AnchorCorner::TopLeft as i32,
+                    },
+                    CardAnchor {
+                        node_corner: AnchorCorner::BottomRight as i32,
+                        card_corner: AnchorCorner::TopRight as i32,
+                    },
+                ],
+            }
+        })
+        .collect()
+}
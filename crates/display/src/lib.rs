@@ -18,7 +18,9 @@
 pub mod animations;
 pub mod assets;
 pub mod card_sync;
+pub mod diff;
 pub mod interface;
+pub mod localization;
 pub mod positions;
 pub mod render;
 pub mod rexard_images;
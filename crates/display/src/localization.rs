@@ -0,0 +1,109 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Translations of user-visible card text into each supported [Locale].
+
+use std::collections::HashMap;
+
+use data::card_name::CardName;
+use data::primitives::Locale;
+use data::text::TextToken;
+use once_cell::sync::Lazy;
+
+/// Translated text for a single card in a single [Locale].
+#[derive(Debug, Clone)]
+pub struct CardStrings {
+    pub title: String,
+    /// Translations of the literal words in the card's rules text, keyed by
+    /// their English text. Values such as mana amounts are still rendered from
+    /// the card definition. If empty, the English rules text is displayed.
+    pub rules_text: HashMap<String, String>,
+}
+
+impl CardStrings {
+    pub fn title(title: impl Into<String>) -> Self {
+        Self { title: title.into(), rules_text: HashMap::new() }
+    }
+
+    pub fn rules_text(mut self, english: impl Into<String>, translated: impl Into<String>) -> Self {
+        self.rules_text.insert(english.into(), translated.into());
+        self
+    }
+}
+
+/// Lookup table for translated card text, keyed by ([CardName], [Locale]).
+///
+/// English text is generated from card definitions and is never stored here.
+#[derive(Debug, Clone, Default)]
+pub struct LocalizedStrings {
+    strings: HashMap<(CardName, Locale), CardStrings>,
+}
+
+impl LocalizedStrings {
+    pub fn insert(&mut self, name: CardName, locale: Locale, strings: CardStrings) {
+        self.strings.insert((name, locale), strings);
+    }
+
+    /// Returns the translated text for a card, if any.
+    pub fn get(&self, name: CardName, locale: Locale) -> Option<&CardStrings> {
+        self.strings.get(&(name, locale))
+    }
+
+    /// Returns the title of a card in the given [Locale], falling back to its
+    /// English name if no translation exists.
+    pub fn title(&self, name: CardName, locale: Locale) -> String {
+        self.get(name, locale)
+            .map_or_else(|| name.displayed_name(), |strings| strings.title.clone())
+    }
+
+    /// Translates the literal words in a card's rules text `tokens`, or returns
+    /// `None` if the English rules text should be displayed.
+    pub fn rules_text(
+        &self,
+        name: CardName,
+        locale: Locale,
+        tokens: &[TextToken],
+    ) -> Option<Vec<TextToken>> {
+        let translations = &self.get(name, locale)?.rules_text;
+        if translations.is_empty() {
+            return None;
+        }
+
+        Some(
+            tokens
+                .iter()
+                .map(|token| match token {
+                    TextToken::Literal(text) => {
+                        TextToken::Literal(translations.get(text).unwrap_or(text).clone())
+                    }
+                    _ => token.clone(),
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Translations for all cards
+pub static STRINGS: Lazy<LocalizedStrings> = Lazy::new(|| {
+    let mut result = LocalizedStrings::default();
+    result.insert(
+        CardName::ArcaneRecovery,
+        Locale::Spanish,
+        CardStrings::title("Recuperación Arcana").rules_text("Gain", "Gana"),
+    );
+    result.insert(CardName::Meditation, Locale::Spanish, CardStrings::title("Meditación"));
+    result.insert(CardName::GoldMine, Locale::Spanish, CardStrings::title("Mina de Oro"));
+    result.insert(CardName::Lodestone, Locale::Spanish, CardStrings::title("Piedra Imán"));
+    result
+});
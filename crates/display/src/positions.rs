@@ -26,8 +26,8 @@ use protos::spelldawn::{
     ObjectPositionBrowser, ObjectPositionDeck, ObjectPositionDeckContainer,
     ObjectPositionDiscardPile, ObjectPositionDiscardPileContainer, ObjectPositionHand,
     ObjectPositionIdentity, ObjectPositionIdentityContainer, ObjectPositionIntoCard,
-    ObjectPositionItem, ObjectPositionRaid, ObjectPositionRevealedCards, ObjectPositionRoom,
-    ObjectPositionStaging, RevealedCardsBrowserSize, RoomIdentifier,
+    ObjectPositionItem, ObjectPositionOffscreen, ObjectPositionRaid, ObjectPositionRevealedCards,
+    ObjectPositionRoom, ObjectPositionStaging, RevealedCardsBrowserSize, RoomIdentifier,
 };
 use raids::traits::RaidDisplayState;
 use raids::RaidDataExt;
@@ -39,7 +39,7 @@ pub const RELEASE_SORTING_KEY: u32 = 100;
 pub fn for_card(card: &CardState, position: Position) -> ObjectPosition {
     ObjectPosition {
         position: Some(position),
-        sorting_key: 1 + card.sorting_key,
+        sorting_key: 1 + card.sorting_key.value(),
         sorting_subkey: 0,
     }
 }
@@ -47,7 +47,7 @@ pub fn for_card(card: &CardState, position: Position) -> ObjectPosition {
 pub fn for_ability(game: &GameState, ability_id: AbilityId, position: Position) -> ObjectPosition {
     ObjectPosition {
         position: Some(position),
-        sorting_key: 1 + game.card(ability_id.card_id).sorting_key,
+        sorting_key: 1 + game.card(ability_id.card_id).sorting_key.value(),
         sorting_subkey: 1 + (ability_id.index.value() as u32),
     }
 }
@@ -120,6 +120,10 @@ pub fn identity_container(builder: &ResponseBuilder, side: Side) -> Position {
     })
 }
 
+pub fn offscreen() -> Position {
+    Position::Offscreen(ObjectPositionOffscreen {})
+}
+
 pub fn staging() -> Position {
     Position::Staging(ObjectPositionStaging {})
 }
@@ -154,7 +158,7 @@ pub fn convert(
         position_override
     } else {
         ObjectPosition {
-            sorting_key: card.sorting_key,
+            sorting_key: card.sorting_key.value(),
             position: Some(adapt_position(builder, game, card.id, card.position())?),
             ..ObjectPosition::default()
         }
@@ -171,13 +175,14 @@ fn adapt_position(
         CardPosition::Room(room_id, location) => room(room_id, location),
         CardPosition::ArenaItem(location) => item(location),
         CardPosition::Hand(side) => hand(builder, side),
-        CardPosition::DeckTop(side) => deck(builder, side),
+        CardPosition::DeckTop(side) | CardPosition::DeckBottom(side) => deck(builder, side),
         CardPosition::DiscardPile(side) => discard(builder, side),
         CardPosition::Scored(side) | CardPosition::Identity(side) => identity(builder, side),
         CardPosition::Scoring => staging(),
         CardPosition::Played(side, target) => {
             card_release_position(builder, game, side, card_id, target)?
         }
+        CardPosition::Banished(_) => offscreen(),
         CardPosition::DeckUnknown(_) => fail!("Invalid card position"),
     })
 }
@@ -294,7 +299,13 @@ fn opening_hand_position_override(
     if data.decision(builder.user_side).is_none()
         && game.hand(builder.user_side).any(|c| c.id == card.id)
     {
-        Some(for_card(card, revealed_cards(true)))
+        // Cards marked to be replaced via a partial mulligan are lifted out of
+        // the opening hand browser.
+        if data.selected_cards.contains(&card.id) {
+            Some(for_card(card, staging()))
+        } else {
+            Some(for_card(card, revealed_cards(true)))
+        }
     } else {
         None
     }
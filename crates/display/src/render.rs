@@ -12,32 +12,99 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use adapters::response_builder::{ResponseBuilder, ResponseState};
+use adapters::response_builder::{ResponseBuilder, ResponseOptions, ResponseState};
 use anyhow::Result;
 use data::game::GameState;
 use data::primitives::Side;
+use data::updates::Updates;
 use protos::spelldawn::game_command::Command;
 
-use crate::{animations, sync};
+use crate::{animations, diff, sync};
 
 pub fn connect(game: &GameState, user_side: Side) -> Result<Vec<Command>> {
-    let mut builder =
-        ResponseBuilder::new(user_side, ResponseState { animate: false, is_final_update: true });
+    connect_with_options(game, user_side, ResponseOptions::default())
+}
+
+/// Equivalent to [connect] with custom [ResponseOptions] for the full sync.
+pub fn connect_with_options(
+    game: &GameState,
+    user_side: Side,
+    options: ResponseOptions,
+) -> Result<Vec<Command>> {
+    let mut builder = ResponseBuilder::new(
+        user_side,
+        ResponseState { animate: false, is_final_update: true, options },
+    );
+    sync::run(&mut builder, game)?;
+    Ok(builder.commands)
+}
+
+/// Renders a full sync of `game` for a spectator which does not include
+/// either player's hidden information.
+pub fn spectate(game: &GameState, options: ResponseOptions) -> Result<Vec<Command>> {
+    let mut builder = ResponseBuilder::spectator(ResponseState {
+        animate: false,
+        is_final_update: true,
+        options,
+    });
     sync::run(&mut builder, game)?;
     Ok(builder.commands)
 }
 
+/// Renders the updates recorded in the game's [data::updates::UpdateTracker]
+/// followed by a full sync of the current game state.
+///
+/// Games which are not tracking updates, such as simulation games, only
+/// receive the final sync.
 pub fn render_updates(game: &GameState, user_side: Side) -> Result<Vec<Command>> {
-    let mut builder =
-        ResponseBuilder::new(user_side, ResponseState { animate: true, is_final_update: false });
+    render_updates_with_options(None, game, user_side, ResponseOptions::default())
+}
+
+/// Equivalent to [render_updates] with custom [ResponseOptions].
+///
+/// If [ResponseOptions::scalar_deltas] or [ResponseOptions::card_deltas] is set
+/// and a `previous` state for this game is provided, an update which records
+/// no animation steps is rendered via [diff::execute] instead of as a full
+/// sync where possible.
+pub fn render_updates_with_options(
+    previous: Option<&GameState>,
+    game: &GameState,
+    user_side: Side,
+    options: ResponseOptions,
+) -> Result<Vec<Command>> {
+    let mut builder = ResponseBuilder::new(
+        user_side,
+        ResponseState { animate: true, is_final_update: false, options },
+    );
 
-    for step in &game.updates.steps {
+    let steps =
+        if game.updates.state == Updates::Push { game.updates.steps.as_slice() } else { &[] };
+    for step in steps {
         sync::run(&mut builder, &step.snapshot)?;
         animations::render(&mut builder, &step.update, &step.snapshot)?;
     }
 
     builder.state.is_final_update = true;
-    sync::run(&mut builder, game)?;
+    let view = sync::game_view(&builder, game)?;
+    let delta = match previous {
+        Some(previous) if (options.scalar_deltas || options.card_deltas) && steps.is_empty() => {
+            diff::execute(
+                &sync::game_view(&builder, previous)?,
+                &view,
+                options,
+                builder.state.animate,
+            )
+        }
+        _ => None,
+    };
+
+    if let Some(delta) = delta {
+        for command in delta {
+            builder.push(command);
+        }
+    } else {
+        builder.push_game_view(view);
+    }
 
     Ok(builder.commands)
 }
@@ -19,19 +19,28 @@ use std::fmt::Write as _;
 use core_ui::design::FontColor;
 use core_ui::{design, icons, rendering};
 use data::card_definition::{Ability, AbilityType, CardDefinition, Cost};
-use data::card_state::CardState;
+use data::card_state::{CardPosition, CardState};
 use data::delegates::Scope;
 use data::game::GameState;
-use data::primitives::{AbilityId, AbilityIndex, CardSubtype, CardType, Lineage};
+use data::primitives::{AbilityId, AbilityIndex, CardSubtype, CardType, Lineage, Locale};
 use data::text::{
     AbilityText, DamageWord, Keyword, KeywordKind, NumericOperator, Sentence, TextToken,
 };
 use prompts::card_info::SupplementalCardInfo;
 use protos::spelldawn::{Node, RulesText};
+use rules::flags;
+use rules::flags::PlayObstacle;
+
+use crate::localization;
 
 /// Primary function which turns the current state of a card into its client
-/// [RulesText] representation
-pub fn build(game: &GameState, card: &CardState, definition: &CardDefinition) -> RulesText {
+/// [RulesText] representation in the given [Locale]
+pub fn build(
+    game: &GameState,
+    card: &CardState,
+    definition: &CardDefinition,
+    locale: Locale,
+) -> RulesText {
     let mut lines = vec![];
     for (index, ability) in definition.abilities.iter().enumerate() {
         let mut line = String::new();
@@ -39,7 +48,10 @@ pub fn build(game: &GameState, card: &CardState, definition: &CardDefinition) ->
             line.push_str(&ability_cost_string(cost));
         }
 
-        line.push_str(&ability_text(game, AbilityId::new(card.id, index), ability));
+        let tokens = ability_tokens(game, AbilityId::new(card.id, index), ability);
+        let tokens =
+            localization::STRINGS.rules_text(definition.name, locale, &tokens).unwrap_or(tokens);
+        line.push_str(&process_text_tokens(&tokens));
 
         lines.push(line);
     }
@@ -54,12 +66,13 @@ pub fn build(game: &GameState, card: &CardState, definition: &CardDefinition) ->
 /// Builds the rules text for a single [Ability], not including its cost (if
 /// any).
 pub fn ability_text(game: &GameState, ability_id: AbilityId, ability: &Ability) -> String {
+    process_text_tokens(&ability_tokens(game, ability_id, ability))
+}
+
+fn ability_tokens(game: &GameState, ability_id: AbilityId, ability: &Ability) -> Vec<TextToken> {
     match &ability.text {
-        AbilityText::Text(text) => process_text_tokens(text),
-        AbilityText::TextFn(function) => {
-            let tokens = function(game, Scope::new(ability_id));
-            process_text_tokens(&tokens)
-        }
+        AbilityText::Text(text) => text.clone(),
+        AbilityText::TextFn(function) => function(game, Scope::new(ability_id)),
     }
 }
 
@@ -95,13 +108,83 @@ pub fn build_supplemental_info(
     }
 
     process_keywords(&mut keywords, &mut result);
+
+    let text = match ability_index {
+        Some(index) => {
+            ability_text(game, AbilityId::new(card.id, index.value()), definition.ability(index))
+        }
+        None => build(game, card, definition, Locale::English).text,
+    };
+    result.extend(keyword_definitions(&text).into_iter().map(String::from));
+
+    if ability_index.is_none() && card.position() == CardPosition::Hand(card.side()) {
+        if let Some(obstacle) = flags::play_obstacle(game, card.side(), card.id) {
+            result.push(play_obstacle_text(obstacle));
+        }
+    }
+
     rendering::component(SupplementalCardInfo::new(result))
 }
 
+/// Game terms which are explained in a card's supplemental info when they
+/// appear in its rules text, along with their definitions.
+pub const KEYWORDS: &[(&str, &str)] = &[
+    (
+        "raid",
+        "<b>Raid:</b> The Champion attacks a room, encountering its defenders and accessing cards.",
+    ),
+    ("access", "<b>Access:</b> Look at a card during a raid, then score or destroy it if able."),
+    (
+        "shield",
+        "<b>Shield:</b> Extra mana the Champion must pay to use a weapon against this minion.",
+    ),
+    ("face up", "<b>Face Up:</b> A card in play which is visible to both players."),
+    ("face down", "<b>Face Down:</b> A card in play which is hidden from the opponent."),
+];
+
+/// Returns the definitions from [KEYWORDS] for each keyword which appears in
+/// `text`. Matching ignores case and hyphenation, and each keyword is only
+/// returned once.
+pub fn keyword_definitions(text: &str) -> Vec<&'static str> {
+    let text = text.to_lowercase().replace('-', " ");
+    KEYWORDS
+        .iter()
+        .filter(|(keyword, _)| contains_word(&text, keyword))
+        .map(|(_, definition)| *definition)
+        .collect()
+}
+
+/// Returns true if `word` appears in `text` and is not part of a longer word.
+fn contains_word(text: &str, word: &str) -> bool {
+    text.match_indices(word).any(|(i, _)| {
+        let before = text[..i].chars().next_back();
+        let after = text[i + word.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// Explains why a card in hand cannot currently be played
+fn play_obstacle_text(obstacle: PlayObstacle) -> String {
+    match obstacle {
+        PlayObstacle::NotYourTurn => "<b>Cannot play:</b> It is not your turn.".to_string(),
+        PlayObstacle::NotEnoughMana { short_by } => {
+            format!("<b>Cannot play:</b> You need {}{} more.", short_by, icons::MANA)
+        }
+        PlayObstacle::NoActionPoints => {
+            format!("<b>Cannot play:</b> You do not have enough {}.", icons::ACTION)
+        }
+        PlayObstacle::NoLegalTarget => "<b>Cannot play:</b> There is no legal target.".to_string(),
+        PlayObstacle::WrongPhase => "<b>Cannot play:</b> Cards cannot be played now.".to_string(),
+        PlayObstacle::PlayConditionUnmet => {
+            "<b>Cannot play:</b> This card's play condition is not met.".to_string()
+        }
+    }
+}
+
 fn ability_cost_string(cost: &Cost<AbilityId>) -> String {
     let mut actions = icons::ACTION.repeat(cost.actions as usize);
 
-    if let Some(mana) = cost.mana {
+    if let Some(mana) = cost.mana.value() {
         if mana > 0 {
             let _err = write!(actions, ",{}{}", mana, icons::MANA);
         }
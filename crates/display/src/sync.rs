@@ -21,16 +21,29 @@ use data::game::GameState;
 use data::primitives::{RoomId, Side};
 use protos::spelldawn::{
     ActionTrackerView, CardView, GameView, ManaView, PlayerInfo, PlayerView, ScoreView,
+    TurnTimerView,
 };
 use rules::mana::ManaPurpose;
-use rules::{flags, mana};
+use rules::{flags, mana, queries};
 
 use crate::{assets, card_sync, interface, positions};
 
 pub fn run(builder: &mut ResponseBuilder, game: &GameState) -> Result<()> {
+    let view = game_view(builder, game)?;
+    builder.push_game_view(view);
+    Ok(())
+}
+
+/// Builds the [GameView] for `game` without adding it to the response.
+pub fn game_view(builder: &ResponseBuilder, game: &GameState) -> Result<GameView> {
     let cards: Result<Vec<CardView>> = game
         .all_cards()
         .filter(|c| !c.position().shuffled_into_deck())
+        .filter(|c| {
+            !(builder.state.options.omit_hidden_deck_cards
+                && c.position().in_deck()
+                && !builder.is_revealed(c))
+        })
         .flat_map(|c| {
             let mut cards = card_sync::activated_ability_cards(builder, game, c);
             cards.push(card_sync::card_view(builder, game, c));
@@ -38,32 +51,41 @@ pub fn run(builder: &mut ResponseBuilder, game: &GameState) -> Result<()> {
         })
         .collect();
 
-    builder.push_game_view(GameView {
+    Ok(GameView {
         user: Some(player_view(game, builder.user_side)?),
         opponent: Some(player_view(game, builder.user_side.opponent())?),
         cards: cards?,
         raid_active: game.data.raid.is_some(),
         game_object_positions: Some(positions::game_object_positions(builder, game)?),
-        main_controls: if builder.state.is_final_update {
+        main_controls: if builder.state.is_final_update && !builder.is_spectator() {
             // Only include controls on final update to ensure interface doesn't show
             // previous UI after click.
             interface::render(game, builder.user_side)?
         } else {
             None
         },
-    });
-
-    Ok(())
+        turn_banner: if builder.is_spectator() {
+            None
+        } else {
+            interface::turn_banner(game, builder.user_side)
+        },
+    })
 }
 
 fn player_view(game: &GameState, side: Side) -> Result<PlayerView> {
     let identity = game.card(game.first_identity(side)?);
+    let cosmetics = &game.player(side).cosmetics;
     Ok(PlayerView {
         side: adapters::player_side(side),
         player_info: Some(PlayerInfo {
             name: Some(identity.name.displayed_name()),
             portrait: Some(adapters::sprite(&rules::get(identity.name).image)),
-            portrait_frame: Some(assets::identity_card_frame(side)),
+            portrait_frame: Some(
+                cosmetics
+                    .portrait_frame
+                    .as_ref()
+                    .map_or_else(|| assets::identity_card_frame(side), adapters::sprite),
+            ),
             valid_rooms_to_visit: match side {
                 Side::Overlord => enum_iterator::all::<RoomId>()
                     .filter(|room_id| flags::can_take_level_up_room_action(game, side, *room_id))
@@ -74,7 +96,10 @@ fn player_view(game: &GameState, side: Side) -> Result<PlayerView> {
                     .map(adapters::room_identifier)
                     .collect(),
             },
-            card_back: Some(assets::card_back(rules::get(identity.name).school)),
+            card_back: Some(cosmetics.card_back.as_ref().map_or_else(
+                || assets::card_back(rules::get(identity.name).school),
+                adapters::sprite,
+            )),
         }),
         score: Some(ScoreView { score: game.player(side).score }),
         mana: Some(ManaView {
@@ -85,5 +110,10 @@ fn player_view(game: &GameState, side: Side) -> Result<PlayerView> {
             available_action_count: game.player(side).actions,
         }),
         can_take_action: actions::can_take_action(game, side),
+        turn_timer: game.data.turn_timer.as_ref().map(|timer| TurnTimerView {
+            remaining_milliseconds: timer.remaining(side).as_millis() as u64,
+            running: actions::turn_timer_running(game, side),
+        }),
+        available_undo_count: queries::available_undo_count(game, side),
     })
 }
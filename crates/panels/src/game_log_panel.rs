@@ -0,0 +1,51 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::list_cell::ListCell;
+use core_ui::panel::Panel;
+use core_ui::prelude::*;
+use data::game::GameState;
+use protos::spelldawn::FlexOverflow;
+
+use crate::PanelAddress;
+
+/// Displays the [GameData::log] of recent game events, most recent first.
+///
+/// [GameData::log]: data::game::GameData::log
+#[derive(Debug)]
+pub struct GameLogPanel {
+    entries: Vec<String>,
+}
+
+impl GameLogPanel {
+    pub fn new(game: Option<&GameState>) -> Self {
+        Self { entries: game.map_or_else(Vec::new, |g| g.data.log.iter().rev().cloned().collect()) }
+    }
+}
+
+impl Component for GameLogPanel {
+    fn build(self) -> RenderResult {
+        Panel::new(PanelAddress::GameLog, 1024.px(), 600.px())
+            .title("Game Log")
+            .show_close_button(true)
+            .content(
+                Column::new("Log Entries")
+                    .style(
+                        Style::new().margin(Edge::Vertical, 16.px()).overflow(FlexOverflow::Hidden),
+                    )
+                    .children(self.entries.into_iter().map(ListCell::new)),
+            )
+            .build()
+    }
+}
@@ -16,35 +16,67 @@
 //! be opened or closed by the user, such as a game menu or window.
 
 pub mod debug_panel;
+pub mod game_log_panel;
 pub mod panel_address;
 pub mod set_player_name_panel;
+pub mod waiting_for_opponent_panel;
 
 use anyhow::Result;
 use core_ui::{panel, rendering};
+use data::game::GameState;
 use debug_panel::DebugPanel;
+use game_log_panel::GameLogPanel;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::interface_panel_address::AddressType;
 use protos::spelldawn::{
-    ClientPanelAddress, InterfacePanel, InterfacePanelAddress, Node, UpdatePanelsCommand,
+    ClientPanelAddress, InterfacePanel, InterfacePanelAddress, Node, TogglePanelCommand,
+    UpdatePanelsCommand,
 };
 use serde_json::de;
 use with_error::WithError;
 
 use crate::panel_address::PanelAddress;
 use crate::set_player_name_panel::SetPlayerNamePanel;
+use crate::waiting_for_opponent_panel::WaitingForOpponentPanel;
 
 /// Appends a command to `commands` to render commonly-used panels on connect.
 pub fn append_standard_panels(commands: &mut Vec<Command>) -> Result<()> {
-    commands
-        .push(Command::UpdatePanels(render_panel(panel::client(ClientPanelAddress::DebugPanel))?));
+    commands.push(Command::UpdatePanels(render_panel(
+        panel::client(ClientPanelAddress::DebugPanel),
+        None,
+    )?));
     Ok(())
 }
 
-pub fn render_panel(address: InterfacePanelAddress) -> Result<UpdatePanelsCommand> {
+/// Returns commands to render and open the [WaitingForOpponentPanel], shown
+/// while a player's invitation to start a new game has not been accepted.
+pub fn open_waiting_for_opponent_panel() -> Result<Vec<Command>> {
+    let address: InterfacePanelAddress = PanelAddress::WaitingForOpponent.into();
+    Ok(vec![
+        Command::UpdatePanels(render_panel(address.clone(), None)?),
+        Command::TogglePanel(TogglePanelCommand { panel_address: Some(address), open: true }),
+    ])
+}
+
+/// Returns a command to close the [WaitingForOpponentPanel], e.g. after the
+/// player withdraws their invitation.
+pub fn close_waiting_for_opponent_panel() -> Command {
+    Command::TogglePanel(TogglePanelCommand {
+        panel_address: Some(PanelAddress::WaitingForOpponent.into()),
+        open: false,
+    })
+}
+
+/// Renders the panel identified by `address`. Panels which display game state
+/// read it from `game`, if one is provided.
+pub fn render_panel(
+    address: InterfacePanelAddress,
+    game: Option<&GameState>,
+) -> Result<UpdatePanelsCommand> {
     let node = match address.address_type.as_ref().with_error(|| "missing address_type")? {
         AddressType::Serialized(payload) => {
             let address = de::from_slice(payload).with_error(|| "deserialization failed")?;
-            render_server_panel(address)
+            render_server_panel(address, game)
         }
         AddressType::ClientPanel(client_panel) => render_client_panel(
             ClientPanelAddress::from_i32(*client_panel).with_error(|| "invalid known panel")?,
@@ -54,9 +86,11 @@ pub fn render_panel(address: InterfacePanelAddress) -> Result<UpdatePanelsComman
     Ok(UpdatePanelsCommand { panels: vec![InterfacePanel { address: Some(address), node }] })
 }
 
-fn render_server_panel(address: PanelAddress) -> Option<Node> {
+fn render_server_panel(address: PanelAddress, game: Option<&GameState>) -> Option<Node> {
     match address {
         PanelAddress::SetPlayerName(side) => rendering::component(SetPlayerNamePanel::new(side)),
+        PanelAddress::GameLog => rendering::component(GameLogPanel::new(game)),
+        PanelAddress::WaitingForOpponent => rendering::component(WaitingForOpponentPanel {}),
     }
 }
 
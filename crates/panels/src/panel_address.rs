@@ -20,6 +20,8 @@ use serde_json::ser;
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum PanelAddress {
     SetPlayerName(Side),
+    GameLog,
+    WaitingForOpponent,
 }
 
 impl From<PanelAddress> for InterfacePanelAddress {
@@ -0,0 +1,49 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
+use core_ui::panel::Panel;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use data::game_actions::UserAction;
+use protos::spelldawn::FlexAlign;
+
+use crate::PanelAddress;
+
+/// Displayed while a player's invitation to start a new game is waiting for
+/// their opponent to accept it.
+#[derive(Debug)]
+pub struct WaitingForOpponentPanel {}
+
+impl Component for WaitingForOpponentPanel {
+    fn build(self) -> RenderResult {
+        Panel::new(PanelAddress::WaitingForOpponent, 512.px(), 300.px())
+            .title("New Game")
+            .content(
+                Column::new("WaitingForOpponent")
+                    .style(Style::new().align_items(FlexAlign::Center))
+                    .child(
+                        Text::new("Waiting for opponent...", FontSize::Headline)
+                            .layout(Layout::new().margin(Edge::Bottom, 32.px())),
+                    )
+                    .child(
+                        Button::new("Cancel")
+                            .button_type(ButtonType::Secondary)
+                            .action(UserAction::CancelNewGame),
+                    ),
+            )
+            .build()
+    }
+}
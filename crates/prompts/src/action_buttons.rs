@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use core_ui::icons;
-use data::game::{GameState, MulliganDecision};
-use data::game_actions::{AccessPhaseAction, CardPromptAction, EncounterAction, PromptAction};
-use data::primitives::Side;
+use data::game::{GamePhase, GameState, ManaSource, MulliganDecision};
+use data::game_actions::{
+    AccessPhaseAction, ActivationPhaseAction, CardPromptAction, CardTarget, DamageAllocation,
+    EncounterAction, GamePrompt, PromptAction, PromptContext,
+};
+use data::primitives::{CardId, RoomId, Side};
 use rules::queries;
 
 use crate::response_button::ResponseButton;
@@ -23,9 +26,23 @@ use crate::response_button::ResponseButton;
 pub fn for_prompt(game: &GameState, side: Side, action: PromptAction) -> ResponseButton {
     match action {
         PromptAction::MulliganDecision(data) => mulligan_button(data),
+        PromptAction::ToggleMulliganCard(card_id) => toggle_mulligan_button(game, card_id),
+        PromptAction::ActivationPhaseAction(data) => activation_button(data),
         PromptAction::EncounterAction(data) => encounter_action_button(game, side, data),
         PromptAction::AccessPhaseAction(data) => access_button(data),
         PromptAction::CardAction(data) => card_response_button(side, data),
+        PromptAction::SelectCardTarget(_, target) => card_target_button(game, target),
+        PromptAction::ChooseNumber(_, x) => {
+            ResponseButton::new(format!("Pay {}{}", x, icons::MANA))
+        }
+        PromptAction::DamageDistribution(allocation) => {
+            damage_distribution_button(game, allocation)
+        }
+        PromptAction::RoomChosen(room_id) => ResponseButton::new(room_name(room_id)),
+        PromptAction::ToggleDiscardCard(card_id) => toggle_discard_button(game, side, card_id),
+        PromptAction::ConfirmDiscard => ResponseButton::new("Discard"),
+        PromptAction::ManaSourceChosen(source) => mana_source_button(source),
+        PromptAction::AbortRaid => ResponseButton::new("Retreat").primary(false),
     }
     .action(action)
 }
@@ -34,6 +51,25 @@ fn mulligan_button(mulligan: MulliganDecision) -> ResponseButton {
     match mulligan {
         MulliganDecision::Keep => ResponseButton::new("Keep"),
         MulliganDecision::Mulligan => ResponseButton::new("Mulligan").primary(false),
+        MulliganDecision::Partial(selection) => {
+            ResponseButton::new(format!("Replace {}", selection.cards().count()))
+        }
+    }
+}
+
+fn toggle_mulligan_button(game: &GameState, card_id: CardId) -> ResponseButton {
+    let selected = matches!(
+        &game.data.phase,
+        GamePhase::ResolveMulligans(mulligans) if mulligans.selected_cards.contains(&card_id)
+    );
+    ResponseButton::new(if selected { "Deselect" } else { "Select" })
+        .primary(false)
+        .anchor_to(card_id)
+}
+
+fn activation_button(activation: ActivationPhaseAction) -> ResponseButton {
+    match activation {
+        ActivationPhaseAction::ContinueRaid => ResponseButton::new("Continue Raid"),
     }
 }
 
@@ -92,6 +128,59 @@ fn card_response_button(user_side: Side, action: CardPromptAction) -> ResponseBu
     ResponseButton::new(label)
 }
 
+fn card_target_button(game: &GameState, target: CardTarget) -> ResponseButton {
+    ResponseButton::new(match target {
+        CardTarget::None => "Play".to_string(),
+        CardTarget::Room(room_id) => room_name(room_id).to_string(),
+        CardTarget::Card(card_id) => game.card(card_id).name.displayed_name(),
+    })
+}
+
+fn damage_distribution_button(game: &GameState, allocation: DamageAllocation) -> ResponseButton {
+    ResponseButton::new(
+        allocation
+            .iter()
+            .map(|(card_id, amount)| {
+                format!("{} {}", amount, game.card(card_id).name.displayed_name())
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+fn toggle_discard_button(game: &GameState, side: Side, card_id: CardId) -> ResponseButton {
+    let selected = matches!(
+        &game.player(side).prompt,
+        Some(GamePrompt {
+            context: Some(PromptContext::DiscardToHandSize { selected, .. }),
+            ..
+        }) if selected.contains(&card_id)
+    );
+    ResponseButton::new(if selected { "Deselect" } else { "Select" })
+        .primary(false)
+        .anchor_to(card_id)
+}
+
+fn mana_source_button(source: ManaSource) -> ResponseButton {
+    match source {
+        ManaSource::RaidSpecific => ResponseButton::new("Raid Mana"),
+        ManaSource::Base => ResponseButton::new("Base Mana").primary(false),
+    }
+}
+
+fn room_name(room_id: RoomId) -> &'static str {
+    match room_id {
+        RoomId::Vault => "Vault",
+        RoomId::Sanctum => "Sanctum",
+        RoomId::Crypts => "Crypts",
+        RoomId::RoomA => "Room A",
+        RoomId::RoomB => "Room B",
+        RoomId::RoomC => "Room C",
+        RoomId::RoomD => "Room D",
+        RoomId::RoomE => "Room E",
+    }
+}
+
 fn lose_text(user_side: Side, target_side: Side) -> &'static str {
     if user_side == target_side {
         "Pay"
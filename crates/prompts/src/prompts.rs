@@ -14,16 +14,18 @@
 
 use anyhow::Result;
 use core_ui::design::FontSize;
+use core_ui::icons;
 use core_ui::prelude::Component;
 use core_ui::rendering;
 use core_ui::text::Text;
 use data::game::GameState;
-use data::game_actions::{GamePrompt, PromptContext};
+use data::game_actions::{GamePrompt, PromptContext, UserAction};
 use data::primitives::Side;
 use protos::spelldawn::InterfaceMainControls;
 
 use crate::action_buttons;
 use crate::prompt_container::PromptContainer;
+use crate::response_button::ResponseButton;
 
 /// Builds UI elements to display a [GamePrompt] for the `side` player.
 pub fn action_prompt(
@@ -34,7 +36,7 @@ pub fn action_prompt(
     let mut main_controls: Vec<Box<dyn Component>> = vec![];
     let mut card_anchor_nodes = vec![];
 
-    if let Some(label) = prompt_context(prompt.context) {
+    if let Some(label) = prompt_context(prompt.context.clone()) {
         main_controls.push(Box::new(Text::new(label, FontSize::PromptContext)));
     }
 
@@ -47,6 +49,12 @@ pub fn action_prompt(
         }
     }
 
+    if prompt.cancellable {
+        main_controls.push(Box::new(
+            ResponseButton::new("Cancel").primary(false).action(UserAction::CancelPrompt),
+        ));
+    }
+
     Ok(Some(InterfaceMainControls {
         node: rendering::component(PromptContainer::new().children(main_controls)),
         card_anchor_nodes,
@@ -56,5 +64,10 @@ pub fn action_prompt(
 fn prompt_context(context: Option<PromptContext>) -> Option<String> {
     context.map(|context| match context {
         PromptContext::RaidAdvance => "Continue?".to_string(),
+        PromptContext::PlayCard(_) => "Choose a target".to_string(),
+        PromptContext::DistributeDamage { total, .. } => format!("Divide {} damage", total),
+        PromptContext::ChooseRoom { .. } => "Choose a room".to_string(),
+        PromptContext::DiscardToHandSize { count, .. } => format!("Discard {}", count),
+        PromptContext::ChooseManaSource { cost, .. } => format!("Pay {}{}", cost, icons::MANA),
     })
 }
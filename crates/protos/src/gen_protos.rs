@@ -25,6 +25,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Building rust protocol buffers");
     tonic_build::configure()
         .build_client(false)
+        .type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize)]")
         .type_attribute(
             "spelldawn.GameObjectIdentifier",
             "#[derive(Eq, Hash, Copy, Ord, PartialOrd)]",
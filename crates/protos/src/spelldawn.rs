@@ -1,4 +1,4 @@
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct FlexColor {
     /// Red color component, specified in the range 0.0 to 1.0 inclusive.
     #[prost(float, tag = "1")]
@@ -15,24 +15,24 @@ pub struct FlexColor {
     #[prost(float, tag = "4")]
     pub alpha: f32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct SpriteAddress {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct RenderTextureAddress {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct NodeBackground {
     #[prost(oneof = "node_background::BackgroundAddress", tags = "1, 2")]
     pub background_address: ::core::option::Option<node_background::BackgroundAddress>,
 }
 /// Nested message and enum types in `NodeBackground`.
 pub mod node_background {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum BackgroundAddress {
         #[prost(message, tag = "1")]
         Sprite(super::SpriteAddress),
@@ -40,34 +40,34 @@ pub mod node_background {
         RenderTexture(super::RenderTextureAddress),
     }
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct FontAddress {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ProjectileAddress {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct EffectAddress {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct AudioClipAddress {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct FlexVector2 {
     #[prost(float, tag = "1")]
     pub x: f32,
     #[prost(float, tag = "2")]
     pub y: f32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct FlexVector3 {
     #[prost(float, tag = "1")]
     pub x: f32,
@@ -76,14 +76,14 @@ pub struct FlexVector3 {
     #[prost(float, tag = "3")]
     pub z: f32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct Dimension {
     #[prost(enumeration = "DimensionUnit", tag = "1")]
     pub unit: i32,
     #[prost(float, tag = "2")]
     pub value: f32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct DimensionGroup {
     #[prost(message, optional, tag = "1")]
     pub top: ::core::option::Option<Dimension>,
@@ -94,7 +94,7 @@ pub struct DimensionGroup {
     #[prost(message, optional, tag = "4")]
     pub left: ::core::option::Option<Dimension>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct BorderWidth {
     #[prost(float, tag = "1")]
     pub top: f32,
@@ -105,7 +105,7 @@ pub struct BorderWidth {
     #[prost(float, tag = "4")]
     pub left: f32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct BorderColor {
     #[prost(message, optional, tag = "1")]
     pub top: ::core::option::Option<FlexColor>,
@@ -116,7 +116,7 @@ pub struct BorderColor {
     #[prost(message, optional, tag = "4")]
     pub left: ::core::option::Option<FlexColor>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct BorderRadius {
     #[prost(message, optional, tag = "1")]
     pub top_left: ::core::option::Option<Dimension>,
@@ -127,12 +127,12 @@ pub struct BorderRadius {
     #[prost(message, optional, tag = "4")]
     pub bottom_left: ::core::option::Option<Dimension>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct FlexRotate {
     #[prost(float, tag = "1")]
     pub degrees: f32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct FlexTranslate {
     #[prost(message, optional, tag = "1")]
     pub x: ::core::option::Option<Dimension>,
@@ -141,12 +141,12 @@ pub struct FlexTranslate {
     #[prost(float, tag = "3")]
     pub z: f32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct FlexScale {
     #[prost(message, optional, tag = "1")]
     pub amount: ::core::option::Option<FlexVector3>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct TextShadow {
     #[prost(message, optional, tag = "1")]
     pub offset: ::core::option::Option<FlexVector2>,
@@ -155,12 +155,12 @@ pub struct TextShadow {
     #[prost(message, optional, tag = "3")]
     pub color: ::core::option::Option<FlexColor>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct TimeValue {
     #[prost(uint32, tag = "1")]
     pub milliseconds: u32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ImageSlice {
     #[prost(uint32, tag = "1")]
     pub top: u32,
@@ -171,7 +171,7 @@ pub struct ImageSlice {
     #[prost(uint32, tag = "4")]
     pub left: u32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct FlexStyle {
     #[prost(enumeration = "FlexAlign", tag = "1")]
     pub align_content: i32,
@@ -284,32 +284,32 @@ pub struct FlexStyle {
     #[prost(enumeration = "FlexPickingMode", tag = "55")]
     pub picking_mode: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct Flexbox {}
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct Text {
     #[prost(string, tag = "1")]
     pub label: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct EventHandlers {
     #[prost(message, optional, tag = "1")]
     pub on_click: ::core::option::Option<GameAction>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct NodeType {
     #[prost(oneof = "node_type::NodeType", tags = "1")]
     pub node_type: ::core::option::Option<node_type::NodeType>,
 }
 /// Nested message and enum types in `NodeType`.
 pub mod node_type {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum NodeType {
         #[prost(message, tag = "1")]
         Text(super::Text),
     }
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct Node {
     ///
     /// Used to identify this node in the hierarchy, should be unique among
@@ -337,14 +337,14 @@ pub struct Node {
 // Game Primitives
 // ============================================================================
 
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct PlayerIdentifier {
     #[prost(oneof = "player_identifier::PlayerIdentifierType", tags = "1, 2, 3")]
     pub player_identifier_type: ::core::option::Option<player_identifier::PlayerIdentifierType>,
 }
 /// Nested message and enum types in `PlayerIdentifier`.
 pub mod player_identifier {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum PlayerIdentifierType {
         /// An identifier from Unity's social API: Social.localUser.id
         #[prost(string, tag = "1")]
@@ -357,17 +357,17 @@ pub mod player_identifier {
         ServerIdentifier(::prost::alloc::vec::Vec<u8>),
     }
 }
-#[derive(Eq, Hash, Copy, Ord, PartialOrd, Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Eq, Hash, Copy, Ord, PartialOrd, Clone, PartialEq, ::prost::Message)]
 pub struct DeckIdentifier {
     #[prost(uint64, tag = "1")]
     pub value: u64,
 }
-#[derive(Eq, Hash, Copy, Ord, PartialOrd, Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Eq, Hash, Copy, Ord, PartialOrd, Clone, PartialEq, ::prost::Message)]
 pub struct GameIdentifier {
     #[prost(uint64, tag = "1")]
     pub value: u64,
 }
-#[derive(Eq, Hash, Copy, Ord, PartialOrd, Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Eq, Hash, Copy, Ord, PartialOrd, Clone, PartialEq, ::prost::Message)]
 pub struct CardIdentifier {
     #[prost(enumeration = "PlayerSide", tag = "1")]
     pub side: i32,
@@ -378,14 +378,14 @@ pub struct CardIdentifier {
     #[prost(message, optional, tag = "3")]
     pub ability_id: ::core::option::Option<u32>,
 }
-#[derive(Eq, Hash, Copy, Ord, PartialOrd, Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Eq, Hash, Copy, Ord, PartialOrd, Clone, PartialEq, ::prost::Message)]
 pub struct GameObjectIdentifier {
     #[prost(oneof = "game_object_identifier::Id", tags = "1, 2, 3, 4")]
     pub id: ::core::option::Option<game_object_identifier::Id>,
 }
 /// Nested message and enum types in `GameObjectIdentifier`.
 pub mod game_object_identifier {
-    #[derive(Eq, Hash, Copy, Ord, PartialOrd, Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Eq, Hash, Copy, Ord, PartialOrd, Clone, PartialEq, ::prost::Oneof)]
     pub enum Id {
         #[prost(message, tag = "1")]
         CardId(super::CardIdentifier),
@@ -401,7 +401,7 @@ pub mod game_object_identifier {
 // Game View
 // ============================================================================
 
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct CardIcon {
     /// Background for the icon.
     #[prost(message, optional, tag = "1")]
@@ -413,7 +413,7 @@ pub struct CardIcon {
     #[prost(message, optional, tag = "3")]
     pub background_scale: ::core::option::Option<f32>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct CardIcons {
     #[prost(message, optional, tag = "1")]
     pub top_left_icon: ::core::option::Option<CardIcon>,
@@ -426,34 +426,34 @@ pub struct CardIcons {
     #[prost(message, optional, tag = "5")]
     pub arena_icon: ::core::option::Option<CardIcon>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct CardTitle {
     #[prost(string, tag = "1")]
     pub text: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
     pub text_color: ::core::option::Option<FlexColor>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct RulesText {
     #[prost(string, tag = "1")]
     pub text: ::prost::alloc::string::String,
 }
 /// Card has no targeting requirement
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct NoTargeting {
     /// True if this card can currently be played
     #[prost(bool, tag = "1")]
     pub can_play: bool,
 }
 /// This card should prompt for a room to be played into.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct PlayInRoom {
     /// The card can be played if at least one identifier is present here
     #[prost(enumeration = "RoomIdentifier", repeated, tag = "1")]
     pub valid_rooms: ::prost::alloc::vec::Vec<i32>,
 }
 /// The card should show an arrow to select a room to target
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ArrowTargetRoom {
     /// The card can be played if at least one identifier is present here
     #[prost(enumeration = "RoomIdentifier", repeated, tag = "1")]
@@ -462,14 +462,14 @@ pub struct ArrowTargetRoom {
     #[prost(enumeration = "TargetingArrow", tag = "2")]
     pub arrow: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct CardTargeting {
     #[prost(oneof = "card_targeting::Targeting", tags = "1, 2, 3")]
     pub targeting: ::core::option::Option<card_targeting::Targeting>,
 }
 /// Nested message and enum types in `CardTargeting`.
 pub mod card_targeting {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum Targeting {
         #[prost(message, tag = "1")]
         NoTargeting(super::NoTargeting),
@@ -479,43 +479,43 @@ pub mod card_targeting {
         ArrowTargetRoom(super::ArrowTargetRoom),
     }
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionOffscreen {}
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionRoom {
     #[prost(enumeration = "RoomIdentifier", tag = "1")]
     pub room_id: i32,
     #[prost(enumeration = "ClientRoomLocation", tag = "2")]
     pub room_location: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionItem {
     #[prost(enumeration = "ClientItemLocation", tag = "1")]
     pub item_location: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionStaging {}
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionHand {
     #[prost(enumeration = "PlayerName", tag = "1")]
     pub owner: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionDeck {
     #[prost(enumeration = "PlayerName", tag = "1")]
     pub owner: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionDeckContainer {
     #[prost(enumeration = "PlayerName", tag = "1")]
     pub owner: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionDiscardPile {
     #[prost(enumeration = "PlayerName", tag = "1")]
     pub owner: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionDiscardPileContainer {
     #[prost(enumeration = "PlayerName", tag = "1")]
     pub owner: i32,
@@ -523,38 +523,38 @@ pub struct ObjectPositionDiscardPileContainer {
 ///
 /// Large display of cards *while* the score animation is playing. After the
 /// score animation finishes, scored cards move to 'Identity' position.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionScoreAnimation {}
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionRaid {}
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionBrowser {}
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionIdentity {
     #[prost(enumeration = "PlayerName", tag = "1")]
     pub owner: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionIdentityContainer {
     #[prost(enumeration = "PlayerName", tag = "1")]
     pub owner: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionRewardChest {}
 //// An object position which represents moving into a given card.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionIntoCard {
     #[prost(message, optional, tag = "1")]
     pub card_id: ::core::option::Option<CardIdentifier>,
 }
 //// An object position for newly-revealed cards, appears above other content
 //// like the staging area.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPositionRevealedCards {
     #[prost(enumeration = "RevealedCardsBrowserSize", tag = "1")]
     pub size: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPosition {
     /// A key by which to sort this object -- objects with higher sorting keys
     /// should be displayed 'on top of' or 'in front of' objects with lower
@@ -576,7 +576,7 @@ pub struct ObjectPosition {
 }
 /// Nested message and enum types in `ObjectPosition`.
 pub mod object_position {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum Position {
         #[prost(message, tag = "3")]
         Offscreen(super::ObjectPositionOffscreen),
@@ -610,7 +610,7 @@ pub mod object_position {
         Revealed(super::ObjectPositionRevealedCards),
     }
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct RevealedCardView {
     #[prost(message, optional, tag = "1")]
     pub card_frame: ::core::option::Option<SpriteAddress>,
@@ -642,7 +642,7 @@ pub struct RevealedCardView {
     #[prost(message, optional, tag = "9")]
     pub supplemental_info: ::core::option::Option<Node>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct CardView {
     #[prost(message, optional, tag = "1")]
     pub card_id: ::core::option::Option<CardIdentifier>,
@@ -682,7 +682,7 @@ pub struct CardView {
     #[prost(message, optional, tag = "11")]
     pub destroy_position: ::core::option::Option<ObjectPosition>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct PlayerInfo {
     #[prost(message, optional, tag = "1")]
     pub name: ::core::option::Option<::prost::alloc::string::String>,
@@ -696,7 +696,7 @@ pub struct PlayerInfo {
     #[prost(message, optional, tag = "5")]
     pub card_back: ::core::option::Option<SpriteAddress>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ManaView {
     #[prost(uint32, tag = "1")]
     pub base_mana: u32,
@@ -704,17 +704,26 @@ pub struct ManaView {
     #[prost(uint32, tag = "2")]
     pub bonus_mana: u32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ScoreView {
     #[prost(uint32, tag = "1")]
     pub score: u32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ActionTrackerView {
     #[prost(uint32, tag = "1")]
     pub available_action_count: u32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
+pub struct TurnTimerView {
+    /// Time remaining on this player's clock as of this update.
+    #[prost(uint64, tag = "1")]
+    pub remaining_milliseconds: u64,
+    /// Whether this player's clock is currently counting down.
+    #[prost(bool, tag = "2")]
+    pub running: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct PlayerView {
     #[prost(enumeration = "PlayerSide", tag = "1")]
     pub side: i32,
@@ -729,9 +738,16 @@ pub struct PlayerView {
     /// Whether this player is currently able to take a game action
     #[prost(bool, tag = "6")]
     pub can_take_action: bool,
+    /// Remaining time on this player's turn timer, if the game uses one.
+    #[prost(message, optional, tag = "7")]
+    pub turn_timer: ::core::option::Option<TurnTimerView>,
+    /// Number of this player's recent actions which can currently be undone,
+    /// e.g. in order to enable an undo button.
+    #[prost(uint32, tag = "8")]
+    pub available_undo_count: u32,
 }
 /// Positions of non-Card game objects.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct GameObjectPositions {
     #[prost(message, optional, tag = "1")]
     pub user_deck: ::core::option::Option<ObjectPosition>,
@@ -746,7 +762,7 @@ pub struct GameObjectPositions {
     #[prost(message, optional, tag = "6")]
     pub opponent_discard: ::core::option::Option<ObjectPosition>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct GameView {
     #[prost(message, optional, tag = "1")]
     pub user: ::core::option::Option<PlayerView>,
@@ -767,12 +783,16 @@ pub struct GameView {
     /// Controls for game actions such as interface prompts
     #[prost(message, optional, tag = "6")]
     pub main_controls: ::core::option::Option<InterfaceMainControls>,
+    /// Persistent indicator of which player is currently able to act, from
+    /// the perspective of the viewing player.
+    #[prost(message, optional, tag = "7")]
+    pub turn_banner: ::core::option::Option<Node>,
 }
 // ============================================================================
 // Actions
 // ============================================================================
 
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct StandardAction {
     /// Opaque payload to send to the server when invoked.
     #[prost(bytes = "vec", tag = "1")]
@@ -783,27 +803,27 @@ pub struct StandardAction {
 }
 /// Spend an action to gain 1 mana.
 /// Optimistic: Mana is added immediately.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct GainManaAction {}
 /// Spend an action to draw a card.
 /// Optimistic: Face-down card animates to reveal area.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct DrawCardAction {}
 /// Spend an action to level up a room.
 /// Optimistic: Room visit animation plays
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct LevelUpRoomAction {
     #[prost(enumeration = "RoomIdentifier", tag = "1")]
     pub room_id: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct CardTarget {
     #[prost(oneof = "card_target::CardTarget", tags = "1")]
     pub card_target: ::core::option::Option<card_target::CardTarget>,
 }
 /// Nested message and enum types in `CardTarget`.
 pub mod card_target {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum CardTarget {
         #[prost(enumeration = "super::RoomIdentifier", tag = "1")]
         RoomId(i32),
@@ -813,7 +833,7 @@ pub mod card_target {
 /// Optimistic:
 ///   - Card animates to its 'on_release' position. If the RoomIdentifier is
 ///     unspecified for a room position, the targeted room is used.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct PlayCardAction {
     #[prost(message, optional, tag = "1")]
     pub card_id: ::core::option::Option<CardIdentifier>,
@@ -822,19 +842,19 @@ pub struct PlayCardAction {
 }
 /// Spend an action to initiate a raid on one of the overlord's rooms
 /// Optimistic: Room visit animation plays
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct InitiateRaidAction {
     #[prost(enumeration = "RoomIdentifier", tag = "1")]
     pub room_id: i32,
 }
 /// Fetch the contents of a given interface panel.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct FetchPanelAction {
     #[prost(message, optional, tag = "1")]
     pub panel_address: ::core::option::Option<InterfacePanelAddress>,
 }
 /// Test/debug options for the new game action
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct NewGameDebugOptions {
     /// If true, all game events will be non-random.
     #[prost(bool, tag = "1")]
@@ -848,7 +868,7 @@ pub struct NewGameDebugOptions {
 /// opponent is e.g. an AI player), the game starts immediately.
 /// Otherwise, transitions the caller to a 'waiting' state until the
 /// invitation is accepted.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct NewGameAction {
     //// Deck you would like to use for this game
     #[prost(message, optional, tag = "1")]
@@ -861,21 +881,21 @@ pub struct NewGameAction {
 }
 /// Spend an action point with no other effect, typically used for
 /// tests
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct SpendActionPointAction {}
 /// Possible game actions taken by the user.
 ///
 /// Actions have an associated 'optimistic' behavior to display while waiting
 /// for a server response. The client should not send multiple actions at the
 /// same time -- interaction should be disabled while an action is pending.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct GameAction {
     #[prost(oneof = "game_action::Action", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9")]
     pub action: ::core::option::Option<game_action::Action>,
 }
 /// Nested message and enum types in `GameAction`.
 pub mod game_action {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum Action {
         #[prost(message, tag = "1")]
         StandardAction(super::StandardAction),
@@ -899,13 +919,24 @@ pub mod game_action {
 }
 /// Initiate a play session and download the current state for the
 /// provided player.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ConnectRequest {
     /// User making this request.
     #[prost(message, optional, tag = "1")]
     pub player_id: ::core::option::Option<PlayerIdentifier>,
+    /// If true, the user is connecting as an observer of the game identified
+    /// by 'game_id' rather than as a participant in their current game.
+    #[prost(bool, tag = "2")]
+    pub spectate: bool,
+    /// Game to observe, required when 'spectate' is set.
+    #[prost(message, optional, tag = "3")]
+    pub game_id: ::core::option::Option<GameIdentifier>,
+    /// Language in which to display card titles and rules text. Text without
+    /// a translation in this locale is displayed in English.
+    #[prost(enumeration = "Locale", tag = "4")]
+    pub locale: i32,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct GameRequest {
     #[prost(message, optional, tag = "1")]
     pub action: ::core::option::Option<GameAction>,
@@ -919,7 +950,7 @@ pub struct GameRequest {
 // Commands
 // ============================================================================
 
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct DebugLogCommand {
     #[prost(string, tag = "1")]
     pub message: ::prost::alloc::string::String,
@@ -927,26 +958,26 @@ pub struct DebugLogCommand {
 ///
 /// Run a series of command lists simultaneously. Warning: applying multiple
 /// commands to the same game object will have unpredictable results.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct RunInParallelCommand {
     #[prost(message, repeated, tag = "1")]
     pub commands: ::prost::alloc::vec::Vec<CommandList>,
 }
 /// Wait before executing the next command in sequence.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct DelayCommand {
     #[prost(message, optional, tag = "1")]
     pub duration: ::core::option::Option<TimeValue>,
 }
 /// Identifies an InterfacePanel.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct InterfacePanelAddress {
     #[prost(oneof = "interface_panel_address::AddressType", tags = "1, 2")]
     pub address_type: ::core::option::Option<interface_panel_address::AddressType>,
 }
 /// Nested message and enum types in `InterfacePanelAddress`.
 pub mod interface_panel_address {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum AddressType {
         #[prost(bytes, tag = "1")]
         Serialized(::prost::alloc::vec::Vec<u8>),
@@ -957,7 +988,7 @@ pub mod interface_panel_address {
 /// A 'panel' is an independently addressable block of UI. The contents
 /// of each known panel are cached and can then be opened immediately
 /// by the client, without waiting for a server response.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct InterfacePanel {
     #[prost(message, optional, tag = "1")]
     pub address: ::core::option::Option<InterfacePanelAddress>,
@@ -966,7 +997,7 @@ pub struct InterfacePanel {
 }
 /// Requests that a specific corner of a Node be anchored to a specific
 /// corner of a card.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct CardAnchor {
     #[prost(enumeration = "AnchorCorner", tag = "1")]
     pub node_corner: i32,
@@ -974,7 +1005,7 @@ pub struct CardAnchor {
     pub card_corner: i32,
 }
 /// Render an interface element attached to a specific card.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct CardAnchorNode {
     #[prost(message, optional, tag = "1")]
     pub card_id: ::core::option::Option<CardIdentifier>,
@@ -986,7 +1017,7 @@ pub struct CardAnchorNode {
     #[prost(message, repeated, tag = "3")]
     pub anchors: ::prost::alloc::vec::Vec<CardAnchor>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct InterfaceMainControls {
     /// Main controls area
     #[prost(message, optional, tag = "1")]
@@ -996,14 +1027,14 @@ pub struct InterfaceMainControls {
     pub card_anchor_nodes: ::prost::alloc::vec::Vec<CardAnchorNode>,
 }
 /// Updates the contents of one or more user interface panels
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct UpdatePanelsCommand {
     /// List of panels to update.
     #[prost(message, repeated, tag = "1")]
     pub panels: ::prost::alloc::vec::Vec<InterfacePanel>,
 }
 /// Requests to open or close the given interface panel.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct TogglePanelCommand {
     /// Panel to modify
     #[prost(message, optional, tag = "1")]
@@ -1013,7 +1044,7 @@ pub struct TogglePanelCommand {
     pub open: bool,
 }
 /// Updates the current GameView state.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct UpdateGameViewCommand {
     #[prost(message, optional, tag = "1")]
     pub game: ::core::option::Option<GameView>,
@@ -1024,7 +1055,7 @@ pub struct UpdateGameViewCommand {
 ///
 /// Animates 'initiator' moving to a room and plays a standard particle effect
 /// based on the visit type.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct VisitRoomCommand {
     #[prost(enumeration = "PlayerName", tag = "1")]
     pub initiator: i32,
@@ -1042,7 +1073,7 @@ pub struct VisitRoomCommand {
 ///
 /// Note that the created card will always be deleted by the next
 /// UpdateGameViewCommand if its ID is not present in that update.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct CreateTokenCardCommand {
     #[prost(message, optional, tag = "1")]
     pub card: ::core::option::Option<CardView>,
@@ -1050,7 +1081,61 @@ pub struct CreateTokenCardCommand {
     #[prost(bool, tag = "2")]
     pub animate: bool,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+/// Removes a card from the current GameView, e.g. an ability token which has
+/// finished resolving. The card is animated to its 'destroy_position' if
+/// 'animate' is set.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
+pub struct DestroyCardCommand {
+    #[prost(message, optional, tag = "1")]
+    pub card_id: ::core::option::Option<CardIdentifier>,
+    /// Whether this update should be animated
+    #[prost(bool, tag = "2")]
+    pub animate: bool,
+}
+/// Changed scalar values for a single player. Fields which are not set are
+/// unchanged from the previous PlayerView.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
+pub struct PlayerScalarsUpdate {
+    #[prost(message, optional, tag = "1")]
+    pub score: ::core::option::Option<ScoreView>,
+    #[prost(message, optional, tag = "2")]
+    pub mana: ::core::option::Option<ManaView>,
+    #[prost(message, optional, tag = "3")]
+    pub action_tracker: ::core::option::Option<ActionTrackerView>,
+    #[prost(message, optional, tag = "4")]
+    pub can_take_action: ::core::option::Option<bool>,
+    #[prost(message, optional, tag = "5")]
+    pub available_undo_count: ::core::option::Option<u32>,
+}
+/// Updates only the scalar fields of the current GameView's players, leaving
+/// all other state (cards, object positions, controls, etc) unchanged.
+///
+/// This is sent in place of an UpdateGameViewCommand when an update does not
+/// change anything else, for example when a player gains mana.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePlayerScalarsCommand {
+    #[prost(message, optional, tag = "1")]
+    pub user: ::core::option::Option<PlayerScalarsUpdate>,
+    #[prost(message, optional, tag = "2")]
+    pub opponent: ::core::option::Option<PlayerScalarsUpdate>,
+    /// Whether this update should be animated
+    #[prost(bool, tag = "3")]
+    pub animate: bool,
+}
+/// Replaces the listed cards in the current GameView, leaving all other cards
+/// and game state unchanged.
+///
+/// A card which is revealed to the viewer but has no 'revealed_card' keeps its
+/// previously-displayed revealed content.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCardsCommand {
+    #[prost(message, repeated, tag = "1")]
+    pub cards: ::prost::alloc::vec::Vec<CardView>,
+    /// Whether this update should be animated
+    #[prost(bool, tag = "2")]
+    pub animate: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct GameObjectMove {
     #[prost(message, optional, tag = "1")]
     pub id: ::core::option::Option<GameObjectIdentifier>,
@@ -1058,7 +1143,7 @@ pub struct GameObjectMove {
     pub position: ::core::option::Option<ObjectPosition>,
 }
 /// Move a list of game objects to new positions, in parallel
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct MoveGameObjectsCommand {
     #[prost(message, repeated, tag = "1")]
     pub moves: ::prost::alloc::vec::Vec<GameObjectMove>,
@@ -1068,19 +1153,19 @@ pub struct MoveGameObjectsCommand {
     #[prost(message, optional, tag = "3")]
     pub delay: ::core::option::Option<TimeValue>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct PlaySoundCommand {
     #[prost(message, optional, tag = "1")]
     pub sound: ::core::option::Option<AudioClipAddress>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct SetMusicCommand {
     #[prost(enumeration = "MusicState", tag = "1")]
     pub music_state: i32,
 }
 ///
 /// Fire a projectile from one game object at another.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct FireProjectileCommand {
     #[prost(message, optional, tag = "1")]
     pub source_id: ::core::option::Option<GameObjectIdentifier>,
@@ -1118,20 +1203,20 @@ pub struct FireProjectileCommand {
     #[prost(message, optional, tag = "11")]
     pub jump_to_position: ::core::option::Option<ObjectPosition>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct PlayEffectPosition {
     #[prost(oneof = "play_effect_position::EffectPosition", tags = "1")]
     pub effect_position: ::core::option::Option<play_effect_position::EffectPosition>,
 }
 /// Nested message and enum types in `PlayEffectPosition`.
 pub mod play_effect_position {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum EffectPosition {
         #[prost(message, tag = "1")]
         GameObject(super::GameObjectIdentifier),
     }
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct PlayEffectCommand {
     #[prost(message, optional, tag = "1")]
     pub effect: ::core::option::Option<EffectAddress>,
@@ -1145,24 +1230,24 @@ pub struct PlayEffectCommand {
     #[prost(message, optional, tag = "5")]
     pub sound: ::core::option::Option<AudioClipAddress>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct DisplayGameMessageCommand {
     #[prost(enumeration = "GameMessageType", tag = "1")]
     pub message_type: i32,
 }
 /// Used to hide and show all game UI elements.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct SetGameObjectsEnabledCommand {
     #[prost(bool, tag = "1")]
     pub game_objects_enabled: bool,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct DisplayRewardsCommand {
     #[prost(message, repeated, tag = "1")]
     pub rewards: ::prost::alloc::vec::Vec<CardView>,
 }
 /// Loads a named Unity scene
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct LoadSceneCommand {
     #[prost(string, tag = "1")]
     pub scene_name: ::prost::alloc::string::String,
@@ -1170,7 +1255,7 @@ pub struct LoadSceneCommand {
     pub mode: i32,
 }
 /// Sets a client-side boolean player preference
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct SetBooleanPreference {
     #[prost(string, tag = "1")]
     pub key: ::prost::alloc::string::String,
@@ -1178,7 +1263,7 @@ pub struct SetBooleanPreference {
     pub value: bool,
 }
 /// Logs a client message
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct LogMessage {
     #[prost(string, tag = "1")]
     pub text: ::prost::alloc::string::String,
@@ -1186,14 +1271,14 @@ pub struct LogMessage {
     pub level: i32,
 }
 /// Activates client-side debugging functionality
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct ClientDebugCommand {
     #[prost(oneof = "client_debug_command::DebugCommand", tags = "1, 2, 3, 4")]
     pub debug_command: ::core::option::Option<client_debug_command::DebugCommand>,
 }
 /// Nested message and enum types in `ClientDebugCommand`.
 pub mod client_debug_command {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum DebugCommand {
         #[prost(message, tag = "1")]
         ShowLogs(()),
@@ -1205,17 +1290,17 @@ pub mod client_debug_command {
         SetBooleanPreference(super::SetBooleanPreference),
     }
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct GameCommand {
     #[prost(
         oneof = "game_command::Command",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19"
     )]
     pub command: ::core::option::Option<game_command::Command>,
 }
 /// Nested message and enum types in `GameCommand`.
 pub mod game_command {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Oneof)]
     pub enum Command {
         #[prost(message, tag = "1")]
         Debug(super::ClientDebugCommand),
@@ -1249,9 +1334,15 @@ pub mod game_command {
         MoveGameObjects(super::MoveGameObjectsCommand),
         #[prost(message, tag = "16")]
         CreateTokenCard(super::CreateTokenCardCommand),
+        #[prost(message, tag = "17")]
+        UpdatePlayerScalars(super::UpdatePlayerScalarsCommand),
+        #[prost(message, tag = "18")]
+        UpdateCards(super::UpdateCardsCommand),
+        #[prost(message, tag = "19")]
+        DestroyCard(super::DestroyCardCommand),
     }
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, ::prost::Message)]
 pub struct CommandList {
     #[prost(message, repeated, tag = "1")]
     pub commands: ::prost::alloc::vec::Vec<GameCommand>,
@@ -1260,7 +1351,7 @@ pub struct CommandList {
 // Masonry
 // ============================================================================
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FlexAlign {
     Unspecified = 0,
@@ -1270,14 +1361,14 @@ pub enum FlexAlign {
     FlexEnd = 4,
     Stretch = 5,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FlexDisplayStyle {
     Unspecified = 0,
     Flex = 1,
     None = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FlexDirection {
     Unspecified = 0,
@@ -1286,7 +1377,7 @@ pub enum FlexDirection {
     Row = 3,
     RowReverse = 4,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FlexWrap {
     Unspecified = 0,
@@ -1294,7 +1385,7 @@ pub enum FlexWrap {
     Wrap = 2,
     WrapReverse = 3,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FlexJustify {
     Unspecified = 0,
@@ -1304,28 +1395,28 @@ pub enum FlexJustify {
     SpaceBetween = 4,
     SpaceAround = 5,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FlexOverflow {
     Unspecified = 0,
     Visible = 1,
     Hidden = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FlexPosition {
     Unspecified = 0,
     Relative = 1,
     Absolute = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum TextOverflow {
     Unspecified = 0,
     Clip = 1,
     Ellipsis = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum EasingMode {
     Unspecified = 0,
@@ -1353,7 +1444,7 @@ pub enum EasingMode {
     EaseOutBounce = 22,
     EaseInOutBounce = 23,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ImageScaleMode {
     Unspecified = 0,
@@ -1361,7 +1452,7 @@ pub enum ImageScaleMode {
     ScaleAndCrop = 2,
     ScaleToFit = 3,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FontStyle {
     Unspecified = 0,
@@ -1370,14 +1461,14 @@ pub enum FontStyle {
     Italic = 3,
     BoldAndItalic = 4,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum OverflowClipBox {
     Unspecified = 0,
     PaddingBox = 1,
     ContentBox = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum TextAlign {
     Unspecified = 0,
@@ -1391,7 +1482,7 @@ pub enum TextAlign {
     LowerCenter = 8,
     LowerRight = 9,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum TextOverflowPosition {
     Unspecified = 0,
@@ -1399,21 +1490,21 @@ pub enum TextOverflowPosition {
     Start = 2,
     Middle = 3,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FlexVisibility {
     Unspecified = 0,
     Visible = 1,
     Hidden = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum WhiteSpace {
     Unspecified = 0,
     Normal = 1,
     NoWrap = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum DimensionUnit {
     Unspecified = 0,
@@ -1424,7 +1515,7 @@ pub enum DimensionUnit {
     Percentage = 2,
 }
 /// Controls whether elements respond to interface events.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FlexPickingMode {
     /// Unspecified, currently identical to 'position'.
@@ -1434,21 +1525,21 @@ pub enum FlexPickingMode {
     /// Picking disabled, events ignored.
     Ignore = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum PlayerSide {
     Unspecified = 0,
     Overlord = 1,
     Champion = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum PlayerName {
     Unspecified = 0,
     User = 1,
     Opponent = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum RoomIdentifier {
     Unspecified = 0,
@@ -1461,7 +1552,7 @@ pub enum RoomIdentifier {
     RoomD = 7,
     RoomE = 8,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum TargetingArrow {
     Unspecified = 0,
@@ -1469,43 +1560,51 @@ pub enum TargetingArrow {
     Blue = 2,
     Green = 3,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ClientRoomLocation {
     Unspecified = 0,
     Back = 1,
     Front = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ClientItemLocation {
     Unspecified = 0,
     Left = 1,
     Right = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum RevealedCardsBrowserSize {
     Unspecified = 0,
     Small = 1,
     Large = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum CardPrefab {
     Unspecified = 0,
     Standard = 1,
     TokenCard = 2,
 }
+/// Language in which user-visible game text is displayed.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Locale {
+    Unspecified = 0,
+    English = 1,
+    Spanish = 2,
+}
 /// Panels that are directly fetched by client code.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ClientPanelAddress {
     Unspecified = 0,
     DebugPanel = 1,
 }
 /// Possible corners which can be anchored.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum AnchorCorner {
     Unspecified = 0,
@@ -1514,14 +1613,14 @@ pub enum AnchorCorner {
     BottomLeft = 3,
     BottomRight = 4,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum RoomVisitType {
     Unspecified = 0,
     InitiateRaid = 1,
     LevelUpRoom = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum CardCreationAnimation {
     Unspecified = 0,
@@ -1531,7 +1630,7 @@ pub enum CardCreationAnimation {
     /// identifier with no 'ability_id') to its create position.
     FromParentCard = 2,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum MusicState {
     Unspecified = 0,
@@ -1539,7 +1638,7 @@ pub enum MusicState {
     Gameplay = 2,
     Raid = 3,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum GameMessageType {
     Unspecified = 0,
@@ -1548,7 +1647,7 @@ pub enum GameMessageType {
     Victory = 3,
     Defeat = 4,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SceneLoadMode {
     Unspecified = 0,
@@ -1558,7 +1657,7 @@ pub enum SceneLoadMode {
     Additive = 2,
 }
 /// Possible client logging levels
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum LogMessageLevel {
     Unspecified = 0,
@@ -0,0 +1,86 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use data::game::{GameState, InternalRaidPhase};
+use data::game_actions::{ActivationPhaseAction, PromptAction};
+use data::primitives::Side;
+use rules::queries;
+use with_error::fail;
+
+use crate::defenders;
+use crate::traits::{RaidDisplayState, RaidPhaseImpl};
+
+/// Window at the start of a raid in which the Overlord may activate abilities
+/// in response before the Champion encounters any defenders.
+#[derive(Debug, Clone, Copy)]
+pub struct ActivationPhase {}
+
+impl RaidPhaseImpl for ActivationPhase {
+    type Action = ActivationPhaseAction;
+
+    fn unwrap(action: PromptAction) -> Result<ActivationPhaseAction> {
+        match action {
+            PromptAction::ActivationPhaseAction(action) => Ok(action),
+            _ => fail!("Expected ActivationPhaseAction"),
+        }
+    }
+
+    fn wrap(action: ActivationPhaseAction) -> Result<PromptAction> {
+        Ok(PromptAction::ActivationPhaseAction(action))
+    }
+
+    fn enter(self, game: &mut GameState) -> Result<Option<InternalRaidPhase>> {
+        if queries::can_respond(game, Side::Overlord) {
+            Ok(None)
+        } else {
+            continue_raid(game)
+        }
+    }
+
+    fn actions(self, _: &GameState) -> Result<Vec<ActivationPhaseAction>> {
+        Ok(vec![ActivationPhaseAction::ContinueRaid])
+    }
+
+    fn handle_action(
+        self,
+        game: &mut GameState,
+        action: ActivationPhaseAction,
+    ) -> Result<Option<InternalRaidPhase>> {
+        match action {
+            ActivationPhaseAction::ContinueRaid => continue_raid(game),
+        }
+    }
+
+    fn active_side(self) -> Side {
+        Side::Overlord
+    }
+
+    fn display_state(self, game: &GameState) -> Result<RaidDisplayState> {
+        Ok(RaidDisplayState::Defenders(game.defender_list(game.raid()?.encounter_room()).to_vec()))
+    }
+}
+
+/// Moves on to encounter the first eligible defender, or to access the room if
+/// there are none.
+fn continue_raid(game: &mut GameState) -> Result<Option<InternalRaidPhase>> {
+    Ok(Some(if let Some(encounter) = defenders::next_encounter(game, None)? {
+        game.raid_mut()?.encounter = Some(encounter);
+        InternalRaidPhase::Encounter
+    } else if defenders::encounter_queued_room(game)? {
+        InternalRaidPhase::Encounter
+    } else {
+        InternalRaidPhase::Access
+    }))
+}
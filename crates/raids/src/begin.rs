@@ -20,11 +20,10 @@ use data::primitives::Side;
 use rules::dispatch;
 use with_error::fail;
 
-use crate::defenders;
 use crate::traits::{RaidDisplayState, RaidPhaseImpl};
 
-/// The starting state for all raids, fires 'raid start' events and determines
-/// the next state to move to.
+/// The starting state for all raids, fires 'raid start' events and then gives
+/// the Overlord a chance to respond.
 #[derive(Debug, Clone, Copy)]
 pub struct BeginPhase {}
 
@@ -52,12 +51,7 @@ impl RaidPhaseImpl for BeginPhase {
             return Ok(None);
         }
 
-        Ok(Some(if let Some(encounter) = defenders::next_encounter(game, None)? {
-            game.raid_mut()?.encounter = Some(encounter);
-            InternalRaidPhase::Encounter
-        } else {
-            InternalRaidPhase::Access
-        }))
+        Ok(Some(InternalRaidPhase::Activation))
     }
 
     fn actions(self, _: &GameState) -> Result<Vec<()>> {
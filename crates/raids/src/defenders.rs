@@ -48,11 +48,11 @@ pub fn can_summon_defender(game: &GameState, defender_id: CardId) -> Result<bool
 /// An 'eligible' defender is either one which is face up, or one which *can* be
 /// turned face up by paying its costs.
 pub fn next_encounter(game: &GameState, less_than: Option<usize>) -> Result<Option<usize>> {
-    let target = game.raid()?.target;
+    let target = game.raid()?.encounter_room();
     let defenders = game.defender_list(target);
     let mut reversed = utils::fallible(defenders.iter().enumerate().rev());
     let found = reversed.find(|(index, card_id)| {
-        let in_range = less_than.map_or(true, |less_than| *index < less_than);
+        let in_range = less_than.is_none_or(|less_than| *index < less_than);
         let defender_id = find_defender(game, target, *index)?;
         let can_encounter =
             game.card(**card_id).is_face_up() || can_summon_defender(game, defender_id)?;
@@ -62,6 +62,26 @@ pub fn next_encounter(game: &GameState, less_than: Option<usize>) -> Result<Opti
     Ok(found.map(|(index, _)| index))
 }
 
+/// Moves an ongoing raid on to the first eligible defender of the next room in
+/// its [RaidData::queued_rooms], skipping rooms which have no eligible
+/// defenders. Returns true if a new encounter was started, or false if no
+/// queued rooms remain.
+///
+/// [RaidData::queued_rooms]: data::game::RaidData::queued_rooms
+pub fn encounter_queued_room(game: &mut GameState) -> Result<bool> {
+    while !game.raid()?.queued_rooms.is_empty() {
+        let room_id = game.raid_mut()?.queued_rooms.remove(0);
+        game.raid_mut()?.current_room = Some(room_id);
+        if let Some(encounter) = next_encounter(game, None)? {
+            game.raid_mut()?.encounter = Some(encounter);
+            return Ok(true);
+        }
+    }
+
+    game.raid_mut()?.current_room = None;
+    Ok(false)
+}
+
 fn find_defender(game: &GameState, room_id: RoomId, index: usize) -> Result<CardId> {
     Ok(*game.defender_list(room_id).get(index).with_error(|| "Defender Not Found")?)
 }
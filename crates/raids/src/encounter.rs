@@ -17,13 +17,13 @@ use data::delegates::{
     EncounterMinionEvent, MinionCombatAbilityEvent, MinionCombatActionsQuery, MinionDefeatedEvent,
     UsedWeapon, UsedWeaponEvent,
 };
-use data::game::{GameState, InternalRaidPhase};
-use data::game_actions::{EncounterAction, PromptAction};
-use data::primitives::{CardId, GameObjectId, Side};
+use data::game::{GameState, InternalRaidPhase, ManaSource};
+use data::game_actions::{EncounterAction, GamePrompt, PromptAction};
+use data::primitives::{CardId, GameObjectId, ManaValue, Side};
 use data::updates::{GameUpdate, TargetedInteraction};
 use rules::mana::ManaPurpose;
 use rules::{card_prompt, dispatch, flags, mana, mutations, queries};
-use with_error::{fail, WithError};
+use with_error::{fail, verify, WithError};
 
 use crate::defenders;
 use crate::mutations::SummonMinion;
@@ -62,10 +62,9 @@ impl RaidPhaseImpl for EncounterPhase {
 
     fn actions(self, game: &GameState) -> Result<Vec<EncounterAction>> {
         let defender_id = game.raid_defender()?;
-        Ok(game
-            .weapons()
-            .filter(|weapon| flags::can_defeat_target(game, weapon.id, defender_id))
-            .map(|weapon| EncounterAction::UseWeaponAbility(weapon.id, defender_id))
+        Ok(queries::weapon_options(game, defender_id)
+            .into_iter()
+            .map(|(weapon_id, _)| EncounterAction::UseWeaponAbility(weapon_id, defender_id))
             .chain(minion_combat_actions(game, defender_id))
             .collect())
     }
@@ -77,28 +76,14 @@ impl RaidPhaseImpl for EncounterPhase {
     ) -> Result<Option<InternalRaidPhase>> {
         match action {
             EncounterAction::UseWeaponAbility(source_id, target_id) => {
-                let cost = queries::cost_to_defeat_target(game, source_id, target_id).with_error(
-                    || format!("{:?} cannot defeat target: {:?}", source_id, target_id),
-                )?;
+                let cost = cost_to_defeat(game, source_id, target_id)?;
+                if mana::has_source_choice(game, Side::Champion, cost) {
+                    game.player_mut(Side::Champion).prompt =
+                        Some(GamePrompt::choose_mana_source(source_id, target_id, cost));
+                    return Ok(None);
+                }
                 mana::spend(game, Side::Champion, ManaPurpose::UseWeapon(source_id), cost)?;
-
-                game.record_update(|| {
-                    GameUpdate::TargetedInteraction(TargetedInteraction {
-                        source: GameObjectId::CardId(source_id),
-                        target: GameObjectId::CardId(target_id),
-                    })
-                });
-
-                dispatch::invoke_event(
-                    game,
-                    UsedWeaponEvent(UsedWeapon {
-                        raid_id: game.raid()?.raid_id,
-                        weapon_id: source_id,
-                        target_id,
-                        mana_spent: cost,
-                    }),
-                )?;
-                dispatch::invoke_event(game, MinionDefeatedEvent(target_id))?;
+                use_weapon(game, source_id, target_id, cost)?;
             }
             EncounterAction::NoWeapon | EncounterAction::CardAction(_) => {
                 let defender_id = game.raid_defender()?;
@@ -117,29 +102,91 @@ impl RaidPhaseImpl for EncounterPhase {
             card_prompt::handle(game, Side::Champion, card_action)?;
         }
 
-        Ok(if game.data.raid.is_none() {
-            // Abilities may have ended the raid
-            None
-        } else if let Some(encounter) =
-            defenders::next_encounter(game, Some(game.raid_encounter()?))?
-        {
-            game.raid_mut()?.encounter = Some(encounter);
-            Some(InternalRaidPhase::Encounter)
-        } else {
-            Some(InternalRaidPhase::Access)
-        })
+        next_phase(game)
     }
 
     fn active_side(self) -> Side {
         Side::Champion
     }
 
+    fn can_abort(self, game: &GameState) -> bool {
+        flags::can_abort_raid(game)
+    }
+
     fn display_state(self, game: &GameState) -> Result<RaidDisplayState> {
-        let defenders = game.defender_list(game.raid()?.target);
+        let defenders = game.defender_list(game.raid()?.encounter_room());
         Ok(RaidDisplayState::Defenders(defenders[0..=game.raid_encounter()?].to_vec()))
     }
 }
 
+/// Completes the Champion's use of the `weapon_id` weapon against the
+/// `target_id` minion after they have chosen to pay its cost from `source`
+/// in response to a [GamePrompt::choose_mana_source] prompt.
+pub fn use_weapon_with_source(
+    game: &mut GameState,
+    weapon_id: CardId,
+    target_id: CardId,
+    source: ManaSource,
+) -> Result<Option<InternalRaidPhase>> {
+    verify!(
+        game.raid()?.internal_phase == InternalRaidPhase::Encounter
+            && game.raid_defender()? == target_id,
+        "{:?} is not currently being encountered",
+        target_id
+    );
+    let cost = cost_to_defeat(game, weapon_id, target_id)?;
+    mana::spend_from(game, Side::Champion, source, cost)?;
+    use_weapon(game, weapon_id, target_id, cost)?;
+    next_phase(game)
+}
+
+fn cost_to_defeat(game: &GameState, source_id: CardId, target_id: CardId) -> Result<ManaValue> {
+    queries::cost_to_defeat_target(game, source_id, target_id)
+        .with_error(|| format!("{:?} cannot defeat target: {:?}", source_id, target_id))
+}
+
+/// Resolves the effects of using a weapon once its `cost` has been paid.
+fn use_weapon(
+    game: &mut GameState,
+    source_id: CardId,
+    target_id: CardId,
+    cost: ManaValue,
+) -> Result<()> {
+    game.record_update(|| {
+        GameUpdate::TargetedInteraction(TargetedInteraction {
+            source: GameObjectId::CardId(source_id),
+            target: GameObjectId::CardId(target_id),
+        })
+    });
+
+    dispatch::invoke_event(
+        game,
+        UsedWeaponEvent(UsedWeapon {
+            raid_id: game.raid()?.raid_id,
+            weapon_id: source_id,
+            target_id,
+            mana_spent: cost,
+        }),
+    )?;
+    dispatch::invoke_event(game, MinionDefeatedEvent(target_id))
+}
+
+/// Determines the phase to transition to after the current encounter has been
+/// resolved.
+fn next_phase(game: &mut GameState) -> Result<Option<InternalRaidPhase>> {
+    Ok(if game.data.raid.is_none() {
+        // Abilities may have ended the raid
+        None
+    } else if let Some(encounter) = defenders::next_encounter(game, Some(game.raid_encounter()?))? {
+        game.raid_mut()?.encounter = Some(encounter);
+        Some(InternalRaidPhase::Encounter)
+    } else if defenders::encounter_queued_room(game)? {
+        Some(InternalRaidPhase::Encounter)
+    } else {
+        Some(InternalRaidPhase::Access)
+    })
+}
+
 /// Actions to present when a minion is encountered in combat in addition to
 /// weapon abilities.
 fn minion_combat_actions(game: &GameState, minion_id: CardId) -> Vec<EncounterAction> {
@@ -17,19 +17,23 @@
 pub mod traits;
 
 mod access;
+mod activation;
 mod begin;
 mod defenders;
 mod encounter;
 
 use anyhow::Result;
-use data::game::{GameState, InternalRaidPhase, RaidData, RaidJumpRequest, RoomState};
+use data::delegates::RaidOutcome;
+use data::game::{GameState, InternalRaidPhase, ManaSource, RaidData, RaidJumpRequest};
 use data::game_actions::{GamePrompt, PromptAction};
-use data::primitives::{RaidId, RoomId, Side};
+use data::primitives::{CardId, RaidId, RoomId, Side};
 use data::updates::{GameUpdate, InitiatedBy};
 use rules::{flags, mutations, queries};
+use tracing::debug;
 use with_error::{verify, WithError};
 
 use crate::access::AccessPhase;
+use crate::activation::ActivationPhase;
 use crate::begin::BeginPhase;
 use crate::encounter::EncounterPhase;
 use crate::traits::RaidPhase;
@@ -44,6 +48,7 @@ impl RaidDataExt for RaidData {
     fn phase(&self) -> Box<dyn RaidPhase> {
         match self.internal_phase {
             InternalRaidPhase::Begin => Box::new(BeginPhase {}),
+            InternalRaidPhase::Activation => Box::new(ActivationPhase {}),
             InternalRaidPhase::Encounter => Box::new(EncounterPhase {}),
             InternalRaidPhase::Access => Box::new(AccessPhase {}),
         }
@@ -70,6 +75,8 @@ pub fn handle_initiate_action(
 /// card effect (as differentiated by the [InitiatedBy] prop). Invokes the
 /// `on_begin` function immediately with the [RaidId] that will be used for this
 /// raid, before any other game logic runs.
+///
+/// Returns an error if the game has exhausted its supply of [RaidId]s.
 pub fn initiate(
     game: &mut GameState,
     target_room: RoomId,
@@ -77,6 +84,8 @@ pub fn initiate(
     on_begin: impl Fn(&mut GameState, RaidId),
 ) -> Result<()> {
     let raid_id = RaidId(game.data.next_raid_id);
+    let next_raid_id =
+        raid_id.0.checked_add(1).with_error(|| format!("Raid ID overflow at {:?}", raid_id))?;
     let phase = InternalRaidPhase::Begin;
     let raid = RaidData {
         target: target_room,
@@ -85,12 +94,13 @@ pub fn initiate(
         encounter: None,
         accessed: vec![],
         jump_request: None,
+        queued_rooms: vec![],
+        current_room: None,
     };
 
-    game.data.next_raid_id += 1;
+    game.data.next_raid_id = next_raid_id;
     game.data.raid = Some(raid);
-    game.room_state.entry(target_room).or_insert_with(RoomState::default).last_raided =
-        Some(game.data.turn);
+    game.room_state.entry(target_room).or_default().last_raided = Some(game.data.turn);
     on_begin(game, raid_id);
     game.record_update(|| GameUpdate::InitiateRaid(target_room, initiated_by));
     enter_phase(game, Some(phase))?;
@@ -105,6 +115,10 @@ pub fn handle_action(game: &mut GameState, user_side: Side, action: PromptAction
     let phase = game.raid()?.phase();
     verify!(phase.active_side() == user_side, "Unexpected side");
     verify!(phase.prompts(game)?.iter().any(|c| c == &action), "Unexpected action");
+    if action == PromptAction::AbortRaid {
+        return abort(game);
+    }
+
     let mut new_state = phase.handle_prompt(game, action)?;
     new_state = apply_jump(game)?.or(new_state);
 
@@ -115,6 +129,27 @@ pub fn handle_action(game: &mut GameState, user_side: Side, action: PromptAction
     }
 }
 
+/// Handles the Champion's choice of [ManaSource] in response to a
+/// [GamePrompt::choose_mana_source] prompt, completing their use of the
+/// `weapon_id` weapon against the `target_id` minion.
+pub fn handle_mana_source_chosen(
+    game: &mut GameState,
+    user_side: Side,
+    weapon_id: CardId,
+    target_id: CardId,
+    source: ManaSource,
+) -> Result<()> {
+    verify!(user_side == Side::Champion, "Unexpected side");
+    let mut new_state = encounter::use_weapon_with_source(game, weapon_id, target_id, source)?;
+    new_state = apply_jump(game)?.or(new_state);
+
+    if game.data.raid.is_some() {
+        enter_phase(game, new_state)
+    } else {
+        Ok(())
+    }
+}
+
 /// Returns a list of the user actions which are possible in the current raid
 /// state for the `side` player, or `None` if no such actions are possible.
 pub fn current_actions(game: &GameState, user_side: Side) -> Result<Option<Vec<PromptAction>>> {
@@ -130,20 +165,39 @@ pub fn current_actions(game: &GameState, user_side: Side) -> Result<Option<Vec<P
     Ok(None)
 }
 
+/// Returns the player who can act in the current raid phase, or `None` if no
+/// raid is currently active.
+pub fn active_side(game: &GameState) -> Option<Side> {
+    game.data.raid.as_ref().map(|raid| raid.phase().active_side())
+}
+
 /// Builds a [GamePrompt] representing the possible actions for the `side` user,
 /// as determined by the [current_actions] function.
 pub fn current_prompt(game: &GameState, user_side: Side) -> Result<Option<GamePrompt>> {
     if let Some(actions) = current_actions(game, user_side)? {
-        Ok(Some(GamePrompt { context: game.raid()?.phase().prompt_context(), responses: actions }))
+        Ok(Some(GamePrompt {
+            context: game.raid()?.phase().prompt_context(),
+            responses: actions,
+            cancellable: false,
+        }))
     } else {
         Ok(None)
     }
 }
 
+/// Ends the current raid without accessing its target room. The room remains
+/// marked as raided this turn.
+fn abort(game: &mut GameState) -> Result<()> {
+    let target = game.raid()?.target;
+    game.record_update(|| GameUpdate::AbortRaid(target));
+    mutations::end_raid(game, RaidOutcome::Failure)
+}
+
 /// Sets the gam eto a new raid phase and invokes callbacks as needed.
 fn enter_phase(game: &mut GameState, mut phase: Option<InternalRaidPhase>) -> Result<()> {
     loop {
         if let Some(s) = phase {
+            debug!(phase = ?s, raid_id = ?game.raid()?.raid_id, "enter_raid_phase");
             game.raid_mut()?.internal_phase = s;
             phase = game.raid()?.phase().enter(game)?;
             phase = apply_jump(game)?.or(phase);
@@ -157,14 +211,49 @@ fn enter_phase(game: &mut GameState, mut phase: Option<InternalRaidPhase>) -> Re
 /// raid.
 fn apply_jump(game: &mut GameState) -> Result<Option<InternalRaidPhase>> {
     if let Some(raid) = &game.data.raid {
-        if let Some(RaidJumpRequest::EncounterMinion(card_id)) = raid.jump_request {
-            let (room_id, index) =
-                queries::minion_position(game, card_id).with_error(|| "Minion not found")?;
-            let raid = game.raid_mut()?;
-            raid.target = room_id;
-            raid.encounter = Some(index);
-            raid.jump_request = None;
-            return Ok(Some(InternalRaidPhase::Encounter));
+        match raid.jump_request {
+            Some(RaidJumpRequest::EncounterMinion(card_id)) => {
+                let (room_id, index) =
+                    queries::minion_position(game, card_id).with_error(|| "Minion not found")?;
+                debug!(?card_id, ?room_id, ?index, "apply_raid_jump");
+                let raid = game.raid_mut()?;
+                if room_id != raid.encounter_room() {
+                    raid.target = room_id;
+                    raid.current_room = None;
+                }
+                raid.encounter = Some(index);
+                raid.jump_request = None;
+                return Ok(Some(InternalRaidPhase::Encounter));
+            }
+            Some(RaidJumpRequest::AddRoom(room_id)) => {
+                debug!(?room_id, "apply_raid_jump");
+                let raid = game.raid_mut()?;
+                raid.queued_rooms.push(room_id);
+                raid.jump_request = None;
+            }
+            Some(RaidJumpRequest::SkipEncounter { room_id, less_than }) => {
+                debug!(?room_id, ?less_than, "apply_raid_jump");
+                let raid = game.raid_mut()?;
+                raid.jump_request = None;
+                if raid.internal_phase != InternalRaidPhase::Encounter
+                    || raid.encounter_room() != room_id
+                {
+                    // Raid has already moved on from this room
+                    return Ok(None);
+                }
+
+                return Ok(Some(
+                    if let Some(encounter) = defenders::next_encounter(game, Some(less_than))? {
+                        game.raid_mut()?.encounter = Some(encounter);
+                        InternalRaidPhase::Encounter
+                    } else if defenders::encounter_queued_room(game)? {
+                        InternalRaidPhase::Encounter
+                    } else {
+                        InternalRaidPhase::Access
+                    },
+                ));
+            }
+            None => {}
         }
     }
 
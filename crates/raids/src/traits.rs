@@ -39,11 +39,8 @@ pub trait RaidPhase {
     /// action is required.
     fn enter(&self, game: &mut GameState) -> Result<Option<InternalRaidPhase>>;
 
-    /// Identifies the player who can act in the current raid phase.
-    ///
-    /// At time of writing, the Overlord player can never act during a raid,
-    /// making this largely irrelevant. Keeping it around for now in case that
-    /// changes.
+    /// Identifies the player who can act in the current raid phase. This is the
+    /// Champion except during the Overlord's activation window.
     fn active_side(&self) -> Side;
 
     /// Describes how the current phase should be represented in the UI.
@@ -102,6 +99,12 @@ pub trait RaidPhaseImpl: RaidPhase + Sized + Copy {
         None
     }
 
+    /// Whether the Champion may currently retreat from the raid in this phase
+    /// via [PromptAction::AbortRaid].
+    fn can_abort(self, _game: &GameState) -> bool {
+        false
+    }
+
     fn handle_prompt(
         self,
         game: &mut GameState,
@@ -111,7 +114,12 @@ pub trait RaidPhaseImpl: RaidPhase + Sized + Copy {
     }
 
     fn prompts(self, game: &GameState) -> Result<Vec<PromptAction>> {
-        utils::fallible(self.actions(game)?.into_iter()).map(Self::wrap).collect()
+        let mut result: Vec<_> =
+            utils::fallible(self.actions(game)?.into_iter()).map(Self::wrap).collect()?;
+        if self.can_abort(game) {
+            result.push(PromptAction::AbortRaid);
+        }
+        Ok(result)
     }
 }
 
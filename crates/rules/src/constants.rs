@@ -14,4 +14,5 @@
 
 pub static STARTING_HAND_SIZE: u32 = 5;
 pub static STARTING_MAXIMUM_HAND_SIZE: u32 = 7;
+pub static POINTS_TO_WIN_GAME: u32 = 7;
 pub static MAXIMUM_MINIONS_IN_ROOM: usize = 4;
@@ -17,31 +17,52 @@
 
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use anyhow::Result;
-use data::delegates::{DelegateCache, DelegateContext, EventData, QueryData, Scope};
+use data::delegates::{DelegateCache, DelegateContext, DelegateKind, EventData, QueryData, Scope};
 use data::game::GameState;
-use data::primitives::AbilityId;
+use data::primitives::{AbilityId, CardId};
 use tracing::instrument;
 
 /// Adds a [DelegateCache] for this game in order to improve lookup performance.
 pub fn populate_delegate_cache(game: &mut GameState) {
     let mut result = HashMap::new();
     for card_id in game.all_card_ids() {
-        let definition = crate::get(game.card(card_id).name);
-        for (index, ability) in definition.abilities.iter().enumerate() {
-            let ability_id = AbilityId::new(card_id, index);
-            let scope = Scope::new(ability_id);
-            for delegate in &ability.delegates {
-                result
-                    .entry(delegate.kind())
-                    .or_insert_with(Vec::new)
-                    .push(DelegateContext { delegate: delegate.clone(), scope });
-            }
-        }
+        add_card_delegates(game, card_id, &mut result);
     }
 
-    game.delegate_cache = DelegateCache { lookup: result };
+    game.delegate_cache = DelegateCache { lookup: Arc::new(result) };
+}
+
+/// Adds the delegates of a card which was created after
+/// [populate_delegate_cache] was invoked, e.g. a token, to the cache for this
+/// game.
+///
+/// Delegates are appended after all existing entries, so the indices used by
+/// an event which is currently being dispatched remain valid.
+pub fn add_to_delegate_cache(game: &mut GameState, card_id: CardId) {
+    let mut lookup = Arc::clone(&game.delegate_cache.lookup);
+    add_card_delegates(game, card_id, Arc::make_mut(&mut lookup));
+    game.delegate_cache = DelegateCache { lookup };
+}
+
+fn add_card_delegates(
+    game: &GameState,
+    card_id: CardId,
+    result: &mut HashMap<DelegateKind, Vec<DelegateContext>>,
+) {
+    let definition = crate::get(game.card(card_id).name);
+    for (index, ability) in definition.abilities.iter().enumerate() {
+        let ability_id = AbilityId::new(card_id, index);
+        let scope = Scope::new(ability_id);
+        for delegate in &ability.delegates {
+            result
+                .entry(delegate.kind())
+                .or_default()
+                .push(DelegateContext { delegate: delegate.clone(), scope });
+        }
+    }
 }
 
 /// Called when a game event occurs, invokes each registered
@@ -15,16 +15,16 @@
 //! Functions to query boolean game information, typically whether some game
 //! action can currently be taken
 
-use data::card_definition::{AbilityType, TargetRequirement};
+use data::card_definition::{AbilityType, ManaCost, TargetRequirement};
 use data::card_state::CardPosition;
 use data::delegates::{
     CanActivateAbilityQuery, CanActivateWhileFaceDownQuery, CanDefeatTargetQuery,
     CanEncounterTargetQuery, CanInitiateRaidQuery, CanLevelUpCardQuery, CanLevelUpRoomQuery,
     CanPlayCardQuery, CanTakeDrawCardActionQuery, CanTakeGainManaActionQuery, CardEncounter, Flag,
 };
-use data::game::{GamePhase, GameState};
-use data::game_actions::CardTarget;
-use data::primitives::{AbilityId, CardId, CardType, Lineage, RoomId, Side};
+use data::game::{GamePhase, GameState, InternalRaidPhase};
+use data::game_actions::{CardTarget, CardTargetKind};
+use data::primitives::{AbilityId, CardId, CardType, Lineage, ManaValue, RoomId, Side};
 
 use crate::mana::ManaPurpose;
 use crate::{dispatch, mana, queries};
@@ -37,9 +37,12 @@ pub fn can_make_mulligan_decision(game: &GameState, side: Side) -> bool {
 }
 
 /// Returns true if the owner of the `card_id` card can currently pay its cost.
+///
+/// Cards with a variable mana cost can always be paid for, since X may be 0.
 pub fn can_pay_card_cost(game: &GameState, card_id: CardId) -> bool {
-    let mut can_pay = matches!(queries::mana_cost(game, card_id), Some(cost)
-                             if cost <= mana::get(game, card_id.side, ManaPurpose::PayForCard(card_id)));
+    let mut can_pay = has_variable_mana_cost(game, card_id)
+        || matches!(queries::mana_cost(game, card_id), Some(cost)
+                    if cost <= mana::get(game, card_id.side, ManaPurpose::PayForCard(card_id)));
     if let Some(custom_cost) = &crate::card_definition(game, card_id).cost.custom_cost {
         can_pay &= (custom_cost.can_pay)(game, card_id);
     }
@@ -47,6 +50,18 @@ pub fn can_pay_card_cost(game: &GameState, card_id: CardId) -> bool {
     can_pay
 }
 
+/// Returns true if only one copy of the `card_id` card can be in play for its
+/// owner at a time.
+pub fn is_unique(game: &GameState, card_id: CardId) -> bool {
+    crate::card_definition(game, card_id).config.unique
+}
+
+/// Returns true if the `card_id` card costs 'X' mana, where X is chosen by its
+/// owner when it is played.
+pub fn has_variable_mana_cost(game: &GameState, card_id: CardId) -> bool {
+    crate::card_definition(game, card_id).cost.mana == ManaCost::Variable
+}
+
 /// Returns whether a given card can currently be played via the basic game
 /// action to play a card.
 pub fn can_take_play_card_action(
@@ -68,6 +83,91 @@ pub fn can_take_play_card_action(
     dispatch::perform_query(game, CanPlayCardQuery(card_id), Flag::new(can_play)).into()
 }
 
+/// Returns true if the `side` player can currently play the `card_id` card
+/// with at least one legal [CardTarget].
+pub fn is_currently_playable(game: &GameState, side: Side, card_id: CardId) -> bool {
+    match queries::card_target_kind(game, card_id) {
+        CardTargetKind::None => can_take_play_card_action(game, side, card_id, CardTarget::None),
+        CardTargetKind::Room => enum_iterator::all::<RoomId>().any(|room_id| {
+            can_take_play_card_action(game, side, card_id, CardTarget::Room(room_id))
+        }),
+        CardTargetKind::Card => game
+            .all_cards()
+            .any(|card| can_take_play_card_action(game, side, card_id, CardTarget::Card(card.id))),
+    }
+}
+
+/// Reason a card cannot currently be played, see [play_obstacle].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayObstacle {
+    /// It is currently the opponent's turn.
+    NotYourTurn,
+    /// The card costs more mana than is available to pay for it.
+    NotEnoughMana { short_by: ManaValue },
+    /// The player does not have enough action points to play the card.
+    NoActionPoints,
+    /// There is no legal target for the card.
+    NoLegalTarget,
+    /// Cards cannot be played during the current phase of the game, e.g.
+    /// during mulligans, a raid, or while a prompt is pending.
+    WrongPhase,
+    /// The card is not in its owner's hand, or some other condition imposed by
+    /// a card prevents it from being played.
+    PlayConditionUnmet,
+}
+
+/// Explains why the `side` player cannot currently play the `card_id` card,
+/// or returns None if it can be played.
+///
+/// Conditions are checked in the same order as [is_currently_playable] and
+/// the first failing condition is reported.
+pub fn play_obstacle(game: &GameState, side: Side, card_id: CardId) -> Option<PlayObstacle> {
+    if !matches!(&game.data.phase, GamePhase::Play) {
+        return Some(PlayObstacle::WrongPhase);
+    }
+    if game.data.turn.side != side {
+        return Some(PlayObstacle::NotYourTurn);
+    }
+    if game.data.raid.is_some() || game.overlord.prompt.is_some() || game.champion.prompt.is_some()
+    {
+        return Some(PlayObstacle::WrongPhase);
+    }
+    if side != card_id.side || game.card(card_id).position() != CardPosition::Hand(side) {
+        return Some(PlayObstacle::PlayConditionUnmet);
+    }
+
+    let actions = game.player(side).actions;
+    if actions == 0 || queries::action_cost(game, card_id) > actions {
+        return Some(PlayObstacle::NoActionPoints);
+    }
+    if enters_play_face_up(game, card_id) && !has_variable_mana_cost(game, card_id) {
+        if let Some(cost) = queries::mana_cost(game, card_id) {
+            let available = mana::get(game, side, ManaPurpose::PayForCard(card_id));
+            if cost > available {
+                return Some(PlayObstacle::NotEnoughMana { short_by: cost - available });
+            }
+        }
+    }
+    if !has_valid_target(game, card_id) {
+        return Some(PlayObstacle::NoLegalTarget);
+    }
+
+    (!is_currently_playable(game, side, card_id)).then_some(PlayObstacle::PlayConditionUnmet)
+}
+
+/// Returns true if any [CardTarget] is valid for the `card_id` card, ignoring
+/// all other conditions on playing it.
+fn has_valid_target(game: &GameState, card_id: CardId) -> bool {
+    match queries::card_target_kind(game, card_id) {
+        CardTargetKind::None => is_valid_target(game, card_id, CardTarget::None),
+        CardTargetKind::Room => enum_iterator::all::<RoomId>()
+            .any(|room_id| is_valid_target(game, card_id, CardTarget::Room(room_id))),
+        CardTargetKind::Card => {
+            game.all_cards().any(|card| is_valid_target(game, card_id, CardTarget::Card(card.id)))
+        }
+    }
+}
+
 /// Whether the `ability_id` ability can be activated while its card is face
 /// down in play.
 pub fn can_activate_while_face_down(game: &GameState, ability_id: AbilityId) -> bool {
@@ -95,7 +195,8 @@ pub fn can_take_activate_ability_action(
         return false;
     }
 
-    let mut can_activate = in_main_phase(game, side)
+    let mut can_activate = (in_main_phase(game, side)
+        || (cost.actions == 0 && in_raid_activation_window(game, side)))
         && side == ability_id.card_id.side
         && cost.actions <= game.player(side).actions
         && card.position().in_play()
@@ -113,6 +214,27 @@ pub fn can_take_activate_ability_action(
         .into()
 }
 
+/// Returns true if a raid is paused in its
+/// [InternalRaidPhase::Activation] window with no pending prompts, during which
+/// the Overlord may activate abilities which cost no actions.
+pub fn in_raid_activation_window(game: &GameState, side: Side) -> bool {
+    side == Side::Overlord
+        && matches!(&game.data.raid, Some(raid) if raid.internal_phase == InternalRaidPhase::Activation)
+        && game.overlord.prompt.is_none()
+        && game.champion.prompt.is_none()
+}
+
+/// Returns true if the Champion may currently retreat from the active raid via
+/// [PromptAction::AbortRaid]. This is only possible while a minion is being
+/// encountered, i.e. before a weapon has been committed against it.
+///
+/// [PromptAction::AbortRaid]: data::game_actions::PromptAction::AbortRaid
+pub fn can_abort_raid(game: &GameState) -> bool {
+    matches!(&game.data.raid, Some(raid) if raid.internal_phase == InternalRaidPhase::Encounter)
+        && game.overlord.prompt.is_none()
+        && game.champion.prompt.is_none()
+}
+
 /// Returns true if the `ability_id` ability could be activated with a valid
 /// target.
 pub fn activated_ability_has_valid_targets(
@@ -128,6 +250,9 @@ pub fn activated_ability_has_valid_targets(
             TargetRequirement::TargetRoom(_) => enum_iterator::all::<RoomId>().any(|room_id| {
                 can_take_activate_ability_action(game, side, ability_id, CardTarget::Room(room_id))
             }),
+            TargetRequirement::TargetCard(_) => game.all_cards().any(|card| {
+                can_take_activate_ability_action(game, side, ability_id, CardTarget::Card(card.id))
+            }),
         },
         _ => false,
     }
@@ -173,6 +298,9 @@ fn matching_targeting<T>(
         (TargetRequirement::TargetRoom(predicate), CardTarget::Room(room_id)) => {
             predicate(game, data, room_id)
         }
+        (TargetRequirement::TargetCard(predicate), CardTarget::Card(card_id)) => {
+            predicate(game, data, card_id)
+        }
         _ => false,
     }
 }
@@ -180,10 +308,7 @@ fn matching_targeting<T>(
 /// Returns true if the indicated card should enter play in the face up state
 /// and is expected to pay its costs immediately.
 pub fn enters_play_face_up(game: &GameState, card_id: CardId) -> bool {
-    !matches!(
-        crate::get(game.card(card_id).name).card_type,
-        CardType::Minion | CardType::Scheme | CardType::Project
-    )
+    crate::get(game.card(card_id).name).enters_face_up()
 }
 
 /// Returns true if the indicated card should enter play in a room
@@ -194,6 +319,22 @@ pub fn enters_play_in_room(game: &GameState, card_id: CardId) -> bool {
     )
 }
 
+/// Returns whether the `side` player can currently undo their most recent
+/// action, see [data::game_actions::UserAction::Undo].
+///
+/// Undo is not possible once the game has ended, if the opponent has acted
+/// since that action, or if the action revealed hidden information such as the
+/// identity of a drawn card.
+pub fn can_undo(game: &GameState, side: Side) -> bool {
+    let Some(step) = game.undo_history.last() else {
+        return false;
+    };
+
+    step.side == side
+        && !matches!(game.data.phase, GamePhase::GameOver { .. })
+        && !queries::reveals_hidden_information(&step.snapshot, game)
+}
+
 /// Returns whether the indicated player can currently take the basic game
 /// action to draw a card.
 pub fn can_take_draw_card_action(game: &GameState, side: Side) -> bool {
@@ -208,14 +349,18 @@ pub fn can_take_gain_mana_action(game: &GameState, side: Side) -> bool {
     dispatch::perform_query(game, CanTakeGainManaActionQuery(side), Flag::new(can_gain_mana)).into()
 }
 
+/// Returns whether the indicated player can currently end their turn without
+/// spending their remaining actions. This is only permitted when
+/// [data::game::GameConfiguration::carry_over_actions] is set.
+pub fn can_take_end_turn_action(game: &GameState, side: Side) -> bool {
+    in_main_phase(game, side) && game.data.config.carry_over_actions.is_some()
+}
+
 /// Returns whether the indicated player can currently take the basic game
 /// action to initiate a raid on the target [RoomId].
 pub fn can_take_initiate_raid_action(game: &GameState, side: Side, target: RoomId) -> bool {
     let non_empty = target.is_inner_room() || game.occupants(target).next().is_some();
-    let can_initiate = non_empty
-        && side == Side::Champion
-        && game.data.raid.is_none()
-        && in_main_phase(game, side);
+    let can_initiate = non_empty && side == Side::Champion && in_main_phase(game, side);
     dispatch::perform_query(game, CanInitiateRaidQuery(side), Flag::new(can_initiate)).into()
 }
 
@@ -291,6 +436,17 @@ pub fn can_defeat_target(game: &GameState, source: CardId, target: CardId) -> bo
 
 /// Returns true if the provided `side` player is currently in their Main phase
 /// with no pending prompt responses, and thus can take a primary game action.
+///
+/// This is the single definition of the main phase, which requires that:
+///
+/// - It is the `side` player's turn, and they have at least one action.
+/// - The game is in the [GamePhase::Play] phase.
+/// - No raid is currently active.
+/// - Neither player has a pending prompt.
+///
+/// All basic game actions (drawing a card, gaining mana, playing a card,
+/// activating an ability, leveling up a room, and initiating a raid) check
+/// this function rather than testing these conditions individually.
 pub fn in_main_phase(game: &GameState, side: Side) -> bool {
     game.player(side).actions > 0
         && matches!(&game.data.phase, GamePhase::Play)
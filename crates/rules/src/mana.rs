@@ -18,8 +18,10 @@
 use std::cmp;
 
 use anyhow::Result;
-use data::game::{GameState, SpecificRaidMana};
-use data::primitives::{AbilityId, CardId, ManaValue, RaidId, RoomId, Side};
+use data::game::{
+    GameState, ManaPayment, ManaRestriction, ManaSource, RestrictedMana, SpecificRaidMana,
+};
+use data::primitives::{AbilityId, CardId, CardType, ManaValue, RaidId, RoomId, Side};
 use with_error::{verify, WithError};
 
 /// Identifies possible reasons why a player's mana value would need to be
@@ -51,6 +53,15 @@ pub fn get(game: &GameState, side: Side, purpose: ManaPurpose) -> ManaValue {
         _ => {}
     }
 
+    result += game
+        .player(side)
+        .mana_state
+        .restricted_mana
+        .iter()
+        .filter(|pool| restriction_applies(game, pool.restriction, purpose))
+        .map(|pool| pool.mana)
+        .sum::<ManaValue>();
+
     match purpose {
         ManaPurpose::BaseMana => base_mana,
         ManaPurpose::BonusForDisplay => result - base_mana,
@@ -64,19 +75,41 @@ pub fn get(game: &GameState, side: Side, purpose: ManaPurpose) -> ManaValue {
 /// only be used for a certain type of action is preferred, then raid-specific
 /// mana, then general mana.
 ///
-/// Returns an error if insufficient mana is available.
+/// Returns a [ManaPayment] recording the amount taken from each pool, or an
+/// error if insufficient mana is available.
 pub fn spend(
     game: &mut GameState,
     side: Side,
     purpose: ManaPurpose,
     amount: ManaValue,
-) -> Result<()> {
+) -> Result<ManaPayment> {
     verify!(get(game, side, purpose) >= amount);
     let mut to_spend = amount;
+    let mut payment = ManaPayment::default();
+
+    let applicable = game
+        .player(side)
+        .mana_state
+        .restricted_mana
+        .iter()
+        .map(|pool| restriction_applies(game, pool.restriction, purpose))
+        .collect::<Vec<_>>();
+    let pools = &mut game.player_mut(side).mana_state.restricted_mana;
+    for (pool, _) in pools.iter_mut().zip(applicable).filter(|(_, applies)| *applies) {
+        let remaining = try_spend(&mut pool.mana, to_spend);
+        if remaining < to_spend {
+            payment
+                .restricted_mana
+                .push(RestrictedMana { restriction: pool.restriction, mana: to_spend - remaining });
+        }
+        to_spend = remaining;
+    }
+    pools.retain(|pool| pool.mana > 0);
 
     match (&game.data.raid, &game.player(side).mana_state.specific_raid_mana) {
         (Some(raid_data), Some(raid_mana)) if raid_data.raid_id == raid_mana.raid_id => {
-            to_spend = try_spend(
+            let raid_id = raid_mana.raid_id;
+            let remaining = try_spend(
                 &mut game
                     .player_mut(side)
                     .mana_state
@@ -86,11 +119,78 @@ pub fn spend(
                     .mana,
                 to_spend,
             );
+            if remaining < to_spend {
+                payment.specific_raid_mana =
+                    Some(SpecificRaidMana { raid_id, mana: to_spend - remaining });
+            }
+            to_spend = remaining;
         }
         _ => {}
     }
 
     game.player_mut(side).mana_state.base_mana -= to_spend;
+    payment.base_mana = to_spend;
+    Ok(payment)
+}
+
+/// Returns the mana taken by a [ManaPayment] from [spend] to the pools it was
+/// taken from.
+pub fn refund(game: &mut GameState, side: Side, payment: &ManaPayment) {
+    gain(game, side, payment.base_mana);
+    if let Some(raid_mana) = &payment.specific_raid_mana {
+        add_raid_specific_mana(game, side, raid_mana.raid_id, raid_mana.mana);
+    }
+    for pool in &payment.restricted_mana {
+        add_restricted_mana(game, side, pool.restriction, pool.mana);
+    }
+}
+
+/// Queries the amount of mana available for the `side` player in the given
+/// [ManaSource] pool, ignoring all other pools.
+pub fn get_from(game: &GameState, side: Side, source: ManaSource) -> ManaValue {
+    match source {
+        ManaSource::Base => game.player(side).mana_state.base_mana,
+        ManaSource::RaidSpecific => {
+            match (&game.data.raid, &game.player(side).mana_state.specific_raid_mana) {
+                (Some(raid_data), Some(raid_mana)) if raid_data.raid_id == raid_mana.raid_id => {
+                    raid_mana.mana
+                }
+                _ => 0,
+            }
+        }
+    }
+}
+
+/// Returns true if the `side` player could pay `amount` mana entirely from
+/// either their base mana or their raid-specific mana, meaning they should be
+/// allowed to choose which [ManaSource] to spend via [spend_from].
+pub fn has_source_choice(game: &GameState, side: Side, amount: ManaValue) -> bool {
+    amount > 0
+        && get_from(game, side, ManaSource::Base) >= amount
+        && get_from(game, side, ManaSource::RaidSpecific) >= amount
+}
+
+/// Spends mana for the `side` player only from the given [ManaSource] pool.
+///
+/// Returns an error if that pool contains insufficient mana.
+pub fn spend_from(
+    game: &mut GameState,
+    side: Side,
+    source: ManaSource,
+    amount: ManaValue,
+) -> Result<()> {
+    verify!(get_from(game, side, source) >= amount, "Insufficient mana in {:?}", source);
+    let mana_state = &mut game.player_mut(side).mana_state;
+    match source {
+        ManaSource::Base => mana_state.base_mana -= amount,
+        ManaSource::RaidSpecific => {
+            mana_state
+                .specific_raid_mana
+                .as_mut()
+                .with_error(|| "Expected raid-specific mana")?
+                .mana -= amount
+        }
+    }
     Ok(())
 }
 
@@ -128,6 +228,64 @@ pub fn add_raid_specific_mana(
     }
 }
 
+/// Adds mana for the `side` player which can only be used during the current
+/// raid. This mana is spent before base mana and is removed when the raid ends.
+///
+/// Returns an error if no raid is currently active.
+pub fn gain_for_current_raid(game: &mut GameState, side: Side, amount: ManaValue) -> Result<()> {
+    let raid_id = game.raid()?.raid_id;
+    add_raid_specific_mana(game, side, raid_id, amount);
+    Ok(())
+}
+
+/// Adds mana for the `side` player which can only be used to pay for cards of
+/// the given [CardType]. This mana is spent before all other mana when paying
+/// for a matching card.
+pub fn gain_restricted_to_type(
+    game: &mut GameState,
+    side: Side,
+    amount: ManaValue,
+    card_type: CardType,
+) {
+    add_restricted_mana(game, side, ManaRestriction::OnlyForCardType(card_type), amount);
+}
+
+fn add_restricted_mana(
+    game: &mut GameState,
+    side: Side,
+    restriction: ManaRestriction,
+    amount: ManaValue,
+) {
+    let pools = &mut game.player_mut(side).mana_state.restricted_mana;
+    match pools.iter_mut().find(|pool| pool.restriction == restriction) {
+        Some(pool) => pool.mana += amount,
+        None => pools.push(RestrictedMana { restriction, mana: amount }),
+    }
+}
+
+/// Removes all raid-specific mana for both players.
+pub fn clear_raid_specific_mana(game: &mut GameState) {
+    for side in enum_iterator::all::<Side>() {
+        game.player_mut(side).mana_state.specific_raid_mana = None;
+    }
+}
+
+/// Returns true if mana with the given [ManaRestriction] can be counted
+/// towards the provided [ManaPurpose].
+fn restriction_applies(
+    game: &GameState,
+    restriction: ManaRestriction,
+    purpose: ManaPurpose,
+) -> bool {
+    match (restriction, purpose) {
+        (_, ManaPurpose::BonusForDisplay | ManaPurpose::AllSources) => true,
+        (ManaRestriction::OnlyForCardType(card_type), ManaPurpose::PayForCard(card_id)) => {
+            crate::card_definition(game, card_id).card_type == card_type
+        }
+        _ => false,
+    }
+}
+
 fn try_spend(source: &mut ManaValue, amount: ManaValue) -> ManaValue {
     if *source >= amount {
         *source -= amount;
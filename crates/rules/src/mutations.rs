@@ -19,27 +19,27 @@
 //! *after* performing their mutation to inform other systems that game state
 //! has changed.
 
-use std::cmp;
+use std::{cmp, mem};
 
 use anyhow::Result;
 #[allow(unused)] // Used in rustdocs
-use data::card_state::{CardData, CardPosition, CardPositionKind};
+use data::card_state::{CardData, CardPosition, CardPositionKind, CardState};
 use data::delegates::{
-    CardMoved, DawnEvent, DealtDamage, DealtDamageEvent, DrawCardEvent, DuskEvent, EnterPlayEvent,
-    MoveCardEvent, OverlordScoreCardEvent, RaidEndEvent, RaidEnded, RaidFailureEvent, RaidOutcome,
-    RaidSuccessEvent, Scope, ScoreCard, ScoreCardEvent, StoredManaTakenEvent, SummonMinionEvent,
-    UnveilProjectEvent,
+    CardMoved, CardRevealed, DawnEvent, DealtDamage, DealtDamageEvent, DrawCardActionEvent,
+    DrawCardEvent, DuskEvent, EnterPlayEvent, MoveCardEvent, OverlordScoreCardEvent, RaidEndEvent,
+    RaidEnded, RaidFailureEvent, RaidOutcome, RaidSuccessEvent, RevealCardEvent, Scope, ScoreCard,
+    ScoreCardEvent, StoredManaTakenEvent, SummonMinionEvent, UnveilProjectEvent,
 };
-use data::game::{GamePhase, GameState, TurnData};
-use data::game_actions::{CardPromptAction, GamePrompt};
+use data::game::{GamePhase, GameState, InternalRaidPhase, RaidJumpRequest, TurnData};
+use data::game_actions::{CardPromptAction, GamePrompt, PromptContext};
 use data::primitives::{
-    ActionCount, BoostData, CardId, HasAbilityId, ManaValue, PointsValue, RoomId, RoomLocation,
-    Side, TurnNumber,
+    ActionCount, BoostData, CardId, DefenderPosition, HasAbilityId, LevelValue, ManaValue,
+    PointsValue, RoomId, RoomLocation, Side, TurnNumber,
 };
 use data::random;
 use data::updates::GameUpdate;
 use tracing::{info, instrument};
-use with_error::verify;
+use with_error::{verify, WithError};
 
 use crate::mana::ManaPurpose;
 use crate::{constants, dispatch, flags, mana, queries};
@@ -67,12 +67,22 @@ pub fn move_card(game: &mut GameState, card_id: CardId, new_position: CardPositi
     if !old_position.in_play() && new_position.in_play() {
         game.card_mut(card_id).data.last_entered_play = Some(game.data.turn);
         dispatch::invoke_event(game, EnterPlayEvent(card_id))?;
+        check_unique(game, card_id)?;
     }
 
     if !new_position.in_play() {
         clear_counters(game, card_id);
     }
 
+    if game.card(card_id).data.is_token
+        && old_position.in_play()
+        && !new_position.in_play()
+        && new_position.kind() != CardPositionKind::Banished
+    {
+        // Tokens cease to exist when they leave play
+        game.move_card_internal(card_id, CardPosition::Banished(card_id.side));
+    }
+
     if let CardPosition::Room(room_id, RoomLocation::Defender) = new_position {
         check_minion_limit(game, room_id)?;
     }
@@ -80,6 +90,106 @@ pub fn move_card(game: &mut GameState, card_id: CardId, new_position: CardPositi
     Ok(())
 }
 
+/// Moves the `card_id` card to defend `room_id` via [move_card], placing it at
+/// the provided [DefenderPosition] within the room's defenders.
+///
+/// Like [move_card], this does not change the card's face-up or revealed
+/// state.
+pub fn add_defender(
+    game: &mut GameState,
+    card_id: CardId,
+    room_id: RoomId,
+    position: DefenderPosition,
+) -> Result<()> {
+    info!(?card_id, ?room_id, ?position, "add_defender");
+    let defender_position = CardPosition::Room(room_id, RoomLocation::Defender);
+    move_card(game, card_id, defender_position)?;
+    if position == DefenderPosition::Back && game.card(card_id).position() == defender_position {
+        // Index 0 in the defender list is the innermost position
+        game.move_card_to_index(card_id, 0);
+    }
+    game.record_update(|| GameUpdate::DefenderAdded(card_id));
+    Ok(())
+}
+
+/// Moves the `card_id` defender to a different room via [add_defender],
+/// placing it at the provided [DefenderPosition] within that room's defenders.
+///
+/// If a raid is currently encountering a different defender, the raid's
+/// encounter position is updated to continue against that minion. If
+/// `card_id` itself is being encountered, its encounter ends via
+/// [RaidJumpRequest::SkipEncounter] and the raid continues against the
+/// remaining defenders of its original room.
+///
+/// Returns an error if `card_id` is not currently defending a room or if it
+/// is already defending `room_id`.
+pub fn move_defender(
+    game: &mut GameState,
+    card_id: CardId,
+    room_id: RoomId,
+    position: DefenderPosition,
+) -> Result<()> {
+    let (old_room, _) =
+        queries::minion_position(game, card_id).with_error(|| "Minion not found")?;
+    verify!(old_room != room_id, "{:?} is already defending {:?}", card_id, room_id);
+    info!(?card_id, ?old_room, ?room_id, ?position, "move_defender");
+
+    let encounter = match &game.data.raid {
+        Some(raid) if raid.internal_phase == InternalRaidPhase::Encounter => {
+            raid.encounter.and_then(|index| {
+                Some((index, *game.defender_list(raid.encounter_room()).get(index)?))
+            })
+        }
+        _ => None,
+    };
+
+    add_defender(game, card_id, room_id, position)?;
+
+    match encounter {
+        Some((index, encountered)) if encountered == card_id => {
+            game.raid_mut()?.jump_request =
+                Some(RaidJumpRequest::SkipEncounter { room_id: old_room, less_than: index });
+        }
+        Some((_, encountered)) => {
+            let (_, index) =
+                queries::minion_position(game, encountered).with_error(|| "Minion not found")?;
+            game.raid_mut()?.encounter = Some(index);
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+/// Creates a temporary token copy of the `source_id` card and moves it to
+/// `position` via [move_card]. The token has the same name, and thus the same
+/// definition, as the source card and copies its face-up and revealed state.
+///
+/// Returns the [CardId] of the new token.
+pub fn create_token_copy(
+    game: &mut GameState,
+    source_id: CardId,
+    position: CardPosition,
+) -> Result<CardId> {
+    let side = source_id.side;
+    let token_id = CardId::new(side, game.cards(side).len());
+    let source = game.card(source_id);
+    let mut token = CardState::new_token(token_id, source.name);
+    if source.is_face_up() {
+        token.turn_face_up();
+    }
+    for viewer in enum_iterator::all::<Side>() {
+        token.set_revealed_to(viewer, source.is_revealed_to(viewer));
+    }
+
+    info!(?source_id, ?token_id, "create_token_copy");
+    game.record_update(|| GameUpdate::CreateTokenCopy(source_id, token_id));
+    game.cards_mut(side).push(token);
+    dispatch::add_to_delegate_cache(game, token_id);
+    move_card(game, token_id, position)?;
+    Ok(token_id)
+}
+
 /// Helper to move all cards in a list to a new [CardPosition] via [move_card].
 pub fn move_cards(game: &mut GameState, cards: &[CardId], to_position: CardPosition) -> Result<()> {
     for card_id in cards {
@@ -94,6 +204,45 @@ pub fn sacrifice_card(game: &mut GameState, card_id: CardId) -> Result<()> {
     move_card(game, card_id, CardPosition::DiscardPile(card_id.side))
 }
 
+/// Reveals a card to the `to` player without changing its face-up state and
+/// fires [RevealCardEvent]. Does nothing if the card is already revealed to
+/// this player.
+pub fn reveal_card(game: &mut GameState, card_id: CardId, to: Side) -> Result<()> {
+    if !game.card(card_id).is_revealed_to(to) {
+        game.card_mut(card_id).set_revealed_to(to, true);
+        game.record_update(|| GameUpdate::CardRevealed(to, card_id));
+        dispatch::invoke_event(game, RevealCardEvent(CardRevealed { card_id, to }))?;
+    }
+    Ok(())
+}
+
+/// Reveals up to `count` randomly-selected cards from the `side` player's hand
+/// to the `to` player, selected via the game random number generator. Cards
+/// remain in hand. Because a player can always see their own hand, revealing
+/// to the opponent makes the cards known to both players.
+///
+/// Returns the [CardId]s of the selected cards.
+pub fn reveal_random_from_hand(
+    game: &mut GameState,
+    side: Side,
+    count: usize,
+    to: Side,
+) -> Result<Vec<CardId>> {
+    let cards = random::cards_in_position(game, side, CardPosition::Hand(side), count);
+    for card_id in &cards {
+        reveal_card(game, *card_id, to)?;
+    }
+    Ok(cards)
+}
+
+/// Removes a card from the game, moving it to its owner's banished zone.
+/// Banished cards are revealed to both players and are not part of the deck or
+/// discard pile, so they cannot be retrieved by effects targeting those zones.
+pub fn banish(game: &mut GameState, card_id: CardId) -> Result<()> {
+    game.card_mut(card_id).turn_face_up();
+    move_card(game, card_id, CardPosition::Banished(card_id.side))
+}
+
 // Shuffles the provided `cards` into the `side` player's deck, clearing their
 // revealed state for both players.
 pub fn shuffle_into_deck(game: &mut GameState, side: Side, cards: &[CardId]) -> Result<()> {
@@ -111,11 +260,25 @@ pub fn shuffle_into_deck(game: &mut GameState, side: Side, cards: &[CardId]) ->
 /// Shuffles the `side` player's deck, moving all cards into the `DeckUnknown`
 /// card position.
 pub fn shuffle_deck(game: &mut GameState, side: Side) -> Result<()> {
-    let cards =
-        game.cards_in_position(side, CardPosition::DeckTop(side)).map(|c| c.id).collect::<Vec<_>>();
+    let cards = game
+        .cards(side)
+        .iter()
+        .filter(|c| {
+            c.position() == CardPosition::DeckTop(side)
+                || c.position() == CardPosition::DeckBottom(side)
+        })
+        .map(|c| c.id)
+        .collect::<Vec<_>>();
     move_cards(game, &cards, CardPosition::DeckUnknown(side))
 }
 
+/// Places the provided `cards` on the bottom of the `side` player's deck in
+/// the given order, so that they are only drawn once all other cards in the
+/// deck have been drawn.
+pub fn place_on_bottom_of_deck(game: &mut GameState, side: Side, cards: &[CardId]) -> Result<()> {
+    move_cards(game, cards, CardPosition::DeckBottom(side))
+}
+
 /// Helper function to draw `count` cards from the top of a player's deck and
 /// place them into their hand. If there are insufficient cards available, the
 /// `side` player loses the game.
@@ -143,6 +306,22 @@ pub fn draw_cards(game: &mut GameState, side: Side, count: u32) -> Result<Vec<Ca
     Ok(card_ids)
 }
 
+/// Draws the top card of the `side` player's deck and reveals it to both
+/// players, returning its [CardId] so the caller can branch on e.g. its
+/// card type.
+///
+/// This fires [DrawCardEvent] like any other draw, followed by
+/// [DrawCardActionEvent] for the drawn card. Returns `None` if the deck was
+/// empty, in which case the `side` player loses the game as in [draw_cards].
+pub fn draw_revealed(game: &mut GameState, side: Side) -> Result<Option<CardId>> {
+    let Some(card_id) = draw_cards(game, side, 1)?.first().copied() else {
+        return Ok(None);
+    };
+    reveal_card(game, card_id, side.opponent())?;
+    dispatch::invoke_event(game, DrawCardActionEvent(card_id))?;
+    Ok(Some(card_id))
+}
+
 /// Lose action points if a player has more than 0.
 #[instrument(skip(game))]
 pub fn lose_action_points_if_able(
@@ -167,19 +346,72 @@ pub fn spend_action_points(game: &mut GameState, side: Side, amount: ActionCount
     Ok(())
 }
 
+/// Removes all of a player's remaining action points, e.g. when they end their
+/// turn early. Up to [data::game::GameConfiguration::carry_over_actions] of
+/// these are recorded to be added to the player's actions next turn.
+pub fn forfeit_action_points(game: &mut GameState, side: Side) {
+    let remaining = mem::take(&mut game.player_mut(side).actions);
+    if let Some(max_carried) = game.data.config.carry_over_actions {
+        info!(?side, ?remaining, "carry_over_actions");
+        game.player_mut(side).carried_actions = cmp::min(remaining, max_carried);
+    }
+}
+
+/// Gives action points to a player.
+pub fn gain_action_points(game: &mut GameState, side: Side, amount: ActionCount) {
+    game.player_mut(side).actions += amount;
+}
+
 /// Adds points to a player's score and checks for the Game Over condition.
 pub fn score_points(game: &mut GameState, side: Side, amount: PointsValue) -> Result<()> {
     game.player_mut(side).score += amount;
-    if game.player(side).score >= 7 {
+    check_victory(game, side)
+}
+
+/// Adds points to a player's score outside of scoring a card, e.g. via a card
+/// effect, and checks for the Game Over condition.
+#[instrument(skip(game))]
+pub fn gain_points(game: &mut GameState, side: Side, amount: PointsValue) -> Result<()> {
+    info!(?side, ?amount, "gain_points");
+    game.player_mut(side).score += amount;
+    game.record_update(|| GameUpdate::PointsChanged(side));
+    check_victory(game, side)
+}
+
+/// Removes points from a player's score, e.g. via a card effect. A player's
+/// score cannot go below 0.
+#[instrument(skip(game))]
+pub fn lose_points(game: &mut GameState, side: Side, amount: PointsValue) -> Result<()> {
+    info!(?side, ?amount, "lose_points");
+    game.player_mut(side).score = game.player(side).score.saturating_sub(amount);
+    game.record_update(|| GameUpdate::PointsChanged(side));
+    Ok(())
+}
+
+/// Ends the game with `side` as the winner if they have reached
+/// [constants::POINTS_TO_WIN_GAME].
+fn check_victory(game: &mut GameState, side: Side) -> Result<()> {
+    if game.player(side).score >= constants::POINTS_TO_WIN_GAME {
         game_over(game, side)?;
     }
     Ok(())
 }
 
 /// Mark the game as won by the `winner` player.
+///
+/// If [data::game::GameConfiguration::disclose_on_game_over] is set, all cards
+/// are revealed to both players.
 pub fn game_over(game: &mut GameState, winner: Side) -> Result<()> {
     game.data.phase = GamePhase::GameOver { winner };
     game.record_update(|| GameUpdate::GameOver(winner));
+    if game.data.config.disclose_on_game_over {
+        // Revealed after recording the update so that only the final game state,
+        // not the game over animation, includes disclosed cards.
+        for card in game.all_cards_mut() {
+            card.set_revealed_to(Side::Overlord, true);
+            card.set_revealed_to(Side::Champion, true);
+        }
+    }
     Ok(())
 }
 
@@ -216,6 +448,34 @@ pub fn take_stored_mana(
     Ok(taken)
 }
 
+/// Adds `amount` to the stored mana in a card. Returns the new stored amount.
+#[instrument(skip(game))]
+pub fn store_mana(game: &mut GameState, card_id: CardId, amount: ManaValue) -> Result<ManaValue> {
+    info!(?card_id, ?amount, "store_mana");
+    game.card_mut(card_id).data.stored_mana += amount;
+    game.record_update(|| GameUpdate::StoredManaChanged(card_id));
+    Ok(game.card(card_id).data.stored_mana)
+}
+
+/// Moves *up to* `maximum` stored mana from a card to the mana pool of the
+/// player who owns this card. Returns the amount of mana withdrawn.
+///
+/// Unlike [take_stored_mana], this never sacrifices the card.
+#[instrument(skip(game))]
+pub fn withdraw_mana(
+    game: &mut GameState,
+    card_id: CardId,
+    maximum: ManaValue,
+) -> Result<ManaValue> {
+    info!(?card_id, ?maximum, "withdraw_mana");
+    let withdrawn = cmp::min(queries::stored_mana(game, card_id), maximum);
+    game.card_mut(card_id).data.stored_mana -= withdrawn;
+    mana::gain(game, card_id.side, withdrawn);
+    game.record_update(|| GameUpdate::StoredManaChanged(card_id));
+    dispatch::invoke_event(game, StoredManaTakenEvent(card_id))?;
+    Ok(withdrawn)
+}
+
 /// Overwrites the value of [CardData::boost_count] to match the provided
 /// [BoostData].
 #[instrument(skip(game))]
@@ -259,6 +519,7 @@ pub fn end_raid(game: &mut GameState, outcome: RaidOutcome) -> Result<()> {
     }
     dispatch::invoke_event(game, RaidEndEvent(RaidEnded { raid_id, outcome }))?;
     game.data.raid = None;
+    mana::clear_raid_specific_mana(game);
     check_end_turn(game)?;
     Ok(())
 }
@@ -267,8 +528,9 @@ pub fn end_raid(game: &mut GameState, outcome: RaidOutcome) -> Result<()> {
 #[instrument(skip(game))]
 pub fn deal_opening_hands(game: &mut GameState) -> Result<()> {
     info!("deal_opening_hands");
-    draw_cards(game, Side::Overlord, constants::STARTING_HAND_SIZE)?;
-    draw_cards(game, Side::Champion, constants::STARTING_HAND_SIZE)?;
+    let count = game.data.config.mulligan_hand_size.unwrap_or(constants::STARTING_HAND_SIZE);
+    draw_cards(game, Side::Overlord, count)?;
+    draw_cards(game, Side::Champion, count)?;
     Ok(())
 }
 
@@ -310,7 +572,10 @@ pub fn realize_top_of_deck(game: &mut GameState, side: Side, count: u32) -> Resu
         let remaining = count - cards.len();
         let mut shuffled =
             random::cards_in_position(game, side, CardPosition::DeckUnknown(side), remaining);
+        let mut bottom = game.card_list_for_position(side, CardPosition::DeckBottom(side));
+        bottom.truncate(remaining - shuffled.len());
         shuffled.append(&mut cards);
+        shuffled.append(&mut bottom);
         shuffled
     };
 
@@ -330,6 +595,24 @@ fn check_minion_limit(game: &mut GameState, room_id: RoomId) -> Result<()> {
     Ok(())
 }
 
+/// Checks whether the `card_id` card, which has just entered play, is unique,
+/// and if so moves any older copy of it to its owner's discard pile.
+fn check_unique(game: &mut GameState, card_id: CardId) -> Result<()> {
+    if flags::is_unique(game, card_id) {
+        let name = game.card(card_id).name;
+        let copies = game
+            .cards(card_id.side)
+            .iter()
+            .filter(|c| c.id != card_id && c.name == name && c.position().in_play())
+            .map(|c| c.id)
+            .collect::<Vec<_>>();
+        for copy in copies {
+            move_card(game, copy, CardPosition::DiscardPile(card_id.side))?;
+        }
+    }
+    Ok(())
+}
+
 /// Invoked after taking a game action to check if the turn should be switched
 /// for the provided player.
 pub fn check_end_turn(game: &mut GameState) -> Result<()> {
@@ -340,27 +623,67 @@ pub fn check_end_turn(game: &mut GameState) -> Result<()> {
     let turn = game.data.turn;
     let side = turn.side;
 
-    if game.player(side).actions == 0 && game.data.raid.is_none() {
+    let blocking_prompt = game
+        .player(side)
+        .prompt
+        .as_ref()
+        .and_then(|prompt| prompt.context.as_ref())
+        .is_some_and(PromptContext::blocks_end_turn);
+    if game.player(side).actions == 0 && game.data.raid.is_none() && !blocking_prompt {
         let max_hand_size = queries::maximum_hand_size(game, side) as usize;
         let hand = game.card_list_for_position(side, CardPosition::Hand(side));
         if hand.len() > max_hand_size {
+            // The turn ends once the player has discarded down to their maximum hand size
             let count = hand.len() - max_hand_size;
-            for card_id in hand.iter().take(count) {
-                move_card(game, *card_id, CardPosition::DiscardPile(side))?;
-            }
+            game.player_mut(side).prompt =
+                Some(GamePrompt::discard_to_hand_size(&hand, count, vec![]));
+            return Ok(());
         }
 
-        let turn_number = match side {
-            Side::Overlord => turn.turn_number,
-            Side::Champion => turn.turn_number + 1,
+        for card in game.all_cards_mut() {
+            card.data.damage = 0;
+        }
+        game.data.turn_history.push(turn);
+        let next_side = if game.data.extra_turns.front() == Some(&side) {
+            game.data.extra_turns.pop_front();
+            side
+        } else {
+            side.opponent()
+        };
+        let turn_number = match (side, next_side) {
+            (Side::Overlord, Side::Champion) => turn.turn_number,
+            _ => turn.turn_number + 1,
         };
-        let next_side = side.opponent();
         start_turn(game, next_side, turn_number)?;
     }
 
     Ok(())
 }
 
+/// Moves the provided cards from the `side` player's hand to their discard
+/// pile. Returns an error if any of these cards are not in that player's hand.
+#[instrument(skip(game))]
+pub fn discard_from_hand(game: &mut GameState, side: Side, cards: &[CardId]) -> Result<()> {
+    info!(?side, ?cards, "discard_from_hand");
+    for card_id in cards {
+        verify!(
+            game.card(*card_id).position() == CardPosition::Hand(side),
+            "{:?} is not in hand",
+            card_id
+        );
+        move_card(game, *card_id, CardPosition::DiscardPile(side))?;
+    }
+    Ok(())
+}
+
+/// Queues an additional turn for the `side` player. The next time one of their
+/// turns ends while this is the first queued extra turn, they take another turn
+/// instead of play passing to the opponent.
+pub fn grant_extra_turn(game: &mut GameState, side: Side) -> Result<()> {
+    game.data.extra_turns.push_back(side);
+    Ok(())
+}
+
 /// Increases the level of all `can_level_up` Overlord cards in a room by 1. If
 /// a Scheme card's level reaches its `level_requirement`, that card is
 /// immediately scored and moved to the Overlord score zone.
@@ -392,6 +715,27 @@ pub fn level_up_room(game: &mut GameState, room_id: RoomId) -> Result<()> {
 pub fn add_level_counters(game: &mut GameState, card_id: CardId, amount: u32) -> Result<()> {
     verify!(flags::can_level_up_card(game, card_id));
     game.card_mut(card_id).data.card_level += amount;
+    check_scheme_scored(game, card_id)
+}
+
+/// Sets the level of the provided card to `level`, e.g. for effects which set
+/// a scheme's level to its maximum.
+///
+/// If the card has scheme points and the new level meets its level
+/// requirement, the card is immediately scored and moved to the Overlord's
+/// score zone.
+///
+/// Returns an error if this card cannot be leveled up.
+pub fn set_card_level(game: &mut GameState, card_id: CardId, level: LevelValue) -> Result<()> {
+    verify!(flags::can_level_up_card(game, card_id));
+    game.card_mut(card_id).data.card_level = level;
+    game.record_update(|| GameUpdate::CardLevelChanged(card_id));
+    check_scheme_scored(game, card_id)
+}
+
+/// Scores the provided card if it has scheme points and its current level
+/// meets its level requirement.
+fn check_scheme_scored(game: &mut GameState, card_id: CardId) -> Result<()> {
     let card = game.card(card_id);
     if let Some(scheme_points) = crate::get(card.name).config.stats.scheme_points {
         if card.data.card_level >= scheme_points.level_requirement {
@@ -471,18 +815,23 @@ pub fn unveil_project_for_free(game: &mut GameState, card_id: CardId) -> Result<
 /// Starts the turn for the `next_side` player.
 fn start_turn(game: &mut GameState, next_side: Side, turn_number: TurnNumber) -> Result<()> {
     game.data.phase = GamePhase::Play;
-    game.data.turn = TurnData { side: next_side, turn_number };
+    let turn = TurnData { side: next_side, turn_number };
+    game.data.turn = turn;
 
     info!(?next_side, "start_player_turn");
-    game.record_update(|| GameUpdate::StartTurn(next_side));
+    game.record_update(|| GameUpdate::TurnChanged(turn));
 
     if next_side == Side::Overlord {
         dispatch::invoke_event(game, DuskEvent(turn_number))?;
     } else {
         dispatch::invoke_event(game, DawnEvent(turn_number))?;
     }
-    game.player_mut(next_side).actions = queries::start_of_turn_action_count(game, next_side);
+    // Actions are granted after the turn's draw so that the client shows the
+    // turn message, then the draw, then the updated action tracker.
     draw_cards(game, next_side, 1)?;
+    let carried = mem::take(&mut game.player_mut(next_side).carried_actions);
+    game.player_mut(next_side).actions =
+        queries::start_of_turn_action_count(game, next_side) + carried;
     Ok(())
 }
 
@@ -524,6 +873,18 @@ pub fn summon_minion(game: &mut GameState, card_id: CardId, costs: SummonMinion)
     Ok(())
 }
 
+/// Deals `amount` damage to the `card_id` minion, reducing its health until the
+/// end of the current turn. The minion is destroyed if its health is reduced
+/// to 0.
+pub fn deal_minion_damage(game: &mut GameState, card_id: CardId, amount: u32) -> Result<()> {
+    game.card_mut(card_id).data.damage += amount;
+    if queries::health(game, card_id) == 0 {
+        game.card_mut(card_id).data.damage = 0;
+        move_card(game, card_id, CardPosition::DiscardPile(card_id.side))?;
+    }
+    Ok(())
+}
+
 /// Deals damage. Discards random card from the hand of the Champion player. If
 /// no cards remain, this player loses the game.
 pub fn deal_damage(game: &mut GameState, source: impl HasAbilityId, amount: u32) -> Result<()> {
@@ -23,13 +23,15 @@ use data::delegates::{
     SanctumAccessCountQuery, ShieldValueQuery, StartOfTurnActionsQuery, VaultAccessCountQuery,
 };
 use data::game::GameState;
-use data::game_actions::{CardTarget, CardTargetKind};
+use data::game_actions::{CardTarget, CardTargetKind, PossibleResponse};
 use data::primitives::{
     AbilityId, ActionCount, AttackValue, BoostCount, BreachValue, CardId, CardType, HealthValue,
     ItemLocation, ManaValue, RoomId, RoomLocation, ShieldValue, Side,
 };
+use with_error::WithError;
 
-use crate::{constants, dispatch};
+use crate::mana::ManaPurpose;
+use crate::{constants, dispatch, flags, mana};
 
 /// Obtain the [CardStats] for a given card
 pub fn stats(game: &GameState, card_id: CardId) -> &CardStats {
@@ -46,16 +48,22 @@ pub fn mana_cost(game: &GameState, card_id: CardId) -> Option<ManaValue> {
     dispatch::perform_query(
         game,
         ManaCostQuery(card_id),
-        crate::get(game.card(card_id).name).cost.mana,
+        crate::get(game.card(card_id).name).cost.mana.value(),
     )
 }
 
+/// Returns the value chosen for X when the `card_id` card was played, or 0 if
+/// no value was chosen. See [data::card_definition::ManaCost::Variable].
+pub fn x_value(game: &GameState, card_id: CardId) -> ManaValue {
+    game.card(card_id).data.x_value.unwrap_or(0)
+}
+
 /// Returns the mana cost for a given ability, if any
 pub fn ability_mana_cost(game: &GameState, ability_id: AbilityId) -> Option<ManaValue> {
     let cost = if let AbilityType::Activated(cost, _) =
         &crate::get(game.card(ability_id.card_id).name).ability(ability_id.index).ability_type
     {
-        cost.mana
+        cost.mana.value()
     } else {
         None
     };
@@ -81,13 +89,15 @@ pub fn attack(game: &GameState, card_id: CardId) -> AttackValue {
     )
 }
 
-/// Returns the health value for a given card, or 0 by default.
+/// Returns the health value for a given card, or 0 by default. Damage dealt to
+/// the card via [crate::mutations::deal_minion_damage] is subtracted.
 pub fn health(game: &GameState, card_id: CardId) -> HealthValue {
     dispatch::perform_query(
         game,
         HealthValueQuery(card_id),
         stats(game, card_id).health.unwrap_or(0),
     )
+    .saturating_sub(game.card(card_id).data.damage)
 }
 
 /// Returns the shield value for a given card, or 0 by default.
@@ -122,6 +132,11 @@ pub fn boost_count(game: &GameState, card_id: CardId) -> BoostCount {
     dispatch::perform_query(game, BoostCountQuery(card_id), game.card(card_id).data.boost_count)
 }
 
+/// Returns the amount of mana currently stored in a given card.
+pub fn stored_mana(game: &GameState, card_id: CardId) -> ManaValue {
+    game.card(card_id).data.stored_mana
+}
+
 /// Returns the amount of mana the owner of `card_id` would need to spend to
 /// raise its [AttackValue] to the provided `target` by activating boosts or
 /// by using other innate abilities, plus the amount of mana required to pay
@@ -146,7 +161,7 @@ pub fn cost_to_defeat_target(
             let increase = target - current;
             // If the boost does not evenly divide into the target, we need to apply it an
             // additional time.
-            let add = if (increase % boost.bonus) == 0 { 0 } else { 1 };
+            let add = if increase.is_multiple_of(boost.bonus) { 0 } else { 1 };
 
             #[allow(clippy::integer_division)] // Deliberate integer truncation
             Some((add + (increase / boost.bonus)) * boost.cost)
@@ -158,6 +173,29 @@ pub fn cost_to_defeat_target(
     result.map(|r| r + (shield(game, target_id).saturating_sub(breach(game, card_id))))
 }
 
+/// Returns the Champion's weapons which can currently defeat the `minion_id`
+/// card, each paired with the mana cost to do so via [cost_to_defeat_target].
+///
+/// Weapons which cannot afford to defeat the minion are excluded. Results are
+/// sorted cheapest-first.
+pub fn weapon_options(game: &GameState, minion_id: CardId) -> Vec<(CardId, ManaValue)> {
+    let mut result = game
+        .weapons()
+        .filter(|weapon| flags::can_defeat_target(game, weapon.id, minion_id))
+        .filter_map(|weapon| {
+            cost_to_defeat_target(game, weapon.id, minion_id).map(|cost| (weapon.id, cost))
+        })
+        .collect::<Vec<_>>();
+    result.sort_by_key(|(weapon_id, cost)| (*cost, *weapon_id));
+    result
+}
+
+/// Returns the number of turns the `side` player has completed in this game,
+/// not including the current turn.
+pub fn turns_taken(game: &GameState, side: Side) -> u32 {
+    game.data.turn_history.iter().filter(|turn| turn.side == side).count() as u32
+}
+
 /// Look up the number of action points a player receives at the start of their
 /// turn
 pub fn start_of_turn_action_count(game: &GameState, side: Side) -> ActionCount {
@@ -178,6 +216,12 @@ pub fn sanctum_access_count(game: &GameState) -> Result<u32> {
     Ok(dispatch::perform_query(game, SanctumAccessCountQuery(raid_id), 1))
 }
 
+/// Cards in the discard pile of the opponent of the `side` player. Discard
+/// piles are public information, so these cards may be referenced by effects.
+pub fn opponent_discard(game: &GameState, side: Side) -> impl Iterator<Item = &CardState> {
+    game.discard_pile(side.opponent())
+}
+
 /// Looks up what type of target a given card requires
 pub fn card_target_kind(game: &GameState, card_id: CardId) -> CardTargetKind {
     let definition = crate::card_definition(game, card_id);
@@ -185,6 +229,7 @@ pub fn card_target_kind(game: &GameState, card_id: CardId) -> CardTargetKind {
         return match targeting {
             TargetRequirement::None => CardTargetKind::None,
             TargetRequirement::TargetRoom(_) => CardTargetKind::Room,
+            TargetRequirement::TargetCard(_) => CardTargetKind::Card,
         };
     }
 
@@ -199,23 +244,26 @@ pub fn card_target_kind(game: &GameState, card_id: CardId) -> CardTargetKind {
 /// such card.
 pub fn highest_cost<'a>(card_iterator: impl Iterator<Item = &'a CardState>) -> Option<CardId> {
     let cards = card_iterator.collect::<Vec<_>>();
-    let max = cards.iter().filter_map(|c| crate::get(c.name).cost.mana).max();
-    let mut filtered =
-        cards.into_iter().filter(|c| crate::get(c.name).cost.mana == max).collect::<Vec<_>>();
+    let max = cards.iter().filter_map(|c| crate::get(c.name).cost.mana.value()).max();
+    let mut filtered = cards
+        .into_iter()
+        .filter(|c| crate::get(c.name).cost.mana.value() == max)
+        .collect::<Vec<_>>();
     filtered.sort();
     filtered.first().map(|c| c.id)
 }
 
 /// Queries the maximum hand size for a player.
 pub fn maximum_hand_size(game: &GameState, side: Side) -> u32 {
-    dispatch::perform_query(game, MaximumHandSizeQuery(side), constants::STARTING_MAXIMUM_HAND_SIZE)
+    let base = game.data.config.maximum_hand_size.unwrap_or(constants::STARTING_MAXIMUM_HAND_SIZE);
+    dispatch::perform_query(game, MaximumHandSizeQuery(side), base)
 }
 
 /// Locates a minion in play, returning its current room and index position
 /// within that room, if any.
 pub fn minion_position(game: &GameState, minion_id: CardId) -> Option<(RoomId, usize)> {
     match game.card(minion_id).position() {
-        CardPosition::Room(room_id, location) if location == RoomLocation::Defender => {
+        CardPosition::Room(room_id, RoomLocation::Defender) => {
             let index = game.defender_list(room_id).iter().position(|cid| *cid == minion_id);
             index.map(|i| (room_id, i))
         }
@@ -223,6 +271,45 @@ pub fn minion_position(game: &GameState, minion_id: CardId) -> Option<(RoomId, u
     }
 }
 
+/// Returns the cards in the `side` player's hand which they can currently
+/// afford to play with at least one legal target, see
+/// [flags::is_currently_playable].
+pub fn affordable_cards_in_hand(game: &GameState, side: Side) -> Vec<CardId> {
+    game.hand(side)
+        .filter(|card| flags::is_currently_playable(game, side, card.id))
+        .map(|card| card.id)
+        .collect()
+}
+
+/// Returns true if the `side` player is in a response window and has an
+/// ability in play which costs no actions and can currently be activated, see
+/// [flags::in_raid_activation_window].
+///
+/// Used to skip response windows in which the player could only pass. Abilities
+/// which cost actions are rejected before their targets are evaluated.
+pub fn can_respond(game: &GameState, side: Side) -> bool {
+    flags::in_raid_activation_window(game, side)
+        && game.cards(side).iter().filter(|card| card.position().in_play()).any(|card| {
+            crate::card_definition(game, card.id).ability_ids(card.id).any(|ability_id| {
+                matches!(
+                    &crate::ability_definition(game, ability_id).ability_type,
+                    AbilityType::Activated(cost, _) if cost.actions == 0
+                ) && flags::activated_ability_has_valid_targets(game, side, ability_id)
+            })
+        })
+}
+
+/// Returns the [RoomLocation] in which a card of the given [CardType] is
+/// placed when played, or None if cards of this type do not enter play in a
+/// room. Minions defend a room, while projects and schemes occupy it.
+pub fn play_location(card_type: CardType) -> Option<RoomLocation> {
+    match card_type {
+        CardType::Minion => Some(RoomLocation::Defender),
+        CardType::Project | CardType::Scheme => Some(RoomLocation::Occupant),
+        _ => None,
+    }
+}
+
 /// Returns the position to which a card should be moved after being played by
 /// the [Side] player with a given [CardTarget].
 pub fn played_position(
@@ -231,14 +318,66 @@ pub fn played_position(
     card_id: CardId,
     target: CardTarget,
 ) -> Result<CardPosition> {
-    Ok(match crate::card_definition(game, card_id).card_type {
+    let card_type = crate::card_definition(game, card_id).card_type;
+    Ok(match card_type {
         CardType::ChampionSpell | CardType::OverlordSpell => CardPosition::DiscardPile(side),
         CardType::Weapon => CardPosition::ArenaItem(ItemLocation::Weapons),
         CardType::Artifact => CardPosition::ArenaItem(ItemLocation::Artifacts),
-        CardType::Minion => CardPosition::Room(target.room_id()?, RoomLocation::Defender),
-        CardType::Project | CardType::Scheme => {
-            CardPosition::Room(target.room_id()?, RoomLocation::Occupant)
-        }
+        CardType::Minion | CardType::Project | CardType::Scheme => CardPosition::Room(
+            target.room_id()?,
+            play_location(card_type).with_error(|| "Expected room location")?,
+        ),
         CardType::Identity => CardPosition::Identity(side),
     })
 }
+
+/// Lists the reactive actions the opponent of the `side` player could
+/// conceivably take, without revealing any information hidden from `side`.
+///
+/// This is intentionally conservative: every hidden card the opponent has in
+/// play is treated as a possible response.
+pub fn possible_opponent_responses(game: &GameState, side: Side) -> Vec<PossibleResponse> {
+    let opponent = side.opponent();
+    let mut result = vec![];
+    for card in game.cards(opponent).iter().filter(|card| card.position().in_play()) {
+        if !card.is_revealed_to(side) {
+            result.push(PossibleResponse::HiddenCard(card.id));
+            continue;
+        }
+
+        for ability_id in crate::card_definition(game, card.id).ability_ids(card.id) {
+            if let AbilityType::Activated(cost, _) =
+                &crate::ability_definition(game, ability_id).ability_type
+            {
+                if cost.actions == 0
+                    && ability_mana_cost(game, ability_id).unwrap_or(0)
+                        <= mana::get(game, opponent, ManaPurpose::ActivateAbility(ability_id))
+                {
+                    result.push(PossibleResponse::ActivateAbility(ability_id));
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Returns true if any card in `after` is revealed to a player it was not
+/// revealed to in `before`, an earlier state of the same game.
+pub fn reveals_hidden_information(before: &GameState, after: &GameState) -> bool {
+    after.all_cards().any(|card| {
+        let previous = before.cards(card.side()).get(card.id.index);
+        enum_iterator::all::<Side>().any(|viewer| {
+            card.is_revealed_to(viewer)
+                && previous.is_some_and(|previous| !previous.is_revealed_to(viewer))
+        })
+    })
+}
+
+/// Returns the number of the `side` player's most recent actions which can be
+/// reverted one after another via [data::game_actions::UserAction::Undo].
+pub fn available_undo_count(game: &GameState, side: Side) -> u32 {
+    if !flags::can_undo(game, side) {
+        return 0;
+    }
+    game.undo_history.iter().rev().take_while(|step| step.side == side).count() as u32
+}
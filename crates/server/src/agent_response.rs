@@ -14,32 +14,34 @@
 
 //! Functions  for providing AI responses to the user
 
-use std::sync::atomic::{AtomicBool, Ordering};
-
 use ai_core::agent::{Agent, AgentConfig};
 use ai_game_integration::agents;
 use ai_game_integration::state_node::SpelldawnState;
 use anyhow::Result;
 use concurrent_queue::ConcurrentQueue;
+use dashmap::DashSet;
 use data::game::GameState;
 use data::player_data;
 use data::player_name::{NamedPlayer, PlayerId};
 use data::primitives::{GameId, Side};
 use once_cell::sync::Lazy;
 use protos::spelldawn::{CommandList, GameRequest};
+use tracing::info;
 use with_error::fail;
 
 use crate::database::Database;
 use crate::requests;
 
-// This feels safe-ish?
-static AGENT_RUNNING: AtomicBool = AtomicBool::new(false);
+/// Games in which an agent is currently running. At most one agent loop runs
+/// for a given game at a time.
+static RUNNING_AGENTS: Lazy<DashSet<GameId>> = Lazy::new(DashSet::new);
 
 /// Queue of agent responses that need to be sent to the client, used in offline
 /// mode
 pub static RESPONSES: Lazy<ConcurrentQueue<CommandList>> = Lazy::new(ConcurrentQueue::unbounded);
 
 /// What to do with responses produced by the agent.
+#[derive(Debug, Clone, Copy)]
 pub enum HandleRequest {
     /// Send each response to the the player who initiated the `GameRequest`.
     SendToPlayer,
@@ -48,6 +50,15 @@ pub enum HandleRequest {
     PushQueue,
 }
 
+/// Discards all responses in the [RESPONSES] queue.
+///
+/// Queued responses animate changes relative to the client's previous game
+/// view, so they are stale once a client reconnects and receives a full sync
+/// of the current game state.
+pub fn discard_responses() {
+    while RESPONSES.pop().is_ok() {}
+}
+
 pub fn handle_request(
     mut database: impl Database + 'static,
     request: &GameRequest,
@@ -60,17 +71,45 @@ pub fn handle_request(
     };
     let game = database.game(game_id)?;
 
-    if active_agent(&game).is_some() && !AGENT_RUNNING.swap(true, Ordering::Relaxed) {
+    if active_agent(&game).is_some() && RUNNING_AGENTS.insert(game_id) {
         tokio::spawn(async move {
-            run_agent_loop(database, game_id, respond_to, handle_request)
+            run_agent_loop(&mut database, game_id, respond_to, handle_request)
                 .await
                 .expect("Error running agent");
-            AGENT_RUNNING.store(false, Ordering::Relaxed);
+            RUNNING_AGENTS.remove(&game_id);
         });
     }
     Ok(())
 }
 
+/// Resumes agents which were waiting to act when the server was last shut down.
+///
+/// Agents normally only run in response to a request from a human player, so
+/// this should be invoked on server startup to avoid stalling games where an
+/// AI player is next to act. Responses are sent to the opponent of the agent
+/// player.
+pub async fn resume_agents(
+    database: &mut impl Database,
+    handle_request: HandleRequest,
+) -> Result<()> {
+    for game_id in database.game_ids()? {
+        let game = database.game(game_id)?;
+        let agent_side = active_agent(&game).map(|(side, _)| side);
+        if let Some(side) = agent_side {
+            if !RUNNING_AGENTS.insert(game_id) {
+                // An agent was already started for this game by a request.
+                continue;
+            }
+            info!(?game_id, ?side, "resume_agent");
+            let respond_to = game.player(side.opponent()).id;
+            let result = run_agent_loop(database, game_id, respond_to, handle_request).await;
+            RUNNING_AGENTS.remove(&game_id);
+            result?;
+        }
+    }
+    Ok(())
+}
+
 /// Returns a ([Side], [AgentData]) tuple for an agent that can currently act in
 /// this game, if one exists.
 fn active_agent(game: &GameState) -> Option<(Side, Box<dyn Agent<SpelldawnState>>)> {
@@ -85,21 +124,20 @@ fn active_agent(game: &GameState) -> Option<(Side, Box<dyn Agent<SpelldawnState>
 }
 
 async fn run_agent_loop(
-    mut database: impl Database,
+    database: &mut impl Database,
     game_id: GameId,
     respond_to: PlayerId,
     handle_request: HandleRequest,
 ) -> Result<()> {
     loop {
-        let game = SpelldawnState(database.game(game_id)?);
+        let mut game = SpelldawnState(database.game(game_id)?);
         let commands = if let Some((side, agent)) = active_agent(&game) {
-            let action = agent.pick_action(AgentConfig::with_deadline(3), &game)?;
-            let response = requests::handle_action(
-                &mut database,
-                game.player(side).id,
-                Some(game_id),
-                action,
-            )?;
+            let config = AgentConfig::with_time_budget(agent.time_budget());
+            let action = agent.pick_action(config, &game)?;
+            agent.record_action(&mut game, side, action)?;
+            database.write_game(&game)?;
+            let response =
+                requests::handle_action(database, game.player(side).id, Some(game_id), action)?;
 
             match response.opponent_response {
                 Some((oid, response)) if oid == respond_to => response,
@@ -115,6 +153,7 @@ async fn run_agent_loop(
         match handle_request {
             HandleRequest::SendToPlayer => {
                 requests::send_player_response(Some((respond_to, commands))).await;
+                requests::update_spectators(database, game_id).await;
             }
             HandleRequest::PushQueue => {
                 RESPONSES.push(commands)?;
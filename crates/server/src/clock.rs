@@ -0,0 +1,34 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Injectable source of the current time, used by game rules which depend on
+//! how long a player has taken to respond.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Provides the current time to the server.
+pub trait Clock: Send + Sync {
+    /// Returns the time elapsed since the UNIX epoch.
+    fn now(&self) -> Duration;
+}
+
+/// [Clock] implementation which reads the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+    }
+}
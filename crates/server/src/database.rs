@@ -14,13 +14,20 @@
 
 //! Core database implementation, handles querying and storing game state.
 
-use std::sync::Mutex;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
+use cards::decklists;
+use dashmap::DashMap;
+use data::deck::Deck;
 use data::game::GameState;
 use data::player_data::PlayerData;
 use data::player_name::PlayerId;
-use data::primitives::GameId;
+use data::primitives::{DeckId, GameId};
 use once_cell::sync::Lazy;
 use prost::Message;
 use protos::spelldawn::player_identifier::PlayerIdentifierType;
@@ -28,8 +35,12 @@ use protos::spelldawn::PlayerIdentifier;
 use rules::dispatch;
 use serde_json::{de, ser};
 use sled::{Db, Tree};
+use tracing::error;
 use with_error::WithError;
 
+use crate::clock::{Clock, SystemClock};
+use crate::migrations;
+
 static DATABASE_PATH: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
 
 static DATABASE: Lazy<Db> = Lazy::new(|| {
@@ -44,6 +55,26 @@ pub fn override_path(path: String) {
     DATABASE_PATH.lock().expect("path lock").replace(path);
 }
 
+/// Error returned when a stored game cannot be deserialized, e.g. due to data
+/// corruption or a schema change which was made without a migration.
+///
+/// The stored record is quarantined when this error is produced, so subsequent
+/// lookups treat the game as missing rather than failing repeatedly.
+#[derive(Debug)]
+pub struct CorruptedGameError {
+    pub game_id: GameId,
+    /// Underlying deserialization error
+    pub error: anyhow::Error,
+}
+
+impl Display for CorruptedGameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {:?} is corrupted and cannot be loaded: {:#}", self.game_id, self.error)
+    }
+}
+
+impl Error for CorruptedGameError {}
+
 /// Abstraction layer for interacting with the database
 pub trait Database: Send + Sync {
     /// Generate a new unique [GameId] to be used for a new game
@@ -52,6 +83,39 @@ pub trait Database: Send + Sync {
     /// Check whether a given game exists.
     fn has_game(&self, id: GameId) -> Result<bool>;
 
+    /// Returns the IDs of all games stored in the database.
+    fn game_ids(&self) -> Result<Vec<GameId>>;
+
+    /// Returns the IDs of all stored games in which the `player_id` player is
+    /// a participant.
+    fn games_for_player(&self, player_id: PlayerId) -> Result<Vec<GameId>> {
+        let mut result = vec![];
+        for game_id in self.game_ids()? {
+            let game = self.game(game_id)?;
+            if game.overlord.id == player_id || game.champion.id == player_id {
+                result.push(game_id);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Looks up the [Deck] with the given [DeckId] for the `player_id` player.
+    ///
+    /// Players who have no saved decks are given a starter deck via
+    /// [decklists::starter_deck], so that new players can immediately play.
+    fn deck(&self, player_id: PlayerId, deck_id: DeckId) -> Result<Deck> {
+        let player = self.player(player_id)?.with_error(|| "Player not found")?;
+        if player.decks.is_empty() {
+            decklists::starter_deck(player_id, deck_id)
+                .with_error(|| format!("No starter deck for {:?}", deck_id))
+        } else {
+            Ok(player
+                .find_deck(deck_id)
+                .with_error(|| format!("Deck not found {:?}", deck_id))?
+                .clone())
+        }
+    }
+
     /// Look up an ongoing [GameState] by ID. It is an error to look up an ID
     /// which does not exist.
     fn game(&self, id: GameId) -> Result<GameState>;
@@ -59,10 +123,14 @@ pub trait Database: Send + Sync {
     /// Store a [GameState] in the database based on its ID.
     fn write_game(&mut self, game: &GameState) -> Result<()>;
 
+    /// Removes a game from the database. Does nothing if no game exists with
+    /// this ID.
+    fn delete_game(&mut self, id: GameId) -> Result<()>;
+
+    /// Check whether a given player exists.
+    fn has_player(&self, player_id: PlayerId) -> Result<bool>;
+
     /// Retrieve a player's [PlayerData], if this player exists.
-    ///
-    /// It is always an error to invoke this method with a `PlayerId::Named`
-    /// identifier.
     fn player(&self, player_id: PlayerId) -> Result<Option<PlayerData>>;
 
     /// Store a [PlayerData] in the database based on its ID.
@@ -72,6 +140,11 @@ pub trait Database: Send + Sync {
     /// existing mapping or storing a new randomly-generated ID for this
     /// identifier.
     fn adapt_player_identifier(&mut self, identifier: &PlayerIdentifier) -> Result<PlayerId>;
+
+    /// Source of the current time for time-dependent game rules.
+    fn clock(&self) -> &dyn Clock {
+        &SystemClock
+    }
 }
 
 /// Database implementation based on the sled database
@@ -94,20 +167,27 @@ impl Database for SledDatabase {
         games()?.contains_key(id.key()).with_error(|| format!("Error reading key {:?}", id))
     }
 
+    fn game_ids(&self) -> Result<Vec<GameId>> {
+        games()?
+            .iter()
+            .keys()
+            .map(|key| {
+                let key = key.with_error(|| "Error reading game key")?;
+                Ok(GameId::new(u64::from_be_bytes(key.as_ref().try_into()?)))
+            })
+            .collect()
+    }
+
     fn game(&self, id: GameId) -> Result<GameState> {
-        let content = games()?
-            .get(id.key())
-            .with_error(|| format!("Error reading  game: {:?}", id))?
-            .with_error(|| format!("Game not found: {:?}", id))?;
-        let mut game = de::from_slice(content.as_ref())
-            .with_error(|| format!("Error deserializing game {:?}", id))?;
-        dispatch::populate_delegate_cache(&mut game);
-        Ok(game)
+        let result = read_game(&games()?, &quarantined_games()?, id);
+        if self.flush_on_write && result.as_ref().is_err_and(|e| e.is::<CorruptedGameError>()) {
+            DATABASE.flush()?;
+        }
+        result
     }
 
     fn write_game(&mut self, game: &GameState) -> Result<()> {
-        let serialized =
-            ser::to_vec(game).with_error(|| format!("Error serializing game {:?}", game.id))?;
+        let serialized = migrations::serialize_game(game)?;
         let result = games()?
             .insert(game.id.key(), serialized)
             .map(|_| ()) // Ignore previously-set value
@@ -120,11 +200,29 @@ impl Database for SledDatabase {
         result
     }
 
+    fn delete_game(&mut self, id: GameId) -> Result<()> {
+        let result = games()?
+            .remove(id.key())
+            .map(|_| ()) // Ignore previously-set value
+            .with_error(|| format!("Error deleting game {:?}", id));
+
+        if self.flush_on_write {
+            DATABASE.flush()?;
+        }
+
+        result
+    }
+
+    fn has_player(&self, player_id: PlayerId) -> Result<bool> {
+        let (tree, key) = player_location(player_id)?;
+        tree.contains_key(key).with_error(|| format!("Error reading key {:?}", player_id))
+    }
+
     fn player(&self, player_id: PlayerId) -> Result<Option<PlayerData>> {
+        let (tree, key) = player_location(player_id)?;
         Ok(
-            if let Some(content) = players()?
-                .get(player_id.database_key()?)
-                .with_error(|| format!("Error reading player: {:?}", player_id))?
+            if let Some(content) =
+                tree.get(key).with_error(|| format!("Error reading player: {:?}", player_id))?
             {
                 de::from_slice(content.as_ref())
                     .with_error(|| format!("Error deserializing player {:?}", player_id))?
@@ -137,8 +235,9 @@ impl Database for SledDatabase {
     fn write_player(&mut self, player: &PlayerData) -> Result<()> {
         let serialized = ser::to_vec(player)
             .with_error(|| format!("Error serializing player {:?}", player.id))?;
-        let result = players()?
-            .insert(player.id.database_key()?, serialized)
+        let (tree, key) = player_location(player.id)?;
+        let result = tree
+            .insert(key, serialized)
             .map(|_| ()) // Ignore previously-set value
             .with_error(|| format!("Error writing player {:?}", player.id));
 
@@ -168,14 +267,135 @@ impl Database for SledDatabase {
     }
 }
 
+/// Database implementation which keeps all data in memory, e.g. in order to
+/// run simulated games without creating a sled database on disk.
+///
+/// Clones share the same underlying storage.
+#[derive(Clone, Default)]
+pub struct MemoryDatabase {
+    games: Arc<DashMap<GameId, GameState>>,
+    players: Arc<DashMap<PlayerId, PlayerData>>,
+    player_ids: Arc<DashMap<Vec<u8>, PlayerId>>,
+    last_id: Arc<AtomicU64>,
+}
+
+impl MemoryDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn generate_id(&self) -> u64 {
+        self.last_id.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+impl Database for MemoryDatabase {
+    fn generate_game_id(&self) -> Result<GameId> {
+        Ok(GameId::new(self.generate_id()))
+    }
+
+    fn has_game(&self, id: GameId) -> Result<bool> {
+        Ok(self.games.contains_key(&id))
+    }
+
+    fn game_ids(&self) -> Result<Vec<GameId>> {
+        Ok(self.games.iter().map(|entry| *entry.key()).collect())
+    }
+
+    fn game(&self, id: GameId) -> Result<GameState> {
+        Ok(self.games.get(&id).with_error(|| format!("Game not found: {:?}", id))?.clone())
+    }
+
+    fn write_game(&mut self, game: &GameState) -> Result<()> {
+        self.games.insert(game.id, game.clone());
+        Ok(())
+    }
+
+    fn delete_game(&mut self, id: GameId) -> Result<()> {
+        self.games.remove(&id);
+        Ok(())
+    }
+
+    fn has_player(&self, player_id: PlayerId) -> Result<bool> {
+        Ok(self.players.contains_key(&player_id))
+    }
+
+    fn player(&self, player_id: PlayerId) -> Result<Option<PlayerData>> {
+        Ok(self.players.get(&player_id).map(|player| player.clone()))
+    }
+
+    fn write_player(&mut self, player: &PlayerData) -> Result<()> {
+        self.players.insert(player.id, player.clone());
+        Ok(())
+    }
+
+    fn adapt_player_identifier(&mut self, identifier: &PlayerIdentifier) -> Result<PlayerId> {
+        if let Some(PlayerIdentifierType::ServerIdentifier(bytes)) =
+            &identifier.player_identifier_type
+        {
+            return adapters::named_player_id(bytes);
+        }
+
+        Ok(*self
+            .player_ids
+            .entry(identifier.encode_to_vec())
+            .or_insert_with(|| PlayerId::Database(self.generate_id())))
+    }
+}
+
+/// Reads the [GameState] with the given `id` from the `games` table.
+///
+/// If the stored record cannot be deserialized, it is moved to the
+/// `quarantine` table for later inspection and a [CorruptedGameError] is
+/// returned.
+pub fn read_game(games: &Tree, quarantine: &Tree, id: GameId) -> Result<GameState> {
+    let content = games
+        .get(id.key())
+        .with_error(|| format!("Error reading  game: {:?}", id))?
+        .with_error(|| format!("Game not found: {:?}", id))?;
+    match migrations::deserialize_game(content.as_ref()) {
+        Ok(mut game) => {
+            dispatch::populate_delegate_cache(&mut game);
+            Ok(game)
+        }
+        Err(error) => {
+            error!(?id, ?error, "quarantine_corrupted_game");
+            quarantine
+                .insert(id.key(), content)
+                .with_error(|| format!("Error quarantining game {:?}", id))?;
+            games.remove(id.key()).with_error(|| format!("Error removing game {:?}", id))?;
+            Err(CorruptedGameError { game_id: id, error }.into())
+        }
+    }
+}
+
 fn games() -> Result<Tree> {
     DATABASE.open_tree("games").with_error(|| "Error opening the 'games' table")
 }
 
+fn quarantined_games() -> Result<Tree> {
+    DATABASE
+        .open_tree("quarantined_games")
+        .with_error(|| "Error opening the 'quarantined_games' table")
+}
+
 fn players() -> Result<Tree> {
     DATABASE.open_tree("players").with_error(|| "Error opening the 'players' table")
 }
 
+fn named_players() -> Result<Tree> {
+    DATABASE.open_tree("named_players").with_error(|| "Error opening the 'named_players' table")
+}
+
+/// Returns the table and key used to store data for the `player_id` player.
+/// Named AI players are stored in their own table, keyed by name.
+fn player_location(player_id: PlayerId) -> Result<(Tree, Vec<u8>)> {
+    Ok(match player_id {
+        PlayerId::Database(_) => (players()?, player_id.database_key()?.to_vec()),
+        PlayerId::Named(name) => (named_players()?, name.to_string().into_bytes()),
+    })
+}
+
 fn player_ids() -> Result<Tree> {
     DATABASE.open_tree("player_ids").with_error(|| "Error opening the 'player_ids' table")
 }
@@ -19,9 +19,9 @@ use anyhow::Result;
 use cards::decklists;
 use data::game::GameState;
 use data::game_actions::DebugAction;
-use data::player_data::{CurrentGame, PlayerData};
+use data::player_data::{CurrentGame, PlayerCosmetics, PlayerData};
 use data::player_name::{NamedPlayer, PlayerId};
-use data::primitives::{DeckId, GameId, Side};
+use data::primitives::{DeckId, GameId, Locale, Side};
 use protos::spelldawn::client_debug_command::DebugCommand;
 use protos::spelldawn::game_action::Action;
 use protos::spelldawn::game_command::Command;
@@ -29,12 +29,12 @@ use protos::spelldawn::{
     ClientDebugCommand, CommandList, GameAction, GameCommand, GameIdentifier, LoadSceneCommand,
     NewGameAction, NewGameDebugOptions, SceneLoadMode,
 };
-use rules::mana;
-use with_error::WithError;
+use rules::{dispatch, mana, mutations};
+use with_error::{verify, WithError};
 
 use crate::database::Database;
-use crate::requests;
 use crate::requests::GameResponse;
+use crate::{migrations, requests};
 
 pub fn handle_debug_action(
     database: &mut impl Database,
@@ -106,8 +106,7 @@ pub fn handle_debug_action(
         }
         DebugAction::AddScore(amount) => {
             requests::handle_custom_action(database, player_id, game_id, |game, user_side| {
-                game.player_mut(user_side).score += amount;
-                Ok(())
+                mutations::gain_points(game, user_side, amount)
             })
         }
         DebugAction::SaveState(index) => {
@@ -131,9 +130,40 @@ pub fn handle_debug_action(
                 Ok(())
             })
         }
+        DebugAction::SetResources { side, mana, actions, score } => {
+            verify!(cfg!(debug_assertions), "SetResources is not available in release builds");
+            requests::handle_custom_action(database, player_id, game_id, |game, _| {
+                mana::set(game, side, mana);
+                game.player_mut(side).actions = actions;
+                game.player_mut(side).score = score;
+                Ok(())
+            })
+        }
     }
 }
 
+/// Serializes the game with the provided [GameId] to pretty-printed JSON, e.g.
+/// in order to attach it to a bug report.
+///
+/// The output uses the same versioned envelope as stored games, so exports can
+/// still be imported after later schema changes.
+pub fn export_game(database: &impl Database, game_id: GameId) -> Result<String> {
+    migrations::serialize_game_pretty(&database.game(game_id)?)
+}
+
+/// Parses a game previously produced by [export_game] and writes it to the
+/// database under a newly-generated [GameId], which is returned.
+///
+/// Transient state which is not serialized, such as the update tracker and
+/// delegate cache, is rebuilt.
+pub fn import_game(database: &mut impl Database, json: &str) -> Result<GameId> {
+    let mut game = migrations::deserialize_game(json.as_bytes())?;
+    game.id = database.generate_game_id()?;
+    dispatch::populate_delegate_cache(&mut game);
+    database.write_game(&game)?;
+    Ok(game.id)
+}
+
 fn write_default_player(
     database: &mut impl Database,
     player_id: PlayerId,
@@ -147,6 +177,11 @@ fn write_default_player(
             decklists::canonical_deck(player_id, Side::Champion),
         ],
         collection: HashMap::default(),
+        cosmetics: PlayerCosmetics::default(),
+        sideboard: HashMap::default(),
+        current_match: None,
+        locale: Locale::default(),
+        pending_invitations: vec![],
     })
 }
 
@@ -15,6 +15,10 @@
 //! Crate for operating the GRPC sever and handling top-level client requests.
 
 pub mod agent_response;
+pub mod clock;
 pub mod database;
 pub mod debug;
+pub mod maintenance;
+pub mod migrations;
 pub mod requests;
+pub mod rest;
@@ -0,0 +1,113 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Periodic cleanup tasks for server storage and game timers.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use data::game::{GamePhase, GameState};
+use data::player_data::CurrentGame;
+use data::player_name::PlayerId;
+use data::primitives::GameId;
+use protos::spelldawn::CommandList;
+use tracing::{info, warn};
+
+use crate::database::{CorruptedGameError, Database};
+use crate::requests;
+
+/// Default time to keep finished games before [sweep_finished_games] deletes
+/// them.
+pub const DEFAULT_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Deletes games which ended more than `retention` ago, returning the IDs of
+/// the deleted games.
+///
+/// Games where either player still has a live channel are skipped, so this is
+/// safe to run while games are in progress. Finished games which do not yet
+/// have a completion time are stamped with the current time and will be
+/// removed by a later sweep. Corrupted games are logged and skipped.
+pub fn sweep_finished_games(
+    database: &mut impl Database,
+    retention: Duration,
+) -> Result<Vec<GameId>> {
+    let now = database.clock().now();
+    let mut deleted = vec![];
+    for game_id in database.game_ids()? {
+        let mut game = match database.game(game_id) {
+            Ok(game) => game,
+            Err(error) if error.is::<CorruptedGameError>() => {
+                // The corrupted record has been quarantined, so it will not be
+                // read again by later sweeps.
+                warn!(?game_id, ?error, "sweep_skipped_corrupted_game");
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
+        if !matches!(game.data.phase, GamePhase::GameOver { .. }) || has_live_player(&game) {
+            continue;
+        }
+
+        match game.data.completed_at {
+            None => {
+                requests::record_completion(&mut game, now);
+                database.write_game(&game)?;
+            }
+            Some(completed_at) if now.saturating_sub(completed_at) >= retention => {
+                delete_game(database, &game)?;
+                deleted.push(game_id);
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(deleted)
+}
+
+/// Applies expired timers to every game via [requests::handle_timeouts],
+/// returning the updates to send to players of games which changed.
+///
+/// Errors are logged and the game is skipped so that one bad game does not
+/// stop timers from expiring in all other games.
+pub fn expire_timers(database: &mut impl Database) -> Result<Vec<(PlayerId, CommandList)>> {
+    let mut responses = vec![];
+    for game_id in database.game_ids()? {
+        match requests::handle_timeouts(database, game_id) {
+            Ok(game_responses) => responses.extend(game_responses),
+            Err(error) => warn!(?game_id, ?error, "expire_timers_error"),
+        }
+    }
+    Ok(responses)
+}
+
+fn has_live_player(game: &GameState) -> bool {
+    requests::has_live_channel(game.overlord.id) || requests::has_live_channel(game.champion.id)
+}
+
+/// Deletes a game and removes any remaining references to it from its
+/// players.
+fn delete_game(database: &mut impl Database, game: &GameState) -> Result<()> {
+    info!(?game.id, "delete_finished_game");
+    for player_id in [game.overlord.id, game.champion.id] {
+        requests::remove_closed_channel(player_id);
+        if let Some(mut player) = database.player(player_id)? {
+            if matches!(player.current_game, Some(CurrentGame::Playing(id)) if id == game.id) {
+                player.current_game = None;
+                database.write_player(&player)?;
+            }
+        }
+    }
+
+    database.delete_game(game.id)
+}
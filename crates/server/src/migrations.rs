@@ -0,0 +1,154 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Versioned serialization for [GameState].
+//!
+//! Games are stored inside a small envelope which records the schema version
+//! they were written with. When an older payload is read, the migrations below
+//! are applied in sequence to upgrade it to the current shape before it is
+//! deserialized.
+
+use anyhow::Result;
+use data::game::GameState;
+use serde_json::{json, Map, Value};
+use with_error::{fail, WithError};
+
+/// Schema version written by [serialize_game].
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// Upgrades a payload from version `index + 1` to version `index + 2`.
+const MIGRATIONS: &[fn(&mut Value) -> Result<()>] = &[migrate_v1];
+
+/// Serializes a game in the current schema version, wrapped in an envelope of
+/// the form `{"version": u32, "game": {...}}`.
+pub fn serialize_game(game: &GameState) -> Result<Vec<u8>> {
+    serde_json::to_vec(&envelope(game)?)
+        .with_error(|| format!("Error serializing game {:?}", game.id))
+}
+
+/// Equivalent to [serialize_game] which produces human-readable JSON.
+pub fn serialize_game_pretty(game: &GameState) -> Result<String> {
+    serde_json::to_string_pretty(&envelope(game)?)
+        .with_error(|| format!("Error serializing game {:?}", game.id))
+}
+
+fn envelope(game: &GameState) -> Result<Value> {
+    let value = serde_json::to_value(game)
+        .with_error(|| format!("Error serializing game {:?}", game.id))?;
+    Ok(json!({ "version": CURRENT_SCHEMA_VERSION, "game": value }))
+}
+
+/// Deserializes a game written by any schema version, running migrations on it
+/// as needed.
+///
+/// Payloads without an envelope are treated as version 1, the format used
+/// before versioning was introduced.
+pub fn deserialize_game(bytes: &[u8]) -> Result<GameState> {
+    let value: Value = serde_json::from_slice(bytes).with_error(|| "Error parsing game")?;
+    let (version, mut game) = match value {
+        Value::Object(mut map) if map.contains_key("version") && map.contains_key("game") => {
+            let version = map
+                .get("version")
+                .and_then(Value::as_u64)
+                .with_error(|| "Invalid game schema version")?;
+            (u32::try_from(version)?, map.remove("game").with_error(|| "Expected game")?)
+        }
+        value => (1, value),
+    };
+
+    if version == 0 || version > CURRENT_SCHEMA_VERSION {
+        fail!("Unsupported game schema version {}", version);
+    }
+
+    for migration in &MIGRATIONS[(version as usize - 1)..] {
+        migration(&mut game)?;
+    }
+
+    serde_json::from_value(game).with_error(|| "Error deserializing game")
+}
+
+fn game_object(game: &mut Value) -> Result<&mut Map<String, Value>> {
+    game.as_object_mut().with_error(|| "Expected game to be an object")
+}
+
+/// Inserts each `(key, value)` pair into `object` if `object` is a JSON object
+/// which does not already contain that key.
+fn insert_missing(object: Option<&mut Value>, fields: &[(&str, Value)]) {
+    if let Some(Value::Object(map)) = object {
+        for (key, value) in fields {
+            map.entry(*key).or_insert_with(|| value.clone());
+        }
+    }
+}
+
+/// Version 2 added player cosmetics and restricted mana, per-card damage, X
+/// values, and token status, new game configuration options, multi-room raids,
+/// mulligan selections and timers, and the turn history, timer, and log.
+fn migrate_v1(game: &mut Value) -> Result<()> {
+    let game = game_object(game)?;
+    for side in ["overlord", "champion"] {
+        let player = game.get_mut(side);
+        insert_missing(
+            player,
+            &[("cosmetics", json!({ "card_back": null, "portrait_frame": null }))],
+        );
+        let mana_state = game.get_mut(side).and_then(|player| player.get_mut("mana_state"));
+        insert_missing(mana_state, &[("restricted_mana", json!([]))]);
+    }
+
+    for cards in ["overlord_cards", "champion_cards"] {
+        for card in game.get_mut(cards).and_then(Value::as_array_mut).into_iter().flatten() {
+            insert_missing(
+                card.get_mut("data"),
+                &[("x_value", Value::Null), ("damage", json!(0)), ("is_token", json!(false))],
+            );
+        }
+    }
+
+    let data = game.get_mut("data").with_error(|| "Expected game data")?;
+    insert_missing(
+        data.get_mut("config"),
+        &[
+            ("seed", Value::Null),
+            ("carry_over_actions", Value::Null),
+            ("disclose_on_game_over", json!(false)),
+            ("mulligan_timeout", Value::Null),
+            ("turn_timer", Value::Null),
+            ("prompt_timeout", Value::Null),
+            ("mulligan_hand_size", Value::Null),
+            ("mulligan_draw_size", Value::Null),
+            ("maximum_hand_size", Value::Null),
+        ],
+    );
+    insert_missing(
+        data.get_mut("raid"),
+        &[("queued_rooms", json!([])), ("current_room", Value::Null)],
+    );
+    insert_missing(
+        data.get_mut("phase").and_then(|phase| phase.get_mut("ResolveMulligans")),
+        &[("started_at", Value::Null), ("selected_cards", json!([]))],
+    );
+    insert_missing(
+        Some(data),
+        &[
+            ("extra_turns", json!([])),
+            ("turn_history", json!([])),
+            ("turn_timer", Value::Null),
+            ("completed_at", Value::Null),
+            ("prompt_shown_at", Value::Null),
+            ("log", json!([])),
+        ],
+    );
+    Ok(())
+}
@@ -14,50 +14,77 @@
 
 //! Top-level server request handling
 
-use actions;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use actions::{self, legal_actions};
+use adapters::response_builder::ResponseOptions;
 use adapters::ServerCardId;
 use anyhow::Result;
 use cards::decklists;
 use dashmap::DashMap;
 use data::deck::Deck;
-use data::game::{GameConfiguration, GameState};
-use data::game_actions::UserAction;
-use data::player_data::{CurrentGame, NewGameRequest};
+use data::game::{GameConfiguration, GamePhase, GameState};
+use data::game_actions::{PossibleResponse, UserAction, USER_ACTION_PAYLOAD_VERSION};
+use data::player_data::{
+    CurrentGame, GameInvitation, MatchData, PlayerCosmetics, PlayerData, SideboardSwap,
+};
 use data::player_name::PlayerId;
-use data::primitives::{GameId, Side};
+use data::primitives::{DeckId, GameId, Locale, Side};
 use data::updates::{UpdateTracker, Updates};
 use data::{game_actions, player_data};
 use display::render;
 use once_cell::sync::Lazy;
 use protos::spelldawn::game_action::Action;
 use protos::spelldawn::game_command::Command;
+use protos::spelldawn::game_object_identifier::Id;
+use protos::spelldawn::object_position::Position;
 use protos::spelldawn::spelldawn_server::Spelldawn;
 use protos::spelldawn::{
-    card_target, CardTarget, CommandList, ConnectRequest, GameCommand, GameRequest,
-    LoadSceneCommand, NewGameAction, PlayerIdentifier, SceneLoadMode, StandardAction,
+    card_target, CardIdentifier, CardTarget, CommandList, ConnectRequest, GameCommand,
+    GameIdentifier, GameRequest, LoadSceneCommand, NewGameAction, PlayerIdentifier, SceneLoadMode,
+    StandardAction,
 };
-use rules::{dispatch, mutations};
-use serde_json::de;
+use rules::{dispatch, mutations, queries};
+use serde_json::{de, ser};
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::Sender;
 use tokio_stream::wrappers::ReceiverStream;
-use tonic::{Request, Response, Status};
+use tonic::codegen::Bytes;
+use tonic::{Code, Request, Response, Status};
 use tracing::{error, info, warn, warn_span};
 use with_error::{fail, verify, WithError};
 
 use crate::agent_response::HandleRequest;
-use crate::database::{Database, SledDatabase};
+use crate::database::{CorruptedGameError, Database, SledDatabase};
 use crate::{agent_response, debug};
 
 /// Stores active channels for each user.
 ///
-/// TODO: Clean this up on disconnect. This is quite easy to do with 'real' gRPC
-/// but I haven't figured out how to do it with gRPC-web (which is just
-/// fake-streaming over HTTP1). Unity doesn't support HTTP2 natively, but it's
-/// possible to do it via a third party networking stack.
+/// gRPC-web (which is just fake-streaming over HTTP1) does not tell us when a
+/// client goes away, so entries are removed either explicitly via
+/// [disconnect] or the first time a send to a closed channel fails in
+/// [send_player_response].
 static CHANNELS: Lazy<DashMap<PlayerId, Sender<Result<CommandList, Status>>>> =
     Lazy::new(DashMap::new);
 
+/// Stores read-only channels for players spectating each game, see
+/// [register_spectator].
+static SPECTATORS: Lazy<DashMap<GameId, Vec<Spectator>>> = Lazy::new(DashMap::new);
+
+/// A connected player observing a game they are not participating in.
+#[derive(Clone)]
+struct Spectator {
+    player_id: PlayerId,
+    locale: Locale,
+    sender: Sender<Result<CommandList, Status>>,
+}
+
 pub type ResponseInterceptor = fn(&CommandList);
 
 /// Struct which implements our GRPC service
@@ -83,7 +110,11 @@ impl Spelldawn for GameService {
 
         let (tx, rx) = mpsc::channel(4);
 
-        let result = handle_connect(&mut db, player_id);
+        let spectate = match spectate_game_id(message) {
+            Ok(spectate) => spectate,
+            Err(error) => return Err(Status::invalid_argument(format!("{:#}", error))),
+        };
+        let result = handle_connect(&mut db, player_id, spectate, adapters::locale(message.locale));
         match result {
             Ok(commands) => {
                 let names = commands.commands.iter().map(command_name).collect::<Vec<_>>();
@@ -96,11 +127,21 @@ impl Spelldawn for GameService {
             }
             Err(error) => {
                 error!(?player_id, ?error, "Connection Error!");
+                if error.is::<CorruptedGameError>() {
+                    return Err(error_status(&error));
+                }
                 return Err(Status::internal(format!("Connection Error: {:#}", error)));
             }
         }
 
-        CHANNELS.insert(player_id, tx);
+        match spectate {
+            // Spectators are registered separately so that they receive redacted
+            // updates for the game they are observing and can never act in it.
+            Some(game_id) => {
+                register_spectator(game_id, player_id, adapters::locale(message.locale), tx)
+            }
+            None => register_channel(player_id, tx),
+        }
         Ok(Response::new(ReceiverStream::new(rx)))
     }
 
@@ -117,6 +158,7 @@ impl Spelldawn for GameService {
                 }
 
                 send_player_response(response.opponent_response).await;
+                update_spectators_for_request(&mut db, request.get_ref()).await;
                 let result = agent_response::handle_request(
                     db,
                     request.get_ref(),
@@ -129,17 +171,107 @@ impl Spelldawn for GameService {
             }
             Err(error) => {
                 error!(?error, "Server Error!");
-                Err(Status::internal(format!("Server Error: {:#}", error)))
+                Err(error_status(&error))
             }
         }
     }
 }
 
+/// Error returned by [handle_action] when a [UserAction] cannot be applied to
+/// the current game state. Carries the actions which *are* currently legal for
+/// the requesting player so the client can recover gracefully.
+#[derive(Debug)]
+pub struct IllegalActionError {
+    /// Action which was requested
+    pub action: UserAction,
+    /// Legal actions for the requesting player, as determined by
+    /// [legal_actions::evaluate]
+    pub legal_actions: Vec<UserAction>,
+    /// Underlying error produced when attempting to apply `action`
+    pub error: anyhow::Error,
+}
+
+impl IllegalActionError {
+    /// Classifies an `error` produced by applying `action` to `game`, which
+    /// is expected to be unchanged by the failed action. Returns an
+    /// [IllegalActionError] if `action` is not currently legal, or the original
+    /// error if it is, since the failure was then not caused by the request.
+    fn classify(
+        game: &GameState,
+        side: Side,
+        action: UserAction,
+        error: anyhow::Error,
+    ) -> anyhow::Error {
+        let legal_actions = legal_actions::evaluate(game, side)
+            .map(Iterator::collect::<Vec<_>>)
+            .unwrap_or_default();
+        if legal_actions.contains(&action) {
+            error
+        } else {
+            Self { action, legal_actions, error }.into()
+        }
+    }
+}
+
+impl Display for IllegalActionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Illegal action {:?}: {:#}", self.action, self.error)
+    }
+}
+
+impl Error for IllegalActionError {}
+
+/// Error returned by [handle_standard_action] when an action payload was
+/// serialized with a different [USER_ACTION_PAYLOAD_VERSION] than the server
+/// expects, typically because the client needs to be upgraded.
+#[derive(Debug)]
+pub struct ClientOutOfDateError {
+    /// Payload version sent by the client
+    pub client_version: u8,
+}
+
+impl Display for ClientOutOfDateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Client out of date: action version {} does not match server version {}, please \
+             upgrade",
+            self.client_version, USER_ACTION_PAYLOAD_VERSION
+        )
+    }
+}
+
+impl Error for ClientOutOfDateError {}
+
+/// Converts a request error into a [Status] to return to the client. If the
+/// error was an [IllegalActionError], the serialized list of legal actions is
+/// attached as the status details.
+fn error_status(error: &anyhow::Error) -> Status {
+    if let Some(illegal) = error.downcast_ref::<IllegalActionError>() {
+        if let Ok(details) = ser::to_vec(&illegal.legal_actions) {
+            return Status::with_details(
+                Code::FailedPrecondition,
+                format!("Server Error: {:#}", error),
+                Bytes::from(details),
+            );
+        }
+    }
+
+    if error.downcast_ref::<ClientOutOfDateError>().is_some() {
+        return Status::failed_precondition(format!("{:#}", error));
+    }
+
+    if error.downcast_ref::<CorruptedGameError>().is_some() {
+        return Status::data_loss(format!("{:#}", error));
+    }
+
+    Status::internal(format!("Server Error: {:#}", error))
+}
+
 /// Helper to perform the connect action from the unity plugin
 pub fn connect(message: ConnectRequest) -> Result<CommandList> {
     let mut db = SledDatabase { flush_on_write: true };
-    let player_id = player_id(&mut db, &message.player_id)?;
-    handle_connect(&mut db, player_id)
+    handle_connect_request(&mut db, &message)
 }
 
 /// Helper to perform an action from the unity plugin
@@ -197,8 +329,10 @@ pub fn handle_request(database: &mut impl Database, request: &GameRequest) -> Re
             handle_standard_action(database, player_id, game_id, standard_action)
         }
         Action::FetchPanel(fetch_panel) => {
+            let game = game_id.map(|game_id| database.game(game_id)).transpose()?;
             Ok(GameResponse::from_commands(vec![Command::UpdatePanels(panels::render_panel(
                 fetch_panel.panel_address.clone().with_error(|| "missing address")?,
+                game.as_ref(),
             )?)]))
         }
         Action::NewGame(create_game) => handle_new_game(database, player_id, create_game),
@@ -236,13 +370,73 @@ pub fn handle_request(database: &mut impl Database, request: &GameRequest) -> Re
     Ok(response)
 }
 
+/// Translates a [ConnectRequest] into a call to [handle_connect].
+pub fn handle_connect_request(
+    database: &mut impl Database,
+    message: &ConnectRequest,
+) -> Result<CommandList> {
+    let player_id = player_id(database, &message.player_id)?;
+    handle_connect(
+        database,
+        player_id,
+        spectate_game_id(message)?,
+        adapters::locale(message.locale),
+    )
+}
+
 /// Sets up the game state for a game connection request.
-pub fn handle_connect(database: &mut impl Database, player_id: PlayerId) -> Result<CommandList> {
-    if let Some(game_id) = player_data::current_game_id(database.player(player_id)?) {
+///
+/// If a `spectate` game is provided, the player connects as an observer of that
+/// game instead of their current game. Spectators do not need to be
+/// participants in the game and receive a full sync which hides both players'
+/// hidden information.
+///
+/// Card text is rendered in the requested `locale`, which is also stored for
+/// use in subsequent updates to this player.
+pub fn handle_connect(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    spectate: Option<GameId>,
+    locale: Locale,
+) -> Result<CommandList> {
+    let options =
+        ResponseOptions { omit_hidden_deck_cards: true, locale, ..ResponseOptions::default() };
+    if let Some(game_id) = spectate {
+        info!(?player_id, ?game_id, "spectating_game");
+        let game = database.game(game_id)?;
+        let mut commands = render::spectate(&game, options)?;
+        panels::append_standard_panels(&mut commands)?;
+        return Ok(command_list(commands));
+    }
+
+    let player = database.player(player_id)?;
+    if let Some(mut player) = player.clone().filter(|player| player.locale != locale) {
+        player.locale = locale;
+        database.write_player(&player)?;
+    }
+
+    if let Some(game_id) = player_data::current_game_id(player) {
         if database.has_game(game_id)? {
-            let game = database.game(game_id)?;
+            let mut game = database
+                .game(game_id)
+                .or_else(|error| recover_corrupted_game(database, player_id, error))?;
+            let now = database.clock().now();
+            let mulligan_resolved = actions::handle_mulligan_timeout(&mut game, now)?;
+            let prompt_resolved = actions::handle_prompt_timeout(&mut game, now)?;
+            if actions::handle_turn_timer(&mut game, None, now)?
+                || mulligan_resolved
+                || prompt_resolved
+            {
+                if record_completion(&mut game, now) {
+                    record_match_result(database, &game)?;
+                }
+                database.write_game(&game)?;
+            }
             let side = user_side(player_id, &game)?;
-            let mut commands = render::connect(&game, side)?;
+            // Any animations queued for the previous connection describe state which is
+            // already included in this sync, so they must not be played afterwards.
+            agent_response::discard_responses();
+            let mut commands = render::connect_with_options(&game, side, options)?;
             panels::append_standard_panels(&mut commands)?;
             Ok(command_list(commands))
         } else {
@@ -253,6 +447,11 @@ pub fn handle_connect(database: &mut impl Database, player_id: PlayerId) -> Resu
     }
 }
 
+/// Held while a new game request is checked against pending invitations.
+/// Without it, two players inviting each other at the same time could each
+/// record an invitation before seeing the other's, leaving both waiting.
+static NEW_GAME_LOCK: Mutex<()> = Mutex::new(());
+
 /// Creates a new default [GameState], deals opening hands, and writes its value
 /// to the database.
 fn handle_new_game(
@@ -260,20 +459,34 @@ fn handle_new_game(
     user_id: PlayerId,
     action: &NewGameAction,
 ) -> Result<GameResponse> {
+    let _lock = NEW_GAME_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
     let debug_options = action.debug_options.clone().unwrap_or_default();
     let opponent_id = player_id(database, &action.opponent_id)?;
+    ensure_player_exists(database, user_id)?;
+    ensure_player_exists(database, opponent_id)?;
     let deck_id = adapters::deck_id(action.deck.with_error(|| "Expected Deck ID")?);
     let mut user = database.player(user_id)?.with_error(|| "User not found")?;
-    let user_deck = user.deck(deck_id).clone();
-    let opponent_deck =
-        if let Some(deck) = requested_deck(database, opponent_id, user_deck.side.opponent())? {
+    let user_deck = database.deck(user_id, deck_id)?;
+    let user_side = user_deck.side;
+    let opponent_deck = match opponent_id {
+        PlayerId::Database(_) => {
+            let mut opponent = database.player(opponent_id)?.with_error(|| "Opponent not found")?;
+            let Some(invitation) = opponent.invitation_to(user_id) else {
+                return send_invitation(database, user, GameInvitation { opponent_id, deck_id });
+            };
+            let deck = database.deck(opponent_id, invitation.deck_id)?;
+            verify!(deck.side == user_side.opponent(), "Deck side mismatch!");
+            opponent.pending_invitations.retain(|i| i.opponent_id != user_id);
+            let deck = match_deck(&mut opponent, user_id, invitation.deck_id, deck)?;
+            database.write_player(&opponent)?;
             deck
-        } else {
-            user.current_game = Some(CurrentGame::Requested(NewGameRequest { deck_id }));
-            database.write_player(&user)?;
-            // TODO: Implement some kind of waiting UI here
-            return Ok(GameResponse::from_commands(vec![]));
-        };
+        }
+        // TODO: Each named player should have their own decklist
+        PlayerId::Named(_) => decklists::canonical_deck(opponent_id, user_side.opponent()),
+    };
+
+    user.pending_invitations.retain(|i| i.opponent_id != opponent_id);
+    let user_deck = match_deck(&mut user, opponent_id, deck_id, user_deck)?;
 
     let (overlord_deck, champion_deck) = match (user_deck.side, opponent_deck.side) {
         (Side::Overlord, Side::Champion) => (user_deck, opponent_deck),
@@ -295,8 +508,14 @@ fn handle_new_game(
         },
     );
 
+    game.player_mut(user_side).cosmetics = user.cosmetics.clone();
+    game.player_mut(user_side.opponent()).cosmetics = player_cosmetics(database, opponent_id)?;
+
     dispatch::populate_delegate_cache(&mut game);
     mutations::deal_opening_hands(&mut game)?;
+    if let GamePhase::ResolveMulligans(mulligans) = &mut game.data.phase {
+        mulligans.started_at = Some(database.clock().now());
+    }
     database.write_game(&game)?;
 
     user.current_game = Some(CurrentGame::Playing(game_id));
@@ -319,19 +538,102 @@ fn handle_new_game(
     })
 }
 
-/// Looks up the deck the `player_id` player has requested to use for a new game
-fn requested_deck(
-    database: &impl Database,
+/// Records a pending [GameInvitation] from the `user` player, replacing any
+/// previous invitation to the same opponent, and returns commands to display a
+/// waiting screen until the opponent submits a matching request.
+fn send_invitation(
+    database: &mut impl Database,
+    mut user: PlayerData,
+    invitation: GameInvitation,
+) -> Result<GameResponse> {
+    info!(?user.id, ?invitation.opponent_id, "send_invitation");
+    user.pending_invitations.retain(|i| i.opponent_id != invitation.opponent_id);
+    user.pending_invitations.push(invitation);
+    user.current_game = None;
+    database.write_player(&user)?;
+    Ok(GameResponse::from_commands(panels::open_waiting_for_opponent_panel()?))
+}
+
+/// Withdraws all of the `player_id` player's pending invitations and closes
+/// their waiting screen.
+///
+/// Holds the same lock as [handle_new_game], so an opponent accepting the
+/// invitation either completes before this runs, in which case the player is
+/// already being moved into the new game and nothing is changed, or observes
+/// that the invitation no longer exists and sends a new invitation of its own.
+fn handle_cancel_new_game(
+    database: &mut impl Database,
     player_id: PlayerId,
-    side: Side,
-) -> Result<Option<Deck>> {
+) -> Result<GameResponse> {
+    let _lock = NEW_GAME_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut player = database.player(player_id)?.with_error(|| "Player not found")?;
+    if matches!(player.current_game, Some(CurrentGame::Playing(_))) {
+        info!(?player_id, "cancel_new_game_already_started");
+        return Ok(GameResponse::from_commands(vec![]));
+    }
+
+    info!(?player_id, "cancel_new_game");
+    player.pending_invitations.clear();
+    player.current_game = None;
+    database.write_player(&player)?;
+    Ok(GameResponse::from_commands(vec![panels::close_waiting_for_opponent_panel()]))
+}
+
+/// Continues the `player`'s match against `opponent_id`, or starts a new one if
+/// there is no such match in progress, and returns the `deck_id` deck with that
+/// match's sideboard swaps applied.
+fn match_deck(
+    player: &mut PlayerData,
+    opponent_id: PlayerId,
+    deck_id: DeckId,
+    deck: Deck,
+) -> Result<Deck> {
+    let current_match = player
+        .current_match
+        .take()
+        .filter(|m| m.opponent_id == opponent_id && !m.is_complete())
+        .unwrap_or_else(|| MatchData::new(opponent_id));
+    let result = decklists::apply_sideboard_swaps(
+        &deck,
+        deck_id,
+        &player.sideboard,
+        &current_match.sideboard_swaps,
+    )?;
+    player.current_match = Some(current_match);
+    Ok(result)
+}
+
+/// Checks that the `player_id` player exists, returning an error if they do
+/// not. A player record is created for named AI players if needed.
+fn ensure_player_exists(database: &mut impl Database, player_id: PlayerId) -> Result<()> {
+    if database.has_player(player_id)? {
+        return Ok(());
+    }
+
+    match player_id {
+        PlayerId::Named(_) => database.write_player(&PlayerData {
+            id: player_id,
+            current_game: None,
+            decks: vec![],
+            collection: HashMap::new(),
+            cosmetics: PlayerCosmetics::default(),
+            sideboard: HashMap::new(),
+            current_match: None,
+            locale: Locale::default(),
+            pending_invitations: vec![],
+        }),
+        PlayerId::Database(_) => fail!("Player {:?} not found", player_id),
+    }
+}
+
+/// Looks up the [PlayerCosmetics] the `player_id` player has selected. Named
+/// players always use the default appearance.
+fn player_cosmetics(database: &impl Database, player_id: PlayerId) -> Result<PlayerCosmetics> {
     Ok(match player_id {
         PlayerId::Database(_) => {
-            let player = database.player(player_id)?.with_error(|| "Player not found")?;
-            player.requested_deck_id().map(|deck_id| player.deck(deck_id).clone())
+            database.player(player_id)?.with_error(|| "Player not found")?.cosmetics
         }
-        // TODO: Each named player should have their own decklist
-        PlayerId::Named(_) => Some(decklists::canonical_deck(player_id, side)),
+        PlayerId::Named(_) => PlayerCosmetics::default(),
     })
 }
 
@@ -344,14 +646,26 @@ fn requested_deck(
 ///
 /// Schedules an AI Agent response if one is required for the current game
 /// state.
+///
+/// Returns an [IllegalActionError] if the action is not currently legal.
 pub fn handle_action(
     database: &mut impl Database,
     player_id: PlayerId,
     game_id: Option<GameId>,
     action: UserAction,
 ) -> Result<GameResponse> {
+    let now = database.clock().now();
     handle_custom_action(database, player_id, game_id, |game, user_side| {
+        if actions::handle_turn_timer(game, Some(user_side), now)? {
+            // The player whose clock was running ran out of time before this
+            // action could be taken.
+            return Ok(());
+        }
+
+        // Actions leave the game unchanged when they fail, so legal actions can
+        // be evaluated against it afterwards.
         actions::handle_user_action(game, user_side, action)
+            .map_err(|error| IllegalActionError::classify(game, user_side, action, error))
     })
 }
 
@@ -364,15 +678,36 @@ pub fn handle_custom_action(
     function: impl Fn(&mut GameState, Side) -> Result<()>,
 ) -> Result<GameResponse> {
     // TODO: Use transactions?
-    let mut game = find_game(database, game_id)?;
+    let mut game = find_game(database, game_id)
+        .or_else(|error| recover_corrupted_game(database, player_id, error))?;
     let user_side = user_side(player_id, &game)?;
+    let previous = game.clone();
+    let now = database.clock().now();
+    actions::handle_mulligan_timeout(&mut game, now)?;
+    actions::handle_prompt_timeout(&mut game, now)?;
     function(&mut game, user_side)?;
+    actions::restart_prompt_timer(&mut game, now)?;
+    if record_completion(&mut game, now) {
+        record_match_result(database, &game)?;
+    }
 
-    let user_result = render::render_updates(&game, user_side)?;
+    let user_result = render::render_updates_with_options(
+        Some(&previous),
+        &game,
+        user_side,
+        response_options(database, player_id)?,
+    )?;
     let opponent_id = game.player(user_side.opponent()).id;
 
-    let channel_response =
-        Some((opponent_id, command_list(render::render_updates(&game, user_side.opponent())?)));
+    let channel_response = Some((
+        opponent_id,
+        command_list(render::render_updates_with_options(
+            Some(&previous),
+            &game,
+            user_side.opponent(),
+            response_options(database, opponent_id)?,
+        )?),
+    ));
     database.write_game(&game)?;
 
     Ok(GameResponse {
@@ -381,20 +716,226 @@ pub fn handle_custom_action(
     })
 }
 
+/// Applies expired mulligan, prompt, and turn timers to the game with the
+/// given ID without waiting for a player to connect or act, writing the
+/// result if any timer expired.
+///
+/// Returns updates to send to both players, or an empty list if no timer had
+/// expired.
+pub fn handle_timeouts(
+    database: &mut impl Database,
+    game_id: GameId,
+) -> Result<Vec<(PlayerId, CommandList)>> {
+    let mut game = find_game(database, Some(game_id))?;
+    let previous = game.clone();
+    let now = database.clock().now();
+    let mulligan_resolved = actions::handle_mulligan_timeout(&mut game, now)?;
+    let prompt_resolved = actions::handle_prompt_timeout(&mut game, now)?;
+    if !(actions::handle_turn_timer(&mut game, None, now)? || mulligan_resolved || prompt_resolved)
+    {
+        return Ok(vec![]);
+    }
+
+    actions::restart_prompt_timer(&mut game, now)?;
+    if record_completion(&mut game, now) {
+        record_match_result(database, &game)?;
+    }
+
+    let mut responses = vec![];
+    for side in enum_iterator::all::<Side>() {
+        let player_id = game.player(side).id;
+        responses.push((
+            player_id,
+            command_list(render::render_updates_with_options(
+                Some(&previous),
+                &game,
+                side,
+                response_options(database, player_id)?,
+            )?),
+        ));
+    }
+    database.write_game(&game)?;
+    Ok(responses)
+}
+
 /// Sends a game response to a given player, if they are connected to the
 /// server.
 pub async fn send_player_response(response: Option<(PlayerId, CommandList)>) {
     if let Some((player_id, commands)) = response {
-        if let Some(channel) = CHANNELS.get(&player_id) {
+        // Clone the sender so that the map is not locked while awaiting.
+        let channel = CHANNELS.get(&player_id).map(|channel| channel.clone());
+        if let Some(channel) = channel {
             if channel.send(Ok(commands)).await.is_err() {
                 // This returns SendError if the client is disconnected, which isn't a
-                // huge problem. Hopefully they will reconnect again in the future.
+                // huge problem. They will receive a full sync when they reconnect.
                 info!(?player_id, "client_is_disconnected");
+                CHANNELS.remove_if(&player_id, |_, current| current.same_channel(&channel));
             }
         }
     }
 }
 
+/// Registers the channel used to push updates to a connected player, replacing
+/// any previous channel for this player.
+pub fn register_channel(player_id: PlayerId, sender: Sender<Result<CommandList, Status>>) {
+    CHANNELS.insert(player_id, sender);
+}
+
+/// Returns true if a channel is currently registered for the `player_id`
+/// player.
+pub fn is_connected(player_id: PlayerId) -> bool {
+    CHANNELS.contains_key(&player_id)
+}
+
+/// Returns true if the `player_id` player has a registered channel which has
+/// not been closed by the client.
+pub fn has_live_channel(player_id: PlayerId) -> bool {
+    CHANNELS.get(&player_id).is_some_and(|channel| !channel.is_closed())
+}
+
+/// Removes the channel for a player if the client has closed it.
+pub fn remove_closed_channel(player_id: PlayerId) {
+    CHANNELS.remove_if(&player_id, |_, channel| channel.is_closed());
+}
+
+/// Removes the channel for a player who has disconnected. Called by the plugin
+/// layer when it knows a client has gone away.
+pub fn disconnect(player_id: PlayerId) {
+    info!(?player_id, "disconnect");
+    CHANNELS.remove(&player_id);
+}
+
+/// Registers a read-only channel used to push updates for the `game_id` game
+/// to a spectating player, replacing any previous channel for this player in
+/// this game.
+pub fn register_spectator(
+    game_id: GameId,
+    player_id: PlayerId,
+    locale: Locale,
+    sender: Sender<Result<CommandList, Status>>,
+) {
+    let mut spectators = SPECTATORS.entry(game_id).or_default();
+    spectators.retain(|spectator| spectator.player_id != player_id);
+    spectators.push(Spectator { player_id, locale, sender });
+}
+
+/// Returns true if a spectator channel is registered for the `player_id`
+/// player in the `game_id` game.
+pub fn is_spectating(game_id: GameId, player_id: PlayerId) -> bool {
+    SPECTATORS.get(&game_id).is_some_and(|spectators| {
+        spectators.iter().any(|spectator| spectator.player_id == player_id)
+    })
+}
+
+/// Sends a full sync of the current state of the `game_id` game, which does
+/// not include either player's hidden information, to each of its spectators.
+/// Spectators whose channel has been closed are removed.
+///
+/// Does not read the game if it has no spectators.
+pub async fn update_spectators(database: &impl Database, game_id: GameId) {
+    // Clone the spectators so that the map is not locked while awaiting.
+    let spectators = SPECTATORS.get(&game_id).map(|spectators| spectators.clone());
+    let Some(spectators) = spectators.filter(|spectators| !spectators.is_empty()) else {
+        return;
+    };
+    let game = match database.game(game_id) {
+        Ok(game) => game,
+        Err(error) => {
+            warn!(?game_id, ?error, "update_spectators_error");
+            return;
+        }
+    };
+
+    for spectator in spectators {
+        let options = ResponseOptions {
+            omit_hidden_deck_cards: true,
+            locale: spectator.locale,
+            ..ResponseOptions::default()
+        };
+        let commands = match render::spectate(&game, options) {
+            Ok(commands) => command_list(commands),
+            Err(error) => {
+                warn!(?game_id, ?error, "update_spectators_error");
+                return;
+            }
+        };
+        if spectator.sender.send(Ok(commands)).await.is_err() {
+            info!(?game_id, player_id = ?spectator.player_id, "spectator_is_disconnected");
+            if let Some(mut current) = SPECTATORS.get_mut(&game_id) {
+                current.retain(|s| !s.sender.same_channel(&spectator.sender));
+            }
+        }
+    }
+    SPECTATORS.remove_if(&game_id, |_, spectators| spectators.is_empty());
+}
+
+/// Invokes [update_spectators] for the current game of the player who made
+/// `request`, if any.
+pub async fn update_spectators_for_request(database: &mut impl Database, request: &GameRequest) {
+    let game_id = player_id(database, &request.player_id)
+        .and_then(|player_id| database.player(player_id))
+        .map(player_data::current_game_id);
+    if let Ok(Some(game_id)) = game_id {
+        update_spectators(database, game_id).await;
+    }
+}
+
+/// Lists the games the `player_id` player is participating in, e.g. in order to
+/// offer to continue one of them.
+pub fn list_games(database: &impl Database, player_id: PlayerId) -> Result<Vec<GameIdentifier>> {
+    Ok(database.games_for_player(player_id)?.into_iter().map(adapters::game_identifier).collect())
+}
+
+/// Returns the [UserAction]s the `player_id` player can currently take in their
+/// current game, see [legal_actions::evaluate].
+pub fn player_legal_actions(
+    database: &impl Database,
+    player_id: PlayerId,
+) -> Result<Vec<UserAction>> {
+    let game_id = player_data::current_game_id(database.player(player_id)?);
+    let game = find_game(database, game_id)?;
+    let side = user_side(player_id, &game)?;
+    let result = legal_actions::evaluate(&game, side)?.collect();
+    Ok(result)
+}
+
+/// Queues a [SideboardSwap] to be applied to the `player_id` player's deck for
+/// the remaining games of their current match.
+///
+/// Returns an error if the player is not in a match or if the swap would
+/// produce an invalid deck, see [decklists::apply_sideboard_swaps].
+fn handle_sideboard_swap(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    swap: SideboardSwap,
+) -> Result<()> {
+    let deck = database.deck(player_id, swap.deck_id)?;
+    let mut player = database.player(player_id)?.with_error(|| "Player not found")?;
+    let current_match = player
+        .current_match
+        .as_mut()
+        .filter(|m| !m.is_complete())
+        .with_error(|| "No match in progress")?;
+    let mut swaps = current_match.sideboard_swaps.clone();
+    swaps.push(swap);
+    decklists::apply_sideboard_swaps(&deck, swap.deck_id, &player.sideboard, &swaps)?;
+    current_match.sideboard_swaps = swaps;
+    database.write_player(&player)
+}
+
+/// Previews the reactive actions the opponent of the `player_id` player could
+/// conceivably take in their current game, see
+/// [queries::possible_opponent_responses].
+pub fn possible_opponent_responses(
+    database: &impl Database,
+    player_id: PlayerId,
+) -> Result<Vec<PossibleResponse>> {
+    let game_id = player_data::current_game_id(database.player(player_id)?);
+    let game = find_game(database, game_id)?;
+    let side = user_side(player_id, &game)?;
+    Ok(queries::possible_opponent_responses(&game, side))
+}
+
 /// Parses the serialized payload in a [StandardAction] and dispatches to the
 /// correct handler.
 fn handle_standard_action(
@@ -403,15 +944,165 @@ fn handle_standard_action(
     game_id: Option<GameId>,
     standard_action: &StandardAction,
 ) -> Result<GameResponse> {
-    verify!(!standard_action.payload.is_empty(), "Empty action payload received");
-    let action: UserAction = de::from_slice(&standard_action.payload)
-        .with_error(|| "Failed to deserialize action payload")?;
-    match action {
+    let (version, payload) =
+        standard_action.payload.split_first().with_error(|| "Empty action payload received")?;
+    if *version != USER_ACTION_PAYLOAD_VERSION {
+        return Err(ClientOutOfDateError { client_version: *version }.into());
+    }
+    let action: UserAction =
+        de::from_slice(payload).with_error(|| "Failed to deserialize action payload")?;
+    let response = match action {
         UserAction::Debug(debug_action) => {
             debug::handle_debug_action(database, player_id, game_id, debug_action)
         }
+        UserAction::SideboardSwap(swap) => {
+            handle_sideboard_swap(database, player_id, swap)?;
+            Ok(GameResponse::from_commands(vec![]))
+        }
+        UserAction::CancelNewGame => handle_cancel_new_game(database, player_id),
         _ => handle_action(database, player_id, game_id, action),
+    }?;
+
+    if let Some(optimistic) =
+        standard_action.update.as_ref().filter(|_| CHECK_OPTIMISTIC_UPDATES.load(Ordering::Relaxed))
+    {
+        if let Err(error) =
+            check_optimistic_update(database, player_id, game_id, action, optimistic)
+        {
+            warn!(?player_id, ?error, "optimistic_update_check_failed");
+        }
     }
+    Ok(response)
+}
+
+/// Whether [check_optimistic_update] runs for incoming actions, see
+/// [enable_optimistic_update_checks].
+static CHECK_OPTIMISTIC_UPDATES: AtomicBool = AtomicBool::new(false);
+
+/// Enables comparing optimistic client updates against the authoritative
+/// result of each action. Disabled by default because each comparison renders
+/// a full sync of the game.
+pub fn enable_optimistic_update_checks() {
+    CHECK_OPTIMISTIC_UPDATES.store(true, Ordering::Relaxed);
+}
+
+/// Number of actions received whose optimistic client update did not match
+/// the authoritative result, see [check_optimistic_update].
+static OPTIMISTIC_UPDATE_MISMATCHES: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the number of optimistic update mismatches observed since the
+/// server started.
+pub fn optimistic_update_mismatches() -> u64 {
+    OPTIMISTIC_UPDATE_MISMATCHES.load(Ordering::Relaxed)
+}
+
+/// Compares the card positions predicted by a client's `optimistic` update
+/// against the card positions in a full sync of the resulting game state,
+/// logging and counting any divergence. The response sent to the client cannot
+/// be used for this because card deltas omit cards which did not move.
+///
+/// Divergences indicate client bugs or tampering, but this check is diagnostic
+/// only and does not reject the action.
+fn check_optimistic_update(
+    database: &impl Database,
+    player_id: PlayerId,
+    game_id: Option<GameId>,
+    action: UserAction,
+    optimistic: &CommandList,
+) -> Result<()> {
+    let game = find_game(database, game_id)?;
+    let mismatched = mismatched_cards(&game, user_side(player_id, &game)?, optimistic)?;
+    if !mismatched.is_empty() {
+        OPTIMISTIC_UPDATE_MISMATCHES.fetch_add(1, Ordering::Relaxed);
+        warn!(?player_id, ?action, ?mismatched, "optimistic_update_mismatch");
+    }
+    Ok(())
+}
+
+/// Returns the cards whose position in an `optimistic` update for `user_side`
+/// does not match their position in the current state of `game`.
+pub fn mismatched_cards(
+    game: &GameState,
+    user_side: Side,
+    optimistic: &CommandList,
+) -> Result<Vec<CardIdentifier>> {
+    let actual = card_positions(&command_list(render::connect(game, user_side)?));
+    Ok(card_positions(optimistic)
+        .into_iter()
+        .filter(|(card_id, predicted)| actual.get(card_id).is_some_and(|p| p != predicted))
+        .map(|(card_id, _)| card_id)
+        .collect())
+}
+
+/// Final position of each card set by a [CommandList], either via a game view
+/// update or via an explicit move.
+fn card_positions(commands: &CommandList) -> HashMap<CardIdentifier, Option<Position>> {
+    let mut result = HashMap::new();
+    for command in commands.commands.iter().filter_map(|c| c.command.as_ref()) {
+        match command {
+            Command::UpdateGameView(update) => {
+                for card in update.game.iter().flat_map(|game| &game.cards) {
+                    if let (Some(card_id), Some(position)) = (card.card_id, &card.card_position) {
+                        result.insert(card_id, position.position.clone());
+                    }
+                }
+            }
+            Command::MoveGameObjects(move_objects) => {
+                for game_object_move in &move_objects.moves {
+                    if let (Some(Id::CardId(card_id)), Some(position)) = (
+                        game_object_move.id.as_ref().and_then(|id| id.id.as_ref()),
+                        &game_object_move.position,
+                    ) {
+                        result.insert(*card_id, position.position.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Stores the time at which a game ended, if it has just ended. Returns true if
+/// the game has just ended.
+pub fn record_completion(game: &mut GameState, now: Duration) -> bool {
+    if matches!(game.data.phase, GamePhase::GameOver { .. }) && game.data.completed_at.is_none() {
+        game.data.completed_at = Some(now);
+        true
+    } else {
+        false
+    }
+}
+
+/// Updates the [MatchData] of each player in a game which has just ended with
+/// its result. Games which have already been recorded are ignored, so this can
+/// safely be retried if writing the game afterwards fails.
+fn record_match_result(database: &mut impl Database, game: &GameState) -> Result<()> {
+    let winner = match game.data.phase {
+        GamePhase::GameOver { winner } => winner,
+        _ => return Ok(()),
+    };
+
+    for side in enum_iterator::all::<Side>() {
+        let Some(mut player) = database.player(game.player(side).id)? else {
+            continue;
+        };
+        let opponent_id = game.player(side.opponent()).id;
+        if let Some(current_match) = player
+            .current_match
+            .as_mut()
+            .filter(|m| m.opponent_id == opponent_id && !m.recorded_games.contains(&game.id))
+        {
+            if side == winner {
+                current_match.games_won += 1;
+            } else {
+                current_match.games_lost += 1;
+            }
+            current_match.recorded_games.push(game.id);
+            database.write_player(&player)?;
+        }
+    }
+    Ok(())
 }
 
 /// Look up the state for a game which is expected to exist and assigns an
@@ -428,6 +1119,50 @@ pub fn find_game(database: &impl Database, game_id: Option<GameId>) -> Result<Ga
     Ok(game)
 }
 
+/// Returns the [ResponseOptions] to use when rendering updates for the
+/// `player_id` player.
+fn response_options(database: &impl Database, player_id: PlayerId) -> Result<ResponseOptions> {
+    let locale = database.player(player_id)?.map_or_else(Locale::default, |player| player.locale);
+    Ok(ResponseOptions {
+        omit_hidden_deck_cards: true,
+        scalar_deltas: true,
+        card_deltas: true,
+        locale,
+    })
+}
+
+/// Returns the game a [ConnectRequest] is asking to observe, if any.
+fn spectate_game_id(message: &ConnectRequest) -> Result<Option<GameId>> {
+    Ok(if message.spectate {
+        Some(adapters::game_id(message.game_id.with_error(|| "Expected game_id to spectate")?))
+    } else {
+        None
+    })
+}
+
+/// Handles an `error` produced while reading a game for the `player_id`
+/// player. If it is a [CorruptedGameError], the player's reference to the
+/// corrupted game is cleared so that they are able to start a new game.
+///
+/// Always returns the original error.
+fn recover_corrupted_game<T>(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    error: anyhow::Error,
+) -> Result<T> {
+    if let Some(corrupted) = error.downcast_ref::<CorruptedGameError>() {
+        let game_id = corrupted.game_id;
+        if let Some(mut player) = database.player(player_id)? {
+            if matches!(player.current_game, Some(CurrentGame::Playing(id)) if id == game_id) {
+                warn!(?player_id, ?game_id, "clear_corrupted_game");
+                player.current_game = None;
+                database.write_player(&player)?;
+            }
+        }
+    }
+    Err(error)
+}
+
 /// Turns an `&Option<PlayerIdentifier>` into a [PlayerId], or returns an error
 /// if the input is `None`.
 pub fn player_id(
@@ -468,6 +1203,9 @@ pub fn command_name(command: &GameCommand) -> &'static str {
         Command::DisplayRewards(_) => "DisplayRewards",
         Command::LoadScene(_) => "LoadScene",
         Command::CreateTokenCard(_) => "CreateTokenCard",
+        Command::UpdatePlayerScalars(_) => "UpdatePlayerScalars",
+        Command::UpdateCards(_) => "UpdateCards",
+        Command::DestroyCard(_) => "DestroyCard",
     })
 }
 
@@ -0,0 +1,229 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional HTTP+JSON gateway to the game server, for tooling such as scripts
+//! and bots which cannot easily speak gRPC.
+//!
+//! Every endpoint accepts a `POST` request whose body is the JSON encoding of
+//! a protocol buffer message and translates it into the same [requests]
+//! functions used by the gRPC service:
+//!
+//! - `/connect`: [ConnectRequest] to [CommandList]
+//! - `/perform_action`: [GameRequest] to [CommandList]
+//! - `/list_games`: [PlayerIdentifier] to a list of [GameIdentifier]s
+//! - `/legal_actions`: [PlayerIdentifier] to a list of [UserAction]s
+//! - `/possible_responses`: [PlayerIdentifier] to a list of
+//!   [PossibleResponse]s the player's opponent could take
+//!
+//! [PlayerIdentifier]: protos::spelldawn::PlayerIdentifier
+//! [GameIdentifier]: protos::spelldawn::GameIdentifier
+//! [UserAction]: data::game_actions::UserAction
+//! [PossibleResponse]: data::game_actions::PossibleResponse
+
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::net::SocketAddr;
+
+use anyhow::Result;
+use data::player_name::PlayerId;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use protos::spelldawn::{CommandList, ConnectRequest, GameRequest};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{de, json, ser};
+use tracing::{error, warn};
+
+use crate::agent_response::{self, HandleRequest};
+use crate::database::{Database, SledDatabase};
+use crate::requests::{self, ClientOutOfDateError, IllegalActionError};
+
+/// Response produced by [handle_rest_request].
+#[derive(Debug)]
+pub struct RestResponse {
+    /// HTTP status code to return to the caller
+    pub status: StatusCode,
+    /// JSON-encoded response body
+    pub body: Vec<u8>,
+    /// Update to send to the opponent of the requesting player, if any
+    pub opponent_response: Option<(PlayerId, CommandList)>,
+    /// Game action which was applied by this request, used to schedule AI
+    /// agent responses
+    pub game_request: Option<GameRequest>,
+}
+
+impl RestResponse {
+    /// Creates a successful response with `value` as its JSON body.
+    fn json(value: &impl Serialize) -> Result<Self> {
+        Ok(Self {
+            status: StatusCode::OK,
+            body: ser::to_vec(value)?,
+            opponent_response: None,
+            game_request: None,
+        })
+    }
+
+    /// Creates a response with the given `status` and a JSON body of the form
+    /// `{"error": message}`.
+    fn error(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.into() }).to_string().into_bytes(),
+            opponent_response: None,
+            game_request: None,
+        }
+    }
+}
+
+/// Error returned when a request body is not valid JSON for the message type
+/// expected by an endpoint.
+#[derive(Debug)]
+pub struct InvalidRequestError {
+    pub error: serde_json::Error,
+}
+
+impl Display for InvalidRequestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid request body: {}", self.error)
+    }
+}
+
+impl Error for InvalidRequestError {}
+
+/// Starts serving the REST gateway on `address`. Does not return unless the
+/// server encounters an error.
+pub async fn serve(address: SocketAddr) -> Result<()> {
+    let service =
+        make_service_fn(|_| async { Ok::<_, Infallible>(service_fn(handle_http_request)) });
+    Server::try_bind(&address)?.serve(service).await?;
+    Ok(())
+}
+
+/// Handles a request for the REST endpoint at `path` with the JSON request
+/// `body`, translating any error into an appropriate HTTP status code.
+pub fn handle_rest_request(database: &mut impl Database, path: &str, body: &[u8]) -> RestResponse {
+    match route(database, path, body) {
+        Ok(response) => response,
+        Err(error) => {
+            warn!(?path, ?error, "rest_request_error");
+            error_response(&error)
+        }
+    }
+}
+
+async fn handle_http_request(request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    if request.method() != Method::POST {
+        return Ok(http_response(RestResponse::error(
+            StatusCode::METHOD_NOT_ALLOWED,
+            "Expected a POST request",
+        )));
+    }
+
+    let path = request.uri().path().to_string();
+    let body = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(body) => body,
+        Err(error) => {
+            return Ok(http_response(RestResponse::error(
+                StatusCode::BAD_REQUEST,
+                format!("Error reading request body: {}", error),
+            )));
+        }
+    };
+
+    let mut database = SledDatabase { flush_on_write: false };
+    let mut response = handle_rest_request(&mut database, &path, &body);
+    requests::send_player_response(response.opponent_response.take()).await;
+    if let Some(game_request) = response.game_request.take() {
+        requests::update_spectators_for_request(&mut database, &game_request).await;
+        if let Err(error) =
+            agent_response::handle_request(database, &game_request, HandleRequest::SendToPlayer)
+        {
+            error!(?error, "Agent Error!");
+        }
+    }
+
+    Ok(http_response(response))
+}
+
+fn route(database: &mut impl Database, path: &str, body: &[u8]) -> Result<RestResponse> {
+    match path {
+        "/connect" => {
+            let message: ConnectRequest = parse(body)?;
+            RestResponse::json(&requests::handle_connect_request(database, &message)?)
+        }
+        "/perform_action" => {
+            let request: GameRequest = parse(body)?;
+            let response = requests::handle_request(database, &request)?;
+            Ok(RestResponse {
+                opponent_response: response.opponent_response,
+                game_request: Some(request),
+                ..RestResponse::json(&response.command_list)?
+            })
+        }
+        "/list_games" => {
+            let player_id = requests::player_id(database, &Some(parse(body)?))?;
+            RestResponse::json(&requests::list_games(database, player_id)?)
+        }
+        "/legal_actions" => {
+            let player_id = requests::player_id(database, &Some(parse(body)?))?;
+            RestResponse::json(&requests::player_legal_actions(database, player_id)?)
+        }
+        "/possible_responses" => {
+            let player_id = requests::player_id(database, &Some(parse(body)?))?;
+            RestResponse::json(&requests::possible_opponent_responses(database, player_id)?)
+        }
+        _ => Ok(RestResponse::error(StatusCode::NOT_FOUND, format!("Unknown endpoint {}", path))),
+    }
+}
+
+fn parse<T: DeserializeOwned>(body: &[u8]) -> Result<T> {
+    de::from_slice(body).map_err(|error| InvalidRequestError { error }.into())
+}
+
+/// Converts a request error into a [RestResponse]. If the error was an
+/// [IllegalActionError], the list of legal actions is included in the body.
+fn error_response(error: &anyhow::Error) -> RestResponse {
+    let message = format!("{:#}", error);
+    if let Some(illegal) = error.downcast_ref::<IllegalActionError>() {
+        return RestResponse {
+            status: StatusCode::CONFLICT,
+            body: json!({ "error": message, "legal_actions": illegal.legal_actions })
+                .to_string()
+                .into_bytes(),
+            opponent_response: None,
+            game_request: None,
+        };
+    }
+
+    if error.is::<InvalidRequestError>() {
+        return RestResponse::error(StatusCode::BAD_REQUEST, message);
+    }
+
+    if error.is::<ClientOutOfDateError>() {
+        return RestResponse::error(StatusCode::PRECONDITION_FAILED, message);
+    }
+
+    RestResponse::error(StatusCode::INTERNAL_SERVER_ERROR, message)
+}
+
+fn http_response(response: RestResponse) -> Response<Body> {
+    let mut result = Response::new(Body::from(response.body));
+    *result.status_mut() = response.status;
+    result
+        .headers_mut()
+        .insert(hyper::header::CONTENT_TYPE, "application/json".parse().expect("valid header"));
+    result
+}
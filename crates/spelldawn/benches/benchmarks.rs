@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
 use std::time::Duration;
 
 use actions::legal_actions;
@@ -27,10 +28,13 @@ use cards::{decklists, initialize};
 use criterion::measurement::WallTime;
 use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
 use data::primitives::Side;
+use display::render;
 
 criterion_group!(
     benches,
     legal_actions,
+    clone_game,
+    render,
     minimax_nim,
     alpha_beta_nim,
     uct1_nim,
@@ -57,6 +61,33 @@ pub fn legal_actions(c: &mut Criterion) {
     group.finish();
 }
 
+pub fn clone_game(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone_game");
+    configure(&mut group);
+    let game = decklists::canonical_game().unwrap();
+    group.bench_function("clone", |b| {
+        b.iter(|| {
+            let mut clone = game.clone();
+            clone.delegate_cache.lookup = Arc::new((*game.delegate_cache.lookup).clone());
+            clone
+        })
+    });
+    group.bench_function("clone_for_simulation", |b| b.iter(|| game.clone_for_simulation()));
+    group.finish();
+}
+
+pub fn render(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render");
+    configure(&mut group);
+    let game = decklists::canonical_game().unwrap();
+    group.bench_function("render", |b| {
+        b.iter(|| {
+            let _commands = render::connect(&game, Side::Overlord).unwrap();
+        })
+    });
+    group.finish();
+}
+
 pub fn minimax_nim(c: &mut Criterion) {
     let mut group = c.benchmark_group("minimax_nim");
     configure(&mut group);
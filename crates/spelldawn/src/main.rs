@@ -14,9 +14,16 @@
 
 //! Spelldawn: An asymmetric trading card game
 
+use std::env;
+use std::time::Duration;
+
 use cards::initialize;
+use display::assets;
 use protos::spelldawn::spelldawn_server::SpelldawnServer;
-use server::requests::GameService;
+use server::agent_response::{self, HandleRequest};
+use server::database::SledDatabase;
+use server::requests::{self, GameService};
+use server::{maintenance, rest};
 use tonic::transport::Server;
 use tracing::warn;
 use tracing_subscriber::filter::LevelFilter;
@@ -24,12 +31,84 @@ use tracing_subscriber::fmt;
 use tracing_subscriber::prelude::*;
 use tracing_subscriber::util::SubscriberInitExt;
 
+/// How often to check for expired mulligan, prompt, and turn timers in games
+/// where neither player is acting.
+const TIMEOUT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often to delete finished games, see [maintenance::sweep_finished_games].
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     initialize::run();
     let fmt_layer = fmt::Layer::default().pretty().with_filter(LevelFilter::WARN);
     tracing_subscriber::registry().with(fmt_layer).init();
 
+    for error in assets::validate_card_definitions() {
+        warn!("{:?}", error);
+    }
+
+    tokio::spawn(async {
+        let mut database = SledDatabase { flush_on_write: false };
+        if let Err(error) =
+            agent_response::resume_agents(&mut database, HandleRequest::SendToPlayer).await
+        {
+            warn!("Error resuming agents: {:?}", error);
+        }
+    });
+
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(TIMEOUT_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            let mut database = SledDatabase { flush_on_write: false };
+            match maintenance::expire_timers(&mut database) {
+                Ok(responses) => {
+                    for response in responses {
+                        requests::send_player_response(Some(response)).await;
+                    }
+                }
+                Err(error) => warn!("Error expiring timers: {:?}", error),
+            }
+        }
+    });
+
+    // Finished games are kept for SPELLDAWN_GAME_RETENTION_SECONDS, if set
+    let retention = match env::var("SPELLDAWN_GAME_RETENTION_SECONDS") {
+        Ok(seconds) => Duration::from_secs(seconds.parse()?),
+        Err(_) => maintenance::DEFAULT_RETENTION,
+    };
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            let mut database = SledDatabase { flush_on_write: false };
+            match maintenance::sweep_finished_games(&mut database, retention) {
+                Ok(deleted) if !deleted.is_empty() => {
+                    warn!("Deleted {} finished games.", deleted.len())
+                }
+                Ok(_) => {}
+                Err(error) => warn!("Error deleting finished games: {:?}", error),
+            }
+        }
+    });
+
+    // The REST gateway is only started if an address for it is configured
+    if let Ok(rest_address) = env::var("SPELLDAWN_REST_ADDRESS") {
+        let rest_address = rest_address.parse()?;
+        warn!("REST gateway listening on {}.", rest_address);
+        tokio::spawn(async move {
+            if let Err(error) = rest::serve(rest_address).await {
+                warn!("Error serving REST gateway: {:?}", error);
+            }
+        });
+    }
+
+    if env::var("SPELLDAWN_CHECK_OPTIMISTIC_UPDATES").is_ok() {
+        warn!("Checking optimistic updates.");
+        requests::enable_optimistic_update_checks();
+    }
+
     let address = "0.0.0.0:50052".parse().expect("valid address");
     let server = SpelldawnServer::new(GameService {
         // To print responses:
@@ -0,0 +1,48 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{Duration, Instant};
+
+use ai_core::agent::AgentConfig;
+use ai_game_integration::agents;
+use ai_game_integration::state_node::SpelldawnState;
+use data::card_name::CardName;
+use data::game_actions::{AccessPhaseAction, PromptAction, UserAction};
+use data::player_name::NamedPlayer;
+use data::primitives::Side;
+use test_utils::*;
+
+#[test]
+fn expectimax_scores_accessed_scheme() {
+    let mut g = new_game(
+        Side::Champion,
+        Args { turn: Some(Side::Overlord), actions: 1, ..Args::default() },
+    );
+    let scheme_id = server_card_id(g.play_from_hand(CardName::TestScheme31));
+    g.initiate_raid(ROOM_ID);
+    let state = SpelldawnState(g.database_mut().game().clone());
+
+    let agent = agents::get(NamedPlayer::TestExpectimax);
+    let start_time = Instant::now();
+    let action = agent
+        .pick_action(AgentConfig::with_time_budget(Duration::from_secs(1)), &state)
+        .expect("Error running agent");
+    assert_eq!(
+        UserAction::PromptAction(PromptAction::AccessPhaseAction(AccessPhaseAction::ScoreCard(
+            scheme_id
+        ))),
+        action
+    );
+    assert!(start_time.elapsed().as_secs() < 2);
+}
@@ -0,0 +1,70 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ai_core::game_state_node::GameStateNode;
+use ai_game_integration::state_node::SpelldawnState;
+use data::card_name::CardName;
+use data::game_actions::{CardTarget, EncounterAction, PromptAction, UserAction};
+use data::primitives::Side;
+use test_utils::*;
+
+/// Asserts that every action reported as legal for `side` in `state` can be
+/// applied to it without error.
+fn assert_all_executable(state: &SpelldawnState, side: Side) {
+    for action in state.legal_actions(side).expect("Legal actions") {
+        let mut copy = state.make_copy();
+        copy.execute_action(side, action)
+            .unwrap_or_else(|error| panic!("Legal action {:?} failed: {:#}", action, error));
+    }
+}
+
+#[test]
+fn legal_actions_in_main_phase() {
+    let mut g = new_game(Side::Champion, Args { mana: 0, ..Args::default() });
+    let free = server_card_id(g.add_to_hand(CardName::Test0CostChampionSpell));
+    let costly = server_card_id(g.add_to_hand(CardName::Test1CostChampionSpell));
+    let state = SpelldawnState(g.database_mut().game().clone());
+
+    let actions = state.legal_actions(Side::Champion).unwrap().collect::<Vec<_>>();
+    assert!(actions.contains(&UserAction::GainMana));
+    assert!(actions.contains(&UserAction::DrawCard));
+    assert!(actions.contains(&UserAction::PlayCard(free, CardTarget::None)));
+    assert!(!actions
+        .iter()
+        .any(|action| matches!(action, UserAction::PlayCard(id, _) if *id == costly)));
+    assert!(state.legal_actions(Side::Overlord).is_err());
+    assert_all_executable(&state, Side::Champion);
+}
+
+#[test]
+fn legal_actions_during_raid_encounter() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let weapon = server_card_id(g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost));
+    let (_, minion) = setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    let state = SpelldawnState(g.database_mut().game().clone());
+
+    assert_contents_equal(
+        vec![
+            UserAction::PromptAction(PromptAction::EncounterAction(
+                EncounterAction::UseWeaponAbility(weapon, server_card_id(minion)),
+            )),
+            UserAction::PromptAction(PromptAction::EncounterAction(EncounterAction::NoWeapon)),
+            UserAction::PromptAction(PromptAction::AbortRaid),
+        ],
+        state.legal_actions(Side::Champion).unwrap().collect(),
+    );
+    assert!(state.legal_actions(Side::Overlord).is_err());
+    assert_all_executable(&state, Side::Champion);
+}
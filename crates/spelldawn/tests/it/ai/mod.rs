@@ -12,5 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod expectimax_tests;
+pub mod legal_actions_tests;
 pub mod monte_carlo_tests;
+pub mod predictor_tests;
+pub mod scripted_agent_tests;
+pub mod simulation_tests;
+pub mod tournament_tests;
 pub mod tree_search_tests;
@@ -12,14 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use ai_core::agent::{Agent, AgentConfig, AgentData};
+use ai_core::game_state_node::GameStateNode;
+use ai_game_integration::agents;
+use ai_game_integration::state_node::SpelldawnState;
 use ai_monte_carlo::monte_carlo::{MonteCarloAlgorithm, RandomPlayoutEvaluator};
 use ai_monte_carlo::uct1::Uct1;
 use ai_testing::nim;
 use ai_testing::nim::NimState;
 use ai_testing::nim_agents::NIM_UCT1_AGENT;
+use cards::decklists;
+use data::player_name::NamedPlayer;
 
 #[test]
 pub fn uct1_222() {
@@ -54,3 +59,18 @@ pub fn uct1_deadline_exceeded() {
     assert!(action.is_ok());
     assert!(start_time.elapsed().as_secs() < 2);
 }
+
+#[test]
+pub fn uct1_canonical_game_time_budget() {
+    cards::initialize::run();
+    let agent = agents::get(NamedPlayer::TestUct1);
+    let state = SpelldawnState(decklists::canonical_game().unwrap());
+    let start_time = Instant::now();
+    let config = AgentConfig::with_time_budget(Duration::from_millis(50));
+    let action = agent.pick_action(config, &state).expect("Error running agent");
+    // Allows for the playout in progress at the deadline to finish, but is well
+    // below the default time budget.
+    assert!(start_time.elapsed() < Duration::from_millis(500));
+    let side = state.current_turn().unwrap();
+    assert!(state.legal_actions(side).unwrap().any(|legal| legal == action));
+}
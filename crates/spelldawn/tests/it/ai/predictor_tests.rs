@@ -0,0 +1,95 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use ai_core::game_state_node::GameStateNode;
+use ai_game_integration::predictors;
+use ai_game_integration::state_node::SpelldawnState;
+use cards::decklists;
+use data::agent_definition::GameStatePredictorName;
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::primitives::{RoomId, RoomLocation, Side};
+use test_utils::client;
+use test_utils::*;
+
+fn name_counts(state: &SpelldawnState, side: Side) -> HashMap<CardName, usize> {
+    let mut result = HashMap::new();
+    for card in state.cards(side) {
+        *result.entry(card.name).or_default() += 1;
+    }
+    result
+}
+
+#[test]
+fn determinized_states_are_consistent() {
+    cards::initialize::run();
+    let state = SpelldawnState(decklists::canonical_game().unwrap());
+    let viewer = state.current_turn().unwrap();
+    let predictor = predictors::get(GameStatePredictorName::Determinized);
+
+    let predicted = predictor(&state).collect::<Vec<_>>();
+    assert_eq!(predictors::DETERMINIZED_SAMPLES, predicted.len());
+    for prediction in &predicted {
+        for side in [Side::Overlord, Side::Champion] {
+            assert_eq!(name_counts(&state, side), name_counts(prediction, side));
+            assert_eq!(state.cards(side).len(), prediction.cards(side).len());
+            for (original, card) in state.cards(side).iter().zip(prediction.cards(side)) {
+                assert_eq!(original.id, card.id);
+                assert_eq!(original.position(), card.position());
+                if original.is_revealed_to(viewer) {
+                    assert_eq!(original.name, card.name);
+                }
+            }
+        }
+
+        let actions = prediction.legal_actions(viewer).unwrap().collect::<Vec<_>>();
+        assert!(!actions.is_empty());
+        let mut next = prediction.make_copy();
+        next.execute_action(viewer, actions[0]).expect("Error executing action");
+    }
+}
+
+#[test]
+fn determinized_states_are_deterministic() {
+    cards::initialize::run();
+    let state = SpelldawnState(decklists::canonical_game().unwrap());
+    let predictor = predictors::get(GameStatePredictorName::Determinized);
+    let names = |prediction: &SpelldawnState| {
+        prediction.all_cards().map(|card| card.name).collect::<Vec<_>>()
+    };
+
+    let first = predictor(&state).map(|prediction| names(&prediction)).collect::<Vec<_>>();
+    let second = predictor(&state).map(|prediction| names(&prediction)).collect::<Vec<_>>();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn face_down_occupants_exchanged_regardless_of_type() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let game = g.database_mut().game_mut();
+    let overlord_cards = game.deck(Side::Overlord).take(2).map(|c| c.id).collect::<Vec<_>>();
+    let (scheme, project) = (overlord_cards[0], overlord_cards[1]);
+    client::overwrite_card(game, scheme, CardName::TestScheme31);
+    client::overwrite_card(game, project, CardName::TestProject2Cost);
+    game.move_card_internal(scheme, CardPosition::Room(RoomId::RoomA, RoomLocation::Occupant));
+    game.move_card_internal(project, CardPosition::Room(RoomId::RoomB, RoomLocation::Occupant));
+    let state = SpelldawnState(game.clone());
+
+    let predictor = predictors::get(GameStatePredictorName::Determinized);
+    assert!(
+        predictor(&state).any(|prediction| prediction.card(scheme).name != state.card(scheme).name)
+    );
+}
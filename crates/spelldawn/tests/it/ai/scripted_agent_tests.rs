@@ -0,0 +1,75 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ai_core::agent::{Agent, AgentConfig};
+use ai_core::game_state_node::GameStateNode;
+use ai_game_integration::agents::{self, PickRandomAgent, ScriptedAgent};
+use ai_game_integration::state_node::SpelldawnState;
+use data::game_actions::UserAction;
+use data::player_name::NamedPlayer;
+use data::primitives::{RoomId, Side};
+use test_utils::*;
+
+/// Picks an action for the current player via `agent` and executes it,
+/// returning the action taken.
+fn play(agent: &(impl Agent<SpelldawnState> + ?Sized), node: &mut SpelldawnState) -> UserAction {
+    let side = node.current_turn().expect("Game over");
+    let action = agent.pick_action(AgentConfig::with_deadline(10), node).expect("No action");
+    agent.record_action(node, side, action).expect("Error recording action");
+    node.execute_action(side, action).expect("Error executing action");
+    action
+}
+
+#[test]
+fn scripted_agent_plays_script() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    let mut node = SpelldawnState(g.database_mut().game().clone());
+    let agent = ScriptedAgent::new(
+        vec![UserAction::GainMana, UserAction::DrawCard, UserAction::GainMana],
+        PickRandomAgent {},
+    );
+
+    assert_eq!(UserAction::GainMana, play(&agent, &mut node));
+    assert_eq!(UserAction::DrawCard, play(&agent, &mut node));
+    assert_eq!(UserAction::GainMana, play(&agent, &mut node));
+
+    let side = node.current_turn().expect("Game over");
+    assert_eq!(Side::Champion, side);
+    let action = agent.pick_action(AgentConfig::with_deadline(10), &node).expect("No action");
+    assert!(node.legal_actions(side).expect("Legal actions").any(|a| a == action));
+}
+
+#[test]
+fn scripted_agent_skips_illegal_action() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    let mut node = SpelldawnState(g.database_mut().game().clone());
+    let agent = ScriptedAgent::new(
+        vec![UserAction::LevelUpRoom(RoomId::RoomA), UserAction::DrawCard],
+        PickRandomAgent {},
+    );
+
+    assert_eq!(UserAction::DrawCard, play(&agent, &mut node));
+}
+
+#[test]
+fn scripted_agent_position_persists_in_game() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    let mut node = SpelldawnState(g.database_mut().game().clone());
+
+    assert_eq!(UserAction::GainMana, play(&*agents::get(NamedPlayer::TestScripted), &mut node));
+    assert_eq!(1, node.player(Side::Overlord).agent.unwrap().script_position);
+    assert_eq!(UserAction::DrawCard, play(&*agents::get(NamedPlayer::TestScripted), &mut node));
+    assert_eq!(UserAction::GainMana, play(&*agents::get(NamedPlayer::TestScripted), &mut node));
+    assert_eq!(3, node.player(Side::Overlord).agent.unwrap().script_position);
+}
@@ -0,0 +1,60 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use ai_core::game_state_node::{GameStateNode, GameStatus};
+use ai_game_integration::state_node::SpelldawnState;
+use cards::decklists;
+use data::game::GameState;
+
+fn summarize(game: &GameState) -> String {
+    format!(
+        "{:?} {:?} {:?} {:?} {:?} {:?}",
+        game.data, game.overlord, game.champion, game.overlord_cards, game.champion_cards, game.rng
+    )
+}
+
+/// Plays up to `count` actions, always choosing the last legal action.
+fn play_actions(node: &mut SpelldawnState, count: usize) {
+    for _ in 0..count {
+        let GameStatus::InProgress { current_turn } = node.status() else {
+            return;
+        };
+        let action = node
+            .legal_actions(current_turn)
+            .expect("Legal actions")
+            .last()
+            .expect("No legal actions");
+        node.execute_action(current_turn, action).expect("Error executing action");
+    }
+}
+
+#[test]
+fn simulation_clone_matches_full_clone() {
+    cards::initialize::run();
+    let game = SpelldawnState(decklists::canonical_game().unwrap());
+    let original = summarize(&game);
+
+    let mut full = SpelldawnState(game.clone());
+    let mut simulation = game.make_copy();
+    assert!(Arc::ptr_eq(&game.delegate_cache.lookup, &simulation.delegate_cache.lookup));
+
+    play_actions(&mut full, 50);
+    play_actions(&mut simulation, 50);
+
+    assert_eq!(summarize(&full), summarize(&simulation));
+    assert_ne!(original, summarize(&simulation));
+    assert_eq!(original, summarize(&game));
+}
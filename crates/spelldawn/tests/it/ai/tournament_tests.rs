@@ -0,0 +1,28 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ai_game_integration::agents::PickRandomAgent;
+use ai_testing::tournament;
+use cards::decklists;
+
+#[test]
+fn parallel_games_total() {
+    cards::initialize::run();
+    let game = decklists::canonical_game().unwrap();
+    let result =
+        tournament::run_games_parallel(&game, &PickRandomAgent {}, &PickRandomAgent {}, 8, 500)
+            .unwrap();
+    assert_eq!(8, result.overlord_wins + result.champion_wins + result.draws);
+    assert_eq!(8, result.games());
+}
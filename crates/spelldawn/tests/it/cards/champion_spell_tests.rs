@@ -17,6 +17,7 @@ use data::card_name::CardName;
 use data::primitives::{RoomId, Side};
 use protos::spelldawn::object_position::Position;
 use protos::spelldawn::{ObjectPositionBrowser, PlayerName};
+use test_utils::client::HasText;
 use test_utils::*;
 
 #[test]
@@ -69,6 +70,7 @@ fn charged_strike() {
     assert_eq!(5, g.user.this_player.bonus_mana());
     assert_eq!(5, g.opponent.other_player.bonus_mana());
     g.click_on(g.user_id(), "Test Weapon");
+    g.click_on(g.user_id(), "Raid Mana");
     assert_eq!(STARTING_MANA - 4, g.me().mana());
     assert_eq!(4, g.user.this_player.bonus_mana());
     assert_eq!(4, g.opponent.other_player.bonus_mana());
@@ -91,6 +93,10 @@ fn preparation() {
     assert_eq!(4, g.user.cards.hand(PlayerName::User).len());
     assert_eq!(1, g.me().actions());
     g.play_from_hand(CardName::Preparation);
-    assert_eq!(7, g.user.cards.hand(PlayerName::User).len()); // One discarded to hand size
+    assert_eq!(8, g.user.cards.hand(PlayerName::User).len());
+    assert!(g.user.interface.controls().has_text("Discard 1"));
+    g.click_on(g.user_id(), "Select");
+    g.click_on(g.user_id(), "Discard");
+    assert_eq!(7, g.user.cards.hand(PlayerName::User).len());
     assert!(g.dusk());
 }
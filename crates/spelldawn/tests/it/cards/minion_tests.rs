@@ -97,6 +97,24 @@ fn temporal_stalker_end_raid() {
     assert_eq!(2, g.opponent.this_player.actions());
 }
 
+#[test]
+fn temporal_stalker_cannot_summon_banished_minion() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.add_to_hand(CardName::TestMinionEndRaid);
+    g.banish(id);
+    assert_eq!(0, g.user.cards.hand(PlayerName::User).len());
+    assert_eq!(0, g.user.cards.discard_pile(PlayerName::User).len());
+    g.play_from_hand(CardName::TemporalStalker);
+    set_up_minion_combat(&mut g);
+    g.click_on(g.opponent_id(), "End Raid");
+    assert!(!g.user.data.raid_active());
+    assert_eq!(
+        vec!["Temporal Stalker"],
+        g.user.cards.room_cards(ROOM_ID, ClientRoomLocation::Front)
+    );
+    assert_eq!(0, g.user.cards.discard_pile(PlayerName::User).len());
+}
+
 #[test]
 fn temporal_stalker_pay_actions() {
     let mut g = new_game(Side::Overlord, Args::default());
@@ -17,6 +17,7 @@ use data::primitives::Side;
 use protos::spelldawn::game_action::Action;
 use protos::spelldawn::object_position::Position;
 use protos::spelldawn::{DrawCardAction, ObjectPositionIdentity, PlayerName};
+use test_utils::client::HasText;
 use test_utils::*;
 
 #[test]
@@ -69,5 +70,11 @@ fn research_project() {
     g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
     g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
     g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
+    assert_eq!(13, g.user.cards.hand(PlayerName::User).len());
+    assert!(g.user.interface.controls().has_text("Discard 4"));
+    for _ in 0..4 {
+        g.click_on(g.user_id(), "Select");
+    }
+    g.click_on(g.user_id(), "Discard");
     assert_eq!(9, g.user.cards.hand(PlayerName::User).len());
 }
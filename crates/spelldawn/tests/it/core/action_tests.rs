@@ -12,18 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use cards::test_cards::{ARTIFACT_COST, MANA_STORED, MANA_TAKEN, UNVEIL_COST};
+use adapters::response_builder::ResponseOptions;
+use cards::test_cards::{
+    ARTIFACT_COST, MANA_STORED, MANA_TAKEN, MINION_HEALTH, UNVEIL_COST, WEAPON_COST,
+};
+use core_ui::actions::InterfaceAction;
+use core_ui::icons;
 use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::game::{
+    GamePhase, GameState, InternalRaidPhase, ManaRestriction, RaidData, RestrictedMana, TurnData,
+};
 use data::game_actions;
-use data::game_actions::UserAction;
-use data::primitives::{RoomId, Side};
+use data::game_actions::{
+    DamageAllocation, GamePrompt, PromptAction, UserAction, USER_ACTION_PAYLOAD_VERSION,
+};
+use data::primitives::{CardId, CardType, RaidId, RoomId, Side};
+use data::random;
+use data::updates::{UpdateTracker, Updates};
 use insta::assert_snapshot;
 use protos::spelldawn::game_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::game_object_identifier::Id;
 use protos::spelldawn::object_position::Position;
 use protos::spelldawn::{
-    card_target, CardTarget, ClientRoomLocation, DrawCardAction, GainManaAction, GameMessageType,
-    LevelUpRoomAction, ObjectPositionDiscardPile, PlayCardAction, PlayerName,
+    card_target, CardIdentifier, CardTarget, ClientRoomLocation, CommandList, DrawCardAction,
+    GainManaAction, GameCommand, GameMessageType, GameObjectIdentifier, GameObjectMove,
+    InitiateRaidAction, LevelUpRoomAction, MoveGameObjectsCommand, ObjectPosition,
+    ObjectPositionDiscardPile, ObjectPositionDiscardPileContainer, ObjectPositionHand,
+    ObjectPositionIntoCard, ObjectPositionRoom, PlayCardAction, PlayerName, RoomIdentifier,
+    SpendActionPointAction,
 };
+use rules::mana::ManaPurpose;
+use rules::{mana, mutations, queries};
+use server::requests;
+use server::requests::{ClientOutOfDateError, IllegalActionError};
+use test_utils::client::{HasText, TestSession};
 use test_utils::summarize::Summary;
 use test_utils::*;
 
@@ -57,6 +81,30 @@ fn connect_to_ongoing() {
     assert_snapshot!(Summary::run(&r3));
 }
 
+#[test]
+fn discard_pile_positions() {
+    let g = new_game(
+        Side::Overlord,
+        Args {
+            discard: Some(CardName::TestOverlordSpell),
+            opponent_discard: Some(CardName::TestChampionSpell),
+            ..Args::default()
+        },
+    );
+
+    for client in [&g.user, &g.opponent] {
+        for player in [PlayerName::User, PlayerName::Opponent] {
+            assert_eq!(
+                Position::DiscardPileContainer(ObjectPositionDiscardPileContainer {
+                    owner: player.into()
+                }),
+                client.data.object_position(Id::DiscardPile(player.into()))
+            );
+            assert_eq!(1, client.cards.discard_pile(player).len());
+        }
+    }
+}
+
 #[test]
 fn draw_card() {
     let mut g = new_game(
@@ -97,6 +145,68 @@ fn cannot_draw_during_raid() {
     assert_error(g.perform_action(Action::DrawCard(DrawCardAction {}), g.user_id()));
 }
 
+/// Sets up a game where the user could otherwise take every main phase action
+/// available to their side, applies `setup` to the game state, and then
+/// asserts that each of those actions is rejected.
+fn assert_main_phase_actions_rejected(side: Side, setup: impl Fn(&mut GameState)) {
+    let mut g = new_game(side, Args { mana: 10, actions: 3, ..Args::default() });
+    let card_id = match side {
+        Side::Overlord => {
+            g.play_from_hand(CardName::TestScheme31);
+            g.add_to_hand(CardName::TestOverlordSpell)
+        }
+        Side::Champion => g.add_to_hand(CardName::TestChampionSpell),
+    };
+    setup(g.database_mut().game_mut());
+
+    assert_error(g.perform_action(Action::GainMana(GainManaAction {}), g.user_id()));
+    assert_error(g.perform_action(Action::DrawCard(DrawCardAction {}), g.user_id()));
+    assert_error(g.perform_action(
+        Action::PlayCard(PlayCardAction { card_id: Some(card_id), target: None }),
+        g.user_id(),
+    ));
+    let level_up = Action::LevelUpRoom(LevelUpRoomAction { room_id: CLIENT_ROOM_ID.into() });
+    assert_error(g.perform_action(level_up, g.user_id()));
+    let raid = Action::InitiateRaid(InitiateRaidAction { room_id: RoomIdentifier::Vault.into() });
+    assert_error(g.perform_action(raid, g.user_id()));
+}
+
+#[test]
+fn main_phase_actions_rejected_during_raid() {
+    for_each_side(|side| {
+        assert_main_phase_actions_rejected(side, |game| {
+            game.data.raid = Some(RaidData {
+                raid_id: RaidId(1),
+                target: RoomId::Vault,
+                internal_phase: InternalRaidPhase::Begin,
+                encounter: None,
+                accessed: vec![],
+                jump_request: None,
+                queued_rooms: vec![],
+                current_room: None,
+            })
+        })
+    });
+}
+
+#[test]
+fn main_phase_actions_rejected_with_own_prompt() {
+    for_each_side(|side| {
+        assert_main_phase_actions_rejected(side, |game| {
+            game.player_mut(side).prompt = Some(GamePrompt::card_actions(vec![]))
+        })
+    });
+}
+
+#[test]
+fn main_phase_actions_rejected_with_opponent_prompt() {
+    for_each_side(|side| {
+        assert_main_phase_actions_rejected(side, |game| {
+            game.player_mut(side.opponent()).prompt = Some(GamePrompt::card_actions(vec![]))
+        })
+    });
+}
+
 #[test]
 fn maximum_hand_size() {
     let mut g = new_game(
@@ -114,7 +224,83 @@ fn maximum_hand_size() {
     spend_actions_until_turn_over(&mut g, Side::Champion);
     assert_eq!(8, g.user.cards.hand(PlayerName::User).len());
     spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert!(g.user.interface.controls().has_text("Discard 1"));
+
+    let minion = hand_cards(&mut g, Side::Overlord)
+        .into_iter()
+        .find(|card_id| g.database_mut().game().card(*card_id).name == CardName::TestMinionEndRaid)
+        .unwrap();
+    g.perform(toggle_discard(minion), g.user_id());
+    g.perform(confirm_discard(), g.user_id());
     assert_eq!(vec!["Test Minion End Raid"], g.user.cards.discard_pile(PlayerName::User));
+    assert_eq!(Side::Champion, g.database_mut().game().data.turn.side);
+}
+
+#[test]
+fn under_maximum_hand_size() {
+    let mut g = new_game(Side::Champion, Args { hand_size: 6, ..Args::default() });
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(!g.user.interface.controls().has_text("Discard"));
+    assert_eq!(6, g.user.cards.hand(PlayerName::User).len());
+    assert_eq!(Side::Overlord, g.database_mut().game().data.turn.side);
+}
+
+#[test]
+fn at_maximum_hand_size() {
+    let mut g = new_game(Side::Champion, Args { hand_size: 7, ..Args::default() });
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(!g.user.interface.controls().has_text("Discard"));
+    assert_eq!(7, g.user.cards.hand(PlayerName::User).len());
+    assert_eq!(Side::Overlord, g.database_mut().game().data.turn.side);
+}
+
+#[test]
+fn over_maximum_hand_size() {
+    let mut g = new_game(Side::Champion, Args { hand_size: 9, ..Args::default() });
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(g.user.interface.controls().has_text("Discard 2"));
+    assert_eq!(Side::Champion, g.database_mut().game().data.turn.side);
+
+    let hand = hand_cards(&mut g, Side::Champion);
+    g.perform(toggle_discard(hand[0]), g.user_id());
+    assert!(g.user.interface.controls().has_text("Deselect"));
+    assert_error(g.perform_action(confirm_discard(), g.user_id()));
+    g.perform(toggle_discard(hand[1]), g.user_id());
+    assert_error(g.perform_action(toggle_discard(hand[2]), g.user_id()));
+    g.perform(toggle_discard(hand[1]), g.user_id());
+    g.perform(toggle_discard(hand[2]), g.user_id());
+
+    g.perform(confirm_discard(), g.user_id());
+    assert_eq!(7, g.user.cards.hand(PlayerName::User).len());
+    assert_eq!(2, g.user.cards.discard_pile(PlayerName::User).len());
+    assert!(!g.user.interface.controls().has_text("Discard 2"));
+    assert_eq!(Side::Overlord, g.database_mut().game().data.turn.side);
+}
+
+#[test]
+fn configured_maximum_hand_size() {
+    let mut g = new_game(Side::Champion, Args { hand_size: 3, ..Args::default() });
+    g.database_mut().game_mut().data.config.maximum_hand_size = Some(2);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(g.user.interface.controls().has_text("Discard 1"));
+
+    let hand = hand_cards(&mut g, Side::Champion);
+    g.perform(toggle_discard(hand[0]), g.user_id());
+    g.perform(confirm_discard(), g.user_id());
+    assert_eq!(2, g.user.cards.hand(PlayerName::User).len());
+    assert_eq!(Side::Overlord, g.database_mut().game().data.turn.side);
+}
+
+fn hand_cards(g: &mut TestSession, side: Side) -> Vec<CardId> {
+    g.database_mut().game().card_list_for_position(side, CardPosition::Hand(side))
+}
+
+fn toggle_discard(card_id: CardId) -> Action {
+    UserAction::PromptAction(PromptAction::ToggleDiscardCard(card_id)).as_game_action().unwrap()
+}
+
+fn confirm_discard() -> Action {
+    UserAction::PromptAction(PromptAction::ConfirmDiscard).as_game_action().unwrap()
 }
 
 #[test]
@@ -138,6 +324,65 @@ fn play_card() {
     );
 }
 
+#[test]
+fn mana_restricted_to_card_type() {
+    let mut g = new_game(Side::Champion, Args { mana: 0, ..Args::default() });
+    mana::gain_restricted_to_type(
+        g.database_mut().game_mut(),
+        Side::Champion,
+        WEAPON_COST,
+        CardType::Weapon,
+    );
+    g.connect(g.user_id()).expect("Connection error");
+    g.connect(g.opponent_id()).expect("Connection error");
+    assert_eq!(0, g.me().mana());
+    assert_eq!(WEAPON_COST, g.me().bonus_mana());
+    assert_eq!(WEAPON_COST, g.opponent.other_player.bonus_mana());
+
+    let spell_id = g.add_to_hand(CardName::TestChampionSpell);
+    assert_error(g.perform_action(
+        Action::PlayCard(PlayCardAction { card_id: Some(spell_id), target: None }),
+        g.user_id(),
+    ));
+
+    g.play_from_hand(CardName::TestWeapon2Attack);
+    assert_eq!(0, g.me().mana());
+    assert_eq!(0, g.me().bonus_mana());
+    assert_identical(vec![CardName::TestWeapon2Attack], g.user.cards.left_items());
+    assert!(g.database_mut().game().player(Side::Champion).mana_state.restricted_mana.is_empty());
+}
+
+#[test]
+fn affordable_cards_in_hand() {
+    let mut g = new_game(Side::Champion, Args { mana: 0, ..Args::default() });
+    let spell = server_card_id(g.add_to_hand(CardName::TestChampionSpell));
+    let weapon = server_card_id(g.add_to_hand(CardName::TestWeapon2Attack));
+    g.add_to_hand(CardName::TestChampionSpellBanishDiscard);
+    let affordable = |g: &mut TestSession| {
+        queries::affordable_cards_in_hand(g.database_mut().game(), Side::Champion)
+    };
+    assert!(affordable(&mut g).is_empty());
+
+    mana::set(g.database_mut().game_mut(), Side::Champion, 1);
+    assert_contents_equal(affordable(&mut g), vec![spell]);
+
+    mana::set(g.database_mut().game_mut(), Side::Champion, WEAPON_COST);
+    assert_contents_equal(affordable(&mut g), vec![spell, weapon]);
+}
+
+#[test]
+fn affordable_targeted_card_in_hand() {
+    let mut g = new_game(
+        Side::Champion,
+        Args { opponent_discard: Some(CardName::TestOverlordSpell), ..Args::default() },
+    );
+    let banish = server_card_id(g.add_to_hand(CardName::TestChampionSpellBanishDiscard));
+    assert_eq!(
+        vec![banish],
+        queries::affordable_cards_in_hand(g.database_mut().game(), Side::Champion)
+    );
+}
+
 #[test]
 fn play_hidden_card() {
     let mut g = new_game(Side::Overlord, Args { actions: 3, mana: 0, ..Args::default() });
@@ -164,6 +409,309 @@ fn play_hidden_card() {
     assert_eq!(vec![HIDDEN_CARD], g.opponent.cards.room_cards(ROOM_ID, ClientRoomLocation::Back));
 }
 
+#[test]
+fn play_location_matches_release_position() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, mana: 10, ..Args::default() });
+    for (name, location) in [
+        (CardName::TestMinionEndRaid, ClientRoomLocation::Front),
+        (CardName::TestScheme31, ClientRoomLocation::Back),
+    ] {
+        let card_id = g.add_to_hand(name);
+        assert_eq!(
+            Position::Room(ObjectPositionRoom {
+                room_id: RoomIdentifier::Unspecified.into(),
+                room_location: location.into()
+            }),
+            g.user.cards.get(card_id).on_release_position()
+        );
+
+        g.play_card(
+            card_id,
+            g.user_id(),
+            Some(CardTarget {
+                card_target: Some(card_target::CardTarget::RoomId(CLIENT_ROOM_ID.into())),
+            }),
+        );
+        assert_identical(vec![name], g.user.cards.room_cards(ROOM_ID, location));
+    }
+}
+
+#[test]
+fn cancel_targeted_play() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, mana: 5, ..Args::default() });
+    let card_id = g.add_to_hand(CardName::ChargedStrike);
+    g.play_card(card_id, g.user_id(), None);
+
+    assert_eq!(2, g.me().actions());
+    assert_eq!(4, g.me().mana());
+    assert!(g.user.cards.hand(PlayerName::User).is_empty());
+    assert!(g.user.interface.controls().has_text("Sanctum"));
+    assert!(g.legal_actions(Side::Champion).contains(&UserAction::CancelPrompt));
+
+    g.click_on(g.user_id(), "Cancel");
+    assert_eq!(3, g.me().actions());
+    assert_eq!(5, g.me().mana());
+    assert_eq!(3, g.opponent.other_player.actions());
+    assert_identical(vec![CardName::ChargedStrike], g.user.cards.hand(PlayerName::User));
+    assert!(!g.user.interface.controls().has_text("Cancel"));
+}
+
+#[test]
+fn cancel_targeted_play_refunds_restricted_mana() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, mana: 5, ..Args::default() });
+    mana::gain_restricted_to_type(
+        g.database_mut().game_mut(),
+        Side::Champion,
+        1,
+        CardType::ChampionSpell,
+    );
+    let card_id = g.add_to_hand(CardName::ChargedStrike);
+    g.play_card(card_id, g.user_id(), None);
+
+    let mana_state = &g.database_mut().game().player(Side::Champion).mana_state;
+    assert_eq!(5, mana_state.base_mana);
+    assert!(mana_state.restricted_mana.is_empty());
+
+    g.click_on(g.user_id(), "Cancel");
+    let mana_state = &g.database_mut().game().player(Side::Champion).mana_state;
+    assert_eq!(5, mana_state.base_mana);
+    assert_eq!(
+        vec![RestrictedMana {
+            restriction: ManaRestriction::OnlyForCardType(CardType::ChampionSpell),
+            mana: 1
+        }],
+        mana_state.restricted_mana
+    );
+    assert_eq!(3, g.me().actions());
+    assert_identical(vec![CardName::ChargedStrike], g.user.cards.hand(PlayerName::User));
+}
+
+#[test]
+fn refund_raid_specific_mana() {
+    let mut g = new_game(Side::Champion, Args { mana: 5, add_raid: true, ..Args::default() });
+    let game = g.database_mut().game_mut();
+    mana::gain_for_current_raid(game, Side::Champion, 3).expect("Error gaining mana");
+    let payment =
+        mana::spend(game, Side::Champion, ManaPurpose::AllSources, 4).expect("Error spending");
+    assert_eq!(1, payment.base_mana);
+    assert_eq!(4, game.player(Side::Champion).mana_state.base_mana);
+    assert_eq!(0, mana::get(game, Side::Champion, ManaPurpose::BonusForDisplay));
+
+    mana::refund(game, Side::Champion, &payment);
+    assert_eq!(5, game.player(Side::Champion).mana_state.base_mana);
+    assert_eq!(3, mana::get(game, Side::Champion, ManaPurpose::BonusForDisplay));
+}
+
+#[test]
+fn select_target_for_targeted_play() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, mana: 5, ..Args::default() });
+    let card_id = g.add_to_hand(CardName::ChargedStrike);
+    g.play_card(card_id, g.user_id(), None);
+    g.click_on(g.user_id(), "Vault");
+
+    assert_eq!(2, g.me().actions());
+    assert!(g.user.data.raid_active());
+    assert_identical(vec![CardName::ChargedStrike], g.user.cards.discard_pile(PlayerName::User));
+}
+
+#[test]
+fn banish_from_opponent_discard() {
+    let mut g = new_game(Side::Champion, Args { turn: Some(Side::Overlord), ..Args::default() });
+    let spell_id = g.add_to_hand(CardName::TestOverlordSpell);
+    g.play_card(spell_id, g.opponent_id(), None);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert_identical(
+        vec![CardName::TestOverlordSpell],
+        g.user.cards.discard_pile(PlayerName::Opponent),
+    );
+
+    let card_id = g.add_to_hand(CardName::TestChampionSpellBanishDiscard);
+    g.play_card(card_id, g.user_id(), None);
+    g.click_on(g.user_id(), "Test Overlord Spell");
+
+    assert!(g.user.cards.discard_pile(PlayerName::Opponent).is_empty());
+    assert!(g.opponent.cards.discard_pile(PlayerName::User).is_empty());
+    assert_identical(
+        vec![CardName::TestChampionSpellBanishDiscard],
+        g.user.cards.discard_pile(PlayerName::User),
+    );
+}
+
+#[test]
+fn cannot_banish_from_empty_opponent_discard() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let card_id = g.add_to_hand(CardName::TestChampionSpellBanishDiscard);
+    assert_error(g.perform_action(
+        Action::PlayCard(PlayCardAction { card_id: Some(card_id), target: None }),
+        g.user_id(),
+    ));
+}
+
+#[test]
+fn failed_play_consumes_no_resources() {
+    let mut g = new_game(Side::Champion, Args { mana: 5, ..Args::default() });
+    let card_id = g.add_to_hand(CardName::TestChampionSpellCastFails);
+    let actions = g.me().actions();
+    let hand_size = g.user.cards.hand(PlayerName::User).len();
+
+    assert_error(g.perform_action(
+        Action::PlayCard(PlayCardAction { card_id: Some(card_id), target: None }),
+        g.user_id(),
+    ));
+
+    g.connect(g.user_id()).expect("Connection error");
+    assert_eq!(5, g.me().mana());
+    assert_eq!(actions, g.me().actions());
+    assert_eq!(hand_size, g.user.cards.hand(PlayerName::User).len());
+    assert!(g.user.get_card(card_id).can_play());
+}
+
+#[test]
+fn failed_action_keeps_undo_history_and_updates() {
+    let mut g = new_game(Side::Champion, Args { mana: 5, ..Args::default() });
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    let card_id = server_card_id(g.add_to_hand(CardName::TestChampionSpellCastFails));
+    let game = g.database_mut().game_mut();
+    game.updates = UpdateTracker::new(Updates::Push);
+    let actions = game.champion.actions;
+
+    assert_error(actions::handle_user_action(
+        game,
+        Side::Champion,
+        UserAction::PlayCard(card_id, game_actions::CardTarget::None),
+    ));
+    assert_eq!(6, game.champion.mana_state.base_mana);
+    assert_eq!(actions, game.champion.actions);
+    assert_eq!(CardPosition::Hand(Side::Champion), game.card(card_id).position());
+    assert_eq!(1, game.undo_history.len());
+    assert_eq!(Updates::Push, game.updates.state);
+    assert!(game.updates.steps.is_empty());
+}
+
+#[test]
+fn failed_variable_cost_play_consumes_no_resources() {
+    let mut g = new_game(Side::Champion, Args { mana: 5, ..Args::default() });
+    let card_id = g.add_to_hand(CardName::TestVariableCostSpellCastFails);
+    g.play_card(card_id, g.user_id(), None);
+    let actions = g.me().actions();
+
+    let pay = g
+        .user
+        .interface
+        .controls()
+        .find_handlers(format!("Pay 3{}", icons::MANA))
+        .and_then(|handlers| handlers.on_click)
+        .and_then(|on_click| on_click.action)
+        .expect("Pay button");
+    assert_error(g.perform_action(pay, g.user_id()));
+
+    g.connect(g.user_id()).expect("Connection error");
+    assert_eq!(5, g.me().mana());
+    assert_eq!(actions, g.me().actions());
+    assert!(g.user.interface.controls().has_text(format!("Pay 3{}", icons::MANA)));
+}
+
+#[test]
+fn play_variable_cost_card() {
+    let mut g = new_game(Side::Champion, Args { mana: 5, ..Args::default() });
+    let card_id = g.add_to_hand(CardName::TestVariableCostSpell);
+    assert_eq!("X", g.user.get_card(card_id).top_left_icon());
+    assert!(g.user.get_card(card_id).can_play());
+
+    g.play_card(card_id, g.user_id(), None);
+    assert!(g.user.interface.controls().has_text(format!("Pay 5{}", icons::MANA)));
+    assert!(!g.user.interface.controls().has_text(format!("Pay 6{}", icons::MANA)));
+
+    g.click_on(g.user_id(), format!("Pay 3{}", icons::MANA));
+    assert_eq!(2, g.me().mana());
+    assert_eq!(2, g.opponent.other_player.mana());
+    assert_eq!(2, g.me().actions());
+    assert_eq!(3, g.user.cards.hand(PlayerName::User).len());
+    assert_identical(
+        vec![CardName::TestVariableCostSpell],
+        g.user.cards.discard_pile(PlayerName::User),
+    );
+}
+
+#[test]
+fn distribute_damage_among_minions() {
+    let mut g = new_game(Side::Champion, Args { turn: Some(Side::Overlord), ..Args::default() });
+    let first = g.play_with_target_room(CardName::TestMinionEndRaid, RoomId::RoomA);
+    let second = g.play_with_target_room(CardName::TestMinionEndRaid, RoomId::RoomB);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    let not_target = g.add_to_hand(CardName::TestChampionSpell);
+    g.play_from_hand(CardName::TestChampionSpellDistributeDamage);
+    assert!(g.user.interface.controls().has_text("Divide 3 damage"));
+
+    let distribute = |entries: [(CardIdentifier, u32); 2]| {
+        UserAction::PromptAction(PromptAction::DamageDistribution(
+            DamageAllocation::new(entries.map(|(id, amount)| (server_card_id(id), amount)))
+                .unwrap(),
+        ))
+        .as_game_action()
+        .unwrap()
+    };
+    // Over-allocation
+    assert_error(g.perform_action(distribute([(first, 2), (second, 2)]), g.user_id()));
+    // Under-allocation
+    assert_error(g.perform_action(distribute([(first, 1), (second, 1)]), g.user_id()));
+    assert_error(g.perform_action(distribute([(first, 0), (second, 0)]), g.user_id()));
+    // Card which is not a target of this prompt
+    assert_error(g.perform_action(distribute([(first, 2), (not_target, 1)]), g.user_id()));
+    assert!(g.user.interface.controls().has_text("Divide 3 damage"));
+
+    g.perform(distribute([(first, 2), (second, 1)]), g.user_id());
+    assert_eq!((MINION_HEALTH - 2).to_string(), g.opponent.get_card(first).bottom_right_icon());
+    assert_eq!((MINION_HEALTH - 1).to_string(), g.opponent.get_card(second).bottom_right_icon());
+    assert!(!g.user.interface.controls().has_text("Divide"));
+}
+
+#[test]
+fn damage_prompt_delays_end_of_turn_and_damage_is_cleared() {
+    let mut g = new_game(Side::Champion, Args { turn: Some(Side::Overlord), ..Args::default() });
+    let minion = g.play_with_target_room(CardName::TestMinionEndRaid, RoomId::RoomA);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    while g.me().actions() > 1 {
+        g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    }
+    g.play_from_hand(CardName::TestChampionSpellDistributeDamage);
+    assert_eq!(0, g.me().actions());
+    assert!(g.user.this_player.can_take_action());
+
+    let distribute = UserAction::PromptAction(PromptAction::DamageDistribution(
+        DamageAllocation::new([(server_card_id(minion), 3)]).unwrap(),
+    ))
+    .as_game_action()
+    .unwrap();
+    g.perform(distribute, g.user_id());
+    assert!(g.opponent.this_player.can_take_action());
+    assert_eq!(MINION_HEALTH.to_string(), g.opponent.get_card(minion).bottom_right_icon());
+}
+
+#[test]
+fn choose_room_prompt() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let scheme = g.play_with_target_room(CardName::TestScheme31, RoomId::RoomB);
+    g.play_from_hand(CardName::TestOverlordSpellChooseRoom);
+    assert!(g.user.interface.controls().has_text("Choose a room"));
+    assert!(g.user.interface.controls().has_text("Room B"));
+
+    let choose = |room_id| {
+        UserAction::PromptAction(PromptAction::RoomChosen(room_id)).as_game_action().unwrap()
+    };
+    assert_error(g.perform_action(choose(RoomId::Vault), g.user_id()));
+
+    g.perform(choose(RoomId::RoomB), g.user_id());
+    assert_eq!(1, g.database_mut().game().card(server_card_id(scheme)).data.card_level);
+    assert!(!g.user.interface.controls().has_text("Choose a room"));
+}
+
+#[test]
+fn cannot_cancel_without_prompt() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, ..Args::default() });
+    assert_error(g.perform_action(UserAction::CancelPrompt.as_game_action().unwrap(), g.user_id()));
+}
+
 #[test]
 fn cannot_play_card_on_opponent_turn() {
     let mut g = new_game(Side::Overlord, Args::default());
@@ -184,6 +732,93 @@ fn cannot_play_card_when_out_of_action_points() {
     ));
 }
 
+#[test]
+fn illegal_action_error_has_legal_actions() {
+    let mut g = new_game(Side::Champion, Args { mana: 0, ..Args::default() });
+    let card_id = g.add_to_hand(CardName::ArcaneRecovery);
+    let error = g
+        .perform_action(
+            Action::PlayCard(PlayCardAction { card_id: Some(card_id), target: None }),
+            g.user_id(),
+        )
+        .unwrap_err();
+    let illegal = error.downcast_ref::<IllegalActionError>().expect("IllegalActionError");
+    assert!(illegal.legal_actions.contains(&UserAction::GainMana));
+    assert!(illegal.legal_actions.contains(&UserAction::DrawCard));
+    assert!(!illegal.legal_actions.iter().any(|a| matches!(a, UserAction::PlayCard(..))));
+}
+
+#[test]
+fn failure_of_legal_action_is_not_illegal_action_error() {
+    let mut g = new_game(Side::Champion, Args { mana: 5, ..Args::default() });
+    let card_id = g.add_to_hand(CardName::TestChampionSpellCastFails);
+    let error = g
+        .perform_action(
+            Action::PlayCard(PlayCardAction { card_id: Some(card_id), target: None }),
+            g.user_id(),
+        )
+        .unwrap_err();
+    assert!(error.downcast_ref::<IllegalActionError>().is_none());
+}
+
+#[test]
+fn mismatched_action_version_requires_upgrade() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let Some(Action::StandardAction(mut action)) = UserAction::GainMana.as_game_action() else {
+        panic!("Expected StandardAction");
+    };
+    action.payload[0] = USER_ACTION_PAYLOAD_VERSION + 1;
+    let error = g.perform_action(Action::StandardAction(action), g.user_id()).unwrap_err();
+    let out_of_date = error.downcast_ref::<ClientOutOfDateError>().expect("ClientOutOfDateError");
+    assert_eq!(USER_ACTION_PAYLOAD_VERSION + 1, out_of_date.client_version);
+    assert_ok(&g.perform_action(UserAction::GainMana.as_game_action().unwrap(), g.user_id()));
+}
+
+#[test]
+fn optimistic_update_mismatch() {
+    fn predicting(card_id: CardIdentifier, position: Position) -> CommandList {
+        CommandList {
+            commands: vec![GameCommand {
+                command: Some(Command::MoveGameObjects(MoveGameObjectsCommand {
+                    moves: vec![GameObjectMove {
+                        id: Some(GameObjectIdentifier { id: Some(Id::CardId(card_id)) }),
+                        position: Some(ObjectPosition {
+                            position: Some(position),
+                            ..ObjectPosition::default()
+                        }),
+                    }],
+                    disable_animation: false,
+                    delay: None,
+                })),
+            }],
+        }
+    }
+
+    let mut g = new_game(Side::Champion, Args::default());
+    let card_id = g.add_to_hand(CardName::TestChampionSpell);
+    let hand =
+        predicting(card_id, Position::Hand(ObjectPositionHand { owner: PlayerName::User.into() }));
+    let discard = predicting(
+        card_id,
+        Position::DiscardPile(ObjectPositionDiscardPile { owner: PlayerName::User.into() }),
+    );
+
+    // Checks are never disabled once enabled and the counter only increases,
+    // so other tests running concurrently cannot make this assertion fail.
+    requests::enable_optimistic_update_checks();
+    let mismatches = requests::optimistic_update_mismatches();
+    let Some(Action::StandardAction(mut action)) = UserAction::GainMana.as_game_action() else {
+        panic!("Expected StandardAction");
+    };
+    action.update = Some(discard.clone());
+    g.perform(Action::StandardAction(action), g.user_id());
+    assert!(requests::optimistic_update_mismatches() > mismatches);
+
+    let game = g.database_mut().game();
+    assert!(requests::mismatched_cards(game, Side::Champion, &hand).unwrap().is_empty());
+    assert_eq!(vec![card_id], requests::mismatched_cards(game, Side::Champion, &discard).unwrap());
+}
+
 #[test]
 fn cannot_play_card_during_raid() {
     let mut g = new_game(Side::Champion, Args { add_raid: true, ..Args::default() });
@@ -207,22 +842,93 @@ fn gain_mana() {
     assert_snapshot!(Summary::run(&response));
 }
 
+#[test]
+fn gain_mana_scalar_delta() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, mana: 5, ..Args::default() });
+    let game = g.database_mut().game_mut();
+    let previous = game.clone();
+    actions::handle_user_action(game, Side::Overlord, UserAction::GainMana).unwrap();
+    let commands = display::render::render_updates_with_options(
+        Some(&previous),
+        game,
+        Side::Overlord,
+        ResponseOptions { scalar_deltas: true, ..ResponseOptions::default() },
+    )
+    .unwrap();
+
+    assert_eq!(1, commands.len());
+    match &commands[0] {
+        Command::UpdatePlayerScalars(update) => {
+            let user = update.user.as_ref().expect("user");
+            assert_eq!(6, user.mana.as_ref().expect("mana").base_mana);
+            assert_eq!(2, user.action_tracker.as_ref().expect("actions").available_action_count);
+            assert!(user.score.is_none());
+            assert!(user.can_take_action.is_none());
+            assert!(update.opponent.is_none());
+        }
+        command => panic!("Expected UpdatePlayerScalars, got {:?}", command),
+    }
+}
+
+#[test]
+fn play_hidden_card_card_delta() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, mana: 0, ..Args::default() });
+    let card_id = g.add_to_hand(CardName::GoldMine);
+    let game = g.database_mut().game_mut();
+    game.updates = UpdateTracker::new(Updates::Ignore);
+    let previous = game.clone();
+    actions::handle_user_action(
+        game,
+        Side::Overlord,
+        UserAction::PlayCard(server_card_id(card_id), game_actions::CardTarget::Room(ROOM_ID)),
+    )
+    .unwrap();
+    let commands = display::render::render_updates_with_options(
+        Some(&previous),
+        game,
+        Side::Champion,
+        ResponseOptions { card_deltas: true, ..ResponseOptions::default() },
+    )
+    .unwrap();
+
+    assert!(commands
+        .iter()
+        .all(|c| !matches!(c, Command::UpdateGameView(_) | Command::UpdateCards(_))));
+    let moves = commands
+        .iter()
+        .find_map(|c| match c {
+            Command::MoveGameObjects(m) => Some(m.moves.clone()),
+            _ => None,
+        })
+        .expect("MoveGameObjects");
+    assert_eq!(1, moves.len());
+    assert_eq!(Some(GameObjectIdentifier { id: Some(Id::CardId(card_id)) }), moves[0].id.clone());
+}
+
 #[test]
 fn cannot_gain_mana_on_opponent_turn() {
-    let mut g = new_game(Side::Overlord, Args::default());
-    assert_error(g.perform_action(Action::GainMana(GainManaAction {}), g.opponent_id()));
+    for_each_side(|side| {
+        let mut g = new_game(side, Args::default());
+        assert_error(g.perform_action(Action::GainMana(GainManaAction {}), g.opponent_id()));
+        assert_player_views_match(&g);
+    });
 }
 
 #[test]
 fn cannot_gain_mana_when_out_of_action_points() {
-    let mut g = new_game(Side::Overlord, Args { actions: 0, ..Args::default() });
-    assert_error(g.perform_action(Action::GainMana(GainManaAction {}), g.user_id()));
+    for_each_side(|side| {
+        let mut g = new_game(side, Args { actions: 0, ..Args::default() });
+        assert_error(g.perform_action(Action::GainMana(GainManaAction {}), g.user_id()));
+        assert_player_views_match(&g);
+    });
 }
 
 #[test]
 fn cannot_gain_mana_during_raid() {
-    let mut g = new_game(Side::Overlord, Args { add_raid: true, ..Args::default() });
-    assert_error(g.perform_action(Action::GainMana(GainManaAction {}), g.user_id()));
+    for_each_side(|side| {
+        let mut g = new_game(side, Args { add_raid: true, ..Args::default() });
+        assert_error(g.perform_action(Action::GainMana(GainManaAction {}), g.user_id()));
+    });
 }
 
 #[test]
@@ -269,6 +975,20 @@ fn score_overlord_card() {
     assert_eq!(g.opponent.other_player.score(), 1);
 }
 
+#[test]
+fn set_card_level_scores_scheme() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let scheme_id = g.play_from_hand(CardName::TestScheme31);
+    mutations::set_card_level(g.database_mut().game_mut(), server_card_id(scheme_id), 3)
+        .expect("Error setting level");
+    g.connect(g.user_id()).expect("Connection error");
+    g.connect(g.opponent_id()).expect("Connection error");
+
+    assert!(g.opponent.cards.get(scheme_id).revealed_to_me());
+    assert_eq!(g.user.this_player.score(), 1);
+    assert_eq!(g.opponent.other_player.score(), 1);
+}
+
 #[test]
 fn overlord_win_game() {
     let mut g =
@@ -284,6 +1004,70 @@ fn overlord_win_game() {
     assert_eq!(g.opponent.data.last_message(), GameMessageType::Defeat);
 }
 
+#[test]
+fn gain_points_win_game() {
+    let mut g = new_game(Side::Overlord, Args { score: 5, ..Args::default() });
+    mutations::gain_points(g.database_mut().game_mut(), Side::Overlord, 2)
+        .expect("Error gaining points");
+    assert!(matches!(
+        g.database_mut().game().data.phase,
+        GamePhase::GameOver { winner: Side::Overlord }
+    ));
+}
+
+#[test]
+fn lose_points_clamped_at_zero() {
+    let mut g = new_game(Side::Overlord, Args { score: 1, opponent_score: 3, ..Args::default() });
+    mutations::lose_points(g.database_mut().game_mut(), Side::Overlord, 2)
+        .expect("Error losing points");
+    mutations::lose_points(g.database_mut().game_mut(), Side::Champion, 2)
+        .expect("Error losing points");
+    g.connect(g.user_id()).expect("Connection error");
+
+    assert_eq!(0, g.me().score());
+    assert_eq!(1, g.user.other_player.score());
+}
+
+#[test]
+fn disclose_hands_on_game_over() {
+    let mut g =
+        new_game(Side::Overlord, Args { mana: 10, score: 6, actions: 5, ..Args::default() });
+    g.database_mut().game_mut().data.config.disclose_on_game_over = true;
+    let overlord_card = g.add_to_hand(CardName::TestOverlordSpell);
+    let champion_card = g.add_to_hand(CardName::TestChampionSpell);
+    g.play_from_hand(CardName::TestScheme31);
+    let level_up = Action::LevelUpRoom(LevelUpRoomAction { room_id: CLIENT_ROOM_ID.into() });
+    g.perform(level_up.clone(), g.user_id());
+    g.perform(level_up.clone(), g.user_id());
+    assert!(!g.opponent.cards.get(overlord_card).revealed_to_me());
+    assert!(!g.user.cards.get(champion_card).revealed_to_me());
+
+    g.perform(level_up, g.user_id());
+    assert_eq!(g.user.data.last_message(), GameMessageType::Victory);
+    assert!(g.opponent.cards.get(overlord_card).revealed_to_me());
+    assert!(g.user.cards.get(champion_card).revealed_to_me());
+}
+
+#[test]
+fn unique_card_discards_older_copy() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let first = g.play_from_hand(CardName::TestUniqueArtifact);
+    let second = g.play_from_hand(CardName::TestUniqueArtifact);
+
+    let in_play = [g.user.cards.left_items(), g.user.cards.right_items()].concat();
+    assert_identical(vec![CardName::TestUniqueArtifact], in_play);
+    assert_identical(
+        vec![CardName::TestUniqueArtifact],
+        g.user.cards.discard_pile(PlayerName::User),
+    );
+    assert_identical(
+        vec![CardName::TestUniqueArtifact],
+        g.opponent.cards.discard_pile(PlayerName::Opponent),
+    );
+    assert!(g.database_mut().game().card(server_card_id(first)).position().in_discard_pile());
+    assert!(g.database_mut().game().card(server_card_id(second)).position().in_play());
+}
+
 #[test]
 fn switch_turn() {
     let mut g = new_game(Side::Overlord, Args { actions: 3, mana: 5, ..Args::default() });
@@ -304,6 +1088,137 @@ fn switch_turn() {
     assert!(!g.opponent.other_player.can_take_action());
 }
 
+#[test]
+fn carry_over_actions() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    g.database_mut().game_mut().data.config.carry_over_actions = Some(2);
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    g.perform(UserAction::EndTurn.as_game_action().unwrap(), g.user_id());
+    assert!(g.dawn());
+    assert_eq!(0, g.me().actions());
+    assert_eq!(3, g.you().actions());
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(g.dusk());
+    assert_eq!(5, g.me().actions());
+}
+
+#[test]
+fn carry_over_actions_capped() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    g.database_mut().game_mut().data.config.carry_over_actions = Some(1);
+    g.perform(UserAction::EndTurn.as_game_action().unwrap(), g.user_id());
+    assert!(g.dawn());
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(g.dusk());
+    assert_eq!(4, g.me().actions());
+}
+
+#[test]
+fn cannot_end_turn_without_carry_over() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    assert_error(g.perform_action(UserAction::EndTurn.as_game_action().unwrap(), g.user_id()));
+    assert_eq!(3, g.me().actions());
+    assert!(g.user.this_player.can_take_action());
+}
+
+#[test]
+fn extra_turn() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, ..Args::default() });
+    g.play_from_hand(CardName::TestChampionSpellExtraTurn);
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert!(g.dawn());
+    assert_eq!(3, g.me().actions());
+    assert!(g.user.this_player.can_take_action());
+    assert!(!g.user.other_player.can_take_action());
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(g.dusk());
+    assert!(!g.user.this_player.can_take_action());
+    assert!(g.user.other_player.can_take_action());
+}
+
+#[test]
+fn extra_turn_for_opponent() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, ..Args::default() });
+    let first = g.database_mut().game().data.turn;
+    mutations::grant_extra_turn(g.database_mut().game_mut(), Side::Overlord).unwrap();
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(g.dusk());
+
+    // Spends the actions for both the normal and the extra Overlord turn
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert!(g.dawn());
+
+    let game = g.database_mut().game();
+    let overlord_turn = first.turn_number + 1;
+    assert_eq!(
+        vec![
+            first,
+            TurnData { side: Side::Overlord, turn_number: overlord_turn },
+            TurnData { side: Side::Overlord, turn_number: overlord_turn + 1 },
+        ],
+        game.data.turn_history
+    );
+    assert!(game.data.extra_turns.is_empty());
+}
+
+#[test]
+fn turn_history() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    let first = g.database_mut().game().data.turn;
+    assert!(g.database_mut().game().data.turn_history.is_empty());
+
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+
+    let game = g.database_mut().game();
+    assert_eq!(
+        vec![
+            first,
+            TurnData { side: Side::Champion, turn_number: first.turn_number },
+            TurnData { side: Side::Overlord, turn_number: first.turn_number + 1 },
+        ],
+        game.data.turn_history
+    );
+    assert_eq!(2, queries::turns_taken(game, Side::Overlord));
+    assert_eq!(1, queries::turns_taken(game, Side::Champion));
+}
+
+#[test]
+fn turn_change_displays_one_message() {
+    fn messages(commands: &CommandList) -> Vec<GameMessageType> {
+        commands
+            .commands
+            .iter()
+            .filter_map(|c| match &c.command {
+                Some(Command::DisplayGameMessage(m)) => GameMessageType::from_i32(m.message_type),
+                _ => None,
+            })
+            .collect()
+    }
+
+    let mut g = new_game(Side::Overlord, Args { actions: 1, ..Args::default() });
+    for (side, expected) in [
+        (Side::Overlord, GameMessageType::Dawn),
+        (Side::Champion, GameMessageType::Dusk),
+        (Side::Overlord, GameMessageType::Dawn),
+        (Side::Champion, GameMessageType::Dusk),
+    ] {
+        let id = g.player_id_for_side(side);
+        while g.player(id).this_player.actions() > 0 {
+            let response = g
+                .perform_action(Action::SpendActionPoint(SpendActionPointAction {}), id)
+                .expect("Error spending action point");
+            let (_, opponent_commands) = response.opponent_response.expect("Opponent response");
+            let expected =
+                if g.player(id).this_player.actions() == 0 { vec![expected] } else { vec![] };
+            assert_eq!(expected, messages(&response.command_list));
+            assert_eq!(expected, messages(&opponent_commands));
+        }
+    }
+}
+
 #[test]
 fn activate_ability() {
     let mut g = new_game(Side::Champion, Args { actions: 3, ..Args::default() });
@@ -329,6 +1244,40 @@ fn activate_ability() {
     assert_eq!(1, g.me().actions());
 }
 
+#[test]
+fn activate_ability_returns_token_to_card() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, ..Args::default() });
+    let card_id = g.play_from_hand(CardName::TestActivatedAbilityTakeMana);
+    let ability_card_id = ability_id(card_id, 1);
+
+    let response = g
+        .perform_action(
+            Action::PlayCard(PlayCardAction { card_id: Some(ability_card_id), target: None }),
+            g.user_id(),
+        )
+        .expect("Error activating ability");
+    let (_, opponent_commands) = response.opponent_response.expect("Opponent response");
+    let commands =
+        opponent_commands.commands.iter().filter_map(|c| c.command.as_ref()).collect::<Vec<_>>();
+    let moved = commands
+        .iter()
+        .position(|c| match c {
+            Command::MoveGameObjects(move_objects) => move_objects.moves.iter().any(|m| {
+                m.id == Some(GameObjectIdentifier { id: Some(Id::CardId(ability_card_id)) })
+                    && m.position.as_ref().and_then(|p| p.position.clone())
+                        == Some(Position::IntoCard(ObjectPositionIntoCard {
+                            card_id: Some(card_id),
+                        }))
+            }),
+            _ => false,
+        })
+        .expect("Expected token to move into card");
+    assert!(matches!(
+        commands.get(moved + 1),
+        Some(Command::DestroyCard(destroy)) if destroy.card_id == Some(ability_card_id)
+    ));
+}
+
 #[test]
 fn activate_ability_take_all_mana() {
     let mut g = new_game(Side::Champion, Args { actions: 3, ..Args::default() });
@@ -367,6 +1316,34 @@ fn activate_ability_take_all_mana() {
     );
 }
 
+#[test]
+fn store_and_withdraw_mana() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, ..Args::default() });
+    let id = g.play_from_hand(CardName::TestManaBattery);
+    for stored in 1..=2 {
+        spend_actions_until_turn_over(&mut g, Side::Champion);
+        spend_actions_until_turn_over(&mut g, Side::Overlord);
+        assert!(g.dawn());
+        assert_eq!(stored.to_string(), g.user.get_card(id).arena_icon());
+        assert_eq!(stored.to_string(), g.opponent.get_card(id).arena_icon());
+    }
+
+    let mana = g.me().mana();
+    let ability_card_id = g
+        .user
+        .cards
+        .cards_in_hand(PlayerName::User)
+        .find(|c| c.id().ability_id.is_some())
+        .expect("ability card")
+        .id();
+    g.perform(
+        Action::PlayCard(PlayCardAction { card_id: Some(ability_card_id), target: None }),
+        g.user_id(),
+    );
+    assert_eq!(mana + 2, g.me().mana());
+    assert_eq!(mana + 2, g.opponent.other_player.mana());
+}
+
 #[test]
 fn triggered_ability() {
     let mut g = new_game(Side::Overlord, Args { actions: 1, ..Args::default() });
@@ -484,3 +1461,160 @@ fn champion_legal_actions() {
         ],
     );
 }
+
+#[test]
+fn resign() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.perform(UserAction::Resign.as_game_action().unwrap(), g.user_id());
+    assert!(g.is_victory_for_player(Side::Overlord));
+    assert_error(g.perform_action(UserAction::Resign.as_game_action().unwrap(), g.opponent_id()));
+}
+
+#[test]
+fn resign_during_raid() {
+    let mut g = new_game(Side::Champion, Args::default());
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    g.perform(UserAction::Resign.as_game_action().unwrap(), g.opponent_id());
+    assert!(g.is_victory_for_player(Side::Champion));
+}
+
+#[test]
+fn render_updates_for_simulation_game() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let game = g.database_mut().game_mut();
+    game.data.config.simulation = true;
+    game.updates = UpdateTracker::new(Updates::Ignore);
+    let commands = display::render::render_updates(game, Side::Overlord).unwrap();
+    assert!(!commands.is_empty());
+
+    let response = g.perform_action(Action::GainMana(GainManaAction {}), g.user_id());
+    assert!(response.is_ok());
+}
+
+#[test]
+fn discard_pile_recency_order() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let spells = [
+        CardName::TestChampionSpell,
+        CardName::Test0CostChampionSpell,
+        CardName::Test1CostChampionSpell,
+    ];
+    for spell in spells {
+        g.play_from_hand(spell);
+        assert_eq!(Some(spell.displayed_name()), g.user.cards.discard_pile_top(PlayerName::User));
+        assert_eq!(
+            Some(spell.displayed_name()),
+            g.opponent.cards.discard_pile_top(PlayerName::Opponent)
+        );
+    }
+
+    assert_eq!(
+        spells.iter().map(CardName::displayed_name).collect::<Vec<_>>(),
+        g.user.cards.discard_pile_by_recency(PlayerName::User)
+    );
+}
+
+#[test]
+fn turn_start_animation_order() {
+    let mut g = new_game(Side::Champion, Args { actions: 1, ..Args::default() });
+    let response =
+        g.perform_action(Action::SpendActionPoint(SpendActionPointAction {}), g.user_id()).unwrap();
+    let commands = response.opponent_response.unwrap().1.commands;
+    let position = |predicate: &dyn Fn(&Command) -> bool| {
+        commands.iter().position(|c| predicate(c.command.as_ref().unwrap())).unwrap()
+    };
+
+    let dusk = position(&|c| {
+        matches!(c, Command::DisplayGameMessage(m)
+            if m.message_type == GameMessageType::Dusk as i32)
+    });
+    let draw = position(&|c| matches!(c, Command::MoveGameObjects(_)));
+    let action_tracker = position(&|c| {
+        matches!(c, Command::UpdateGameView(update) if update
+            .game
+            .as_ref()
+            .and_then(|game| game.user.as_ref())
+            .and_then(|user| user.action_tracker.as_ref())
+            .is_some_and(|tracker| tracker.available_action_count > 0))
+    });
+    assert!(dusk < draw);
+    assert!(draw < action_tracker);
+}
+
+#[test]
+fn undo_gain_mana() {
+    let mut g = new_game(Side::Overlord, Args { mana: 5, ..Args::default() });
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert_eq!(6, g.me().mana());
+    assert_eq!(2, g.me().actions());
+
+    g.perform(UserAction::Undo.as_game_action().unwrap(), g.user_id());
+    assert_eq!(5, g.me().mana());
+    assert_eq!(3, g.me().actions());
+    assert_eq!(5, g.opponent.other_player.mana());
+    assert_eq!(3, g.opponent.other_player.actions());
+}
+
+#[test]
+fn undo_does_not_rewind_rng() {
+    let mut g = new_game(Side::Overlord, Args { mana: 5, ..Args::default() });
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    random::choose(g.database_mut().game_mut(), 0..100);
+    let rng = g.database_mut().game().rng.clone();
+    assert!(rng.is_some());
+
+    g.perform(UserAction::Undo.as_game_action().unwrap(), g.user_id());
+    assert_eq!(5, g.me().mana());
+    assert_eq!(rng, g.database_mut().game().rng);
+}
+
+#[test]
+fn cannot_undo_after_opponent_action() {
+    let mut g = new_game(Side::Overlord, Args { actions: 1, ..Args::default() });
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert!(g.dawn());
+    g.perform(Action::GainMana(GainManaAction {}), g.opponent_id());
+    assert_error(g.perform_action(UserAction::Undo.as_game_action().unwrap(), g.user_id()));
+}
+
+#[test]
+fn cannot_undo_draw_card() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
+    assert_error(g.perform_action(UserAction::Undo.as_game_action().unwrap(), g.user_id()));
+}
+
+#[test]
+fn available_undo_count() {
+    let mut g = new_game(Side::Overlord, Args { actions: 4, ..Args::default() });
+    assert_eq!(0, g.me().available_undo_count());
+
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert_eq!(1, g.me().available_undo_count());
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert_eq!(2, g.me().available_undo_count());
+    assert_eq!(2, g.opponent.other_player.available_undo_count());
+    assert_eq!(0, g.you().available_undo_count());
+
+    g.perform(UserAction::Undo.as_game_action().unwrap(), g.user_id());
+    assert_eq!(1, g.me().available_undo_count());
+
+    g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
+    assert_eq!(Side::Overlord, g.database_mut().game().data.turn.side);
+    assert_eq!(0, g.me().available_undo_count());
+    assert!(g.database_mut().game().undo_history.is_empty());
+}
+
+#[test]
+fn undo_history_cleared_at_turn_end() {
+    let mut g = new_game(Side::Overlord, Args { actions: 2, ..Args::default() });
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert_eq!(1, g.me().available_undo_count());
+
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert_eq!(Side::Champion, g.database_mut().game().data.turn.side);
+    assert_eq!(0, g.me().available_undo_count());
+    assert!(g.database_mut().game().undo_history.is_empty());
+    assert_error(g.perform_action(UserAction::Undo.as_game_action().unwrap(), g.user_id()));
+}
@@ -0,0 +1,36 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::Side;
+use server::agent_response::{self, HandleRequest};
+use test_utils::*;
+
+#[tokio::test]
+async fn resume_pending_agent_turn() {
+    let mut g = new_game(
+        Side::Champion,
+        Args { turn: Some(Side::Overlord), actions: 1, ..Args::default() },
+    );
+    let database = g.database_mut();
+    database.game_mut().player_mut(Side::Overlord).id = PlayerId::Named(NamedPlayer::TestMinimax);
+
+    agent_response::resume_agents(database, HandleRequest::PushQueue)
+        .await
+        .expect("Error resuming agents");
+
+    let game = database.game();
+    assert_eq!(0, game.player(Side::Overlord).actions);
+    assert_eq!(Side::Champion, game.data.turn.side);
+}
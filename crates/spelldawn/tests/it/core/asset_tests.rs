@@ -0,0 +1,81 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use adapters::response_builder::{ResponseBuilder, ResponseOptions, ResponseState};
+use data::card_definition::CardDefinition;
+use data::card_state::CardPosition;
+use data::deck::Deck;
+use data::game::{GameConfiguration, GameState};
+use data::primitives::{CardType, Side};
+use display::{assets, card_sync};
+use protos::spelldawn::SpriteAddress;
+use test_utils::*;
+
+#[test]
+fn card_definitions_have_valid_assets() {
+    cards::initialize::run();
+    let errors = assets::validate_card_definitions();
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn card_views_have_sprite_addresses() {
+    cards::initialize::run();
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let deck = |owner_id, side| Deck {
+        owner_id,
+        side,
+        identity: cards(side).find(|c| c.card_type == CardType::Identity).unwrap().name,
+        cards: cards(side)
+            .filter(|c| c.card_type != CardType::Identity)
+            .map(|c| (c.name, 1))
+            .collect(),
+    };
+    let mut game = GameState::new(
+        game_id,
+        deck(overlord_id, Side::Overlord),
+        deck(champion_id, Side::Champion),
+        GameConfiguration { deterministic: true, ..GameConfiguration::default() },
+    );
+    let card_ids = game.all_cards().map(|c| c.id).collect::<Vec<_>>();
+
+    for card_id in card_ids {
+        game.move_card_internal(card_id, CardPosition::Hand(card_id.side));
+        game.card_mut(card_id).turn_face_up();
+        let builder = ResponseBuilder::new(
+            card_id.side,
+            ResponseState {
+                animate: false,
+                is_final_update: true,
+                options: ResponseOptions::default(),
+            },
+        );
+        let view = card_sync::card_view(&builder, &game, game.card(card_id)).unwrap();
+        let revealed = view.revealed_card.expect("revealed_card");
+        let name = game.card(card_id).name;
+        for sprite in [view.arena_frame, revealed.card_frame, revealed.jewel, revealed.image] {
+            assert_valid(name, sprite);
+        }
+    }
+}
+
+fn cards(side: Side) -> impl Iterator<Item = &'static CardDefinition> {
+    rules::CARDS.values().filter(move |c| c.side == side)
+}
+
+fn assert_valid(name: impl std::fmt::Debug, sprite: Option<SpriteAddress>) {
+    let sprite = sprite.unwrap_or_else(|| panic!("Missing sprite for {:?}", name));
+    assert!(!sprite.address.is_empty(), "Empty sprite address for {:?}", name);
+    assert!(assets::validate_sprite(&sprite).is_ok(), "Invalid sprite address for {:?}", name);
+}
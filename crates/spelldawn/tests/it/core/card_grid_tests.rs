@@ -0,0 +1,59 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::card_grid::CardGrid;
+use core_ui::prelude::*;
+use core_ui::rendering;
+use data::game_actions::UserAction;
+use protos::spelldawn::{FlexWrap, Node};
+
+fn render(grid: CardGrid) -> Node {
+    rendering::component(grid).expect("Node")
+}
+
+#[test]
+fn card_grid_cells() {
+    let grid = (0..10).fold(CardGrid::new("Grid").columns(4), |grid, i| {
+        grid.cell(Column::new(format!("Card{}", i)))
+    });
+    let node = render(grid);
+    assert_eq!(10, node.children.len());
+    assert_eq!(FlexWrap::Wrap as i32, node.style.as_ref().expect("style").wrap);
+    for cell in &node.children {
+        assert_eq!(25.0, cell.style.as_ref().expect("style").width.as_ref().expect("width").value);
+        assert_eq!(1, cell.children.len());
+        assert!(cell.event_handlers.is_none());
+    }
+}
+
+#[test]
+fn card_grid_click_handler() {
+    let node = render(
+        CardGrid::new("Grid")
+            .columns(2)
+            .cell(Column::new("Card0"))
+            .clickable_cell(Column::new("Card1"), UserAction::GainMana),
+    );
+    assert_eq!(2, node.children.len());
+    assert!(node.children[0].event_handlers.is_none());
+    assert!(node.children[1]
+        .event_handlers
+        .as_ref()
+        .and_then(|handlers| handlers.on_click.as_ref())
+        .is_some());
+    assert_eq!(
+        50.0,
+        node.children[1].style.as_ref().expect("style").width.as_ref().expect("width").value
+    );
+}
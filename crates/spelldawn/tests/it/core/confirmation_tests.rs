@@ -0,0 +1,51 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use core_ui::confirmation::ConfirmationPrompt;
+use core_ui::rendering;
+use data::game_actions::{DebugAction, UserAction};
+use protos::spelldawn::Node;
+use test_utils::client::HasText;
+
+fn render(prompt: ConfirmationPrompt) -> Node {
+    rendering::component(prompt).expect("Node")
+}
+
+#[test]
+fn confirmation_labels() {
+    let node = render(ConfirmationPrompt::new("Resign", "Are you sure you want to resign?"));
+    assert!(node.has_text("Resign"));
+    assert!(node.has_text("Are you sure you want to resign?"));
+    assert!(node.has_text("Confirm"));
+    assert!(node.has_text("Cancel"));
+}
+
+#[test]
+fn confirmation_actions() {
+    let node = render(
+        ConfirmationPrompt::new("Discard", "Discard this card?")
+            .confirm_label("Yes")
+            .cancel_label("No")
+            .confirm_action(UserAction::GainMana)
+            .cancel_action(DebugAction::FlipViewpoint),
+    );
+    let confirm = node.find_handlers("Yes").and_then(|handlers| handlers.on_click);
+    assert_eq!(UserAction::GainMana.as_game_action(), confirm.and_then(|action| action.action));
+    let cancel = node.find_handlers("No").and_then(|handlers| handlers.on_click);
+    assert_eq!(
+        DebugAction::FlipViewpoint.as_game_action(),
+        cancel.and_then(|action| action.action)
+    );
+}
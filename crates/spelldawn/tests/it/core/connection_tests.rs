@@ -0,0 +1,109 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::player_name::PlayerId;
+use data::primitives::{Locale, Side};
+use protos::spelldawn::game_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{CommandList, GainManaAction};
+use server::{agent_response, requests};
+use test_utils::*;
+use tokio::sync::mpsc;
+
+#[tokio::test]
+async fn closed_channel_removed_on_send() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let (tx, rx) = mpsc::channel(4);
+    requests::register_channel(g.user_id(), tx);
+    assert!(requests::is_connected(g.user_id()));
+
+    drop(rx);
+    requests::send_player_response(Some((g.user_id(), CommandList::default()))).await;
+    assert!(!requests::is_connected(g.user_id()));
+
+    let commands = g.connect(g.user_id()).expect("Connection error");
+    assert!(commands
+        .commands
+        .iter()
+        .any(|c| matches!(c.command, Some(Command::UpdateGameView(_)))));
+}
+
+#[tokio::test]
+async fn spectators_receive_updates() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let (game_id, spectator) = (g.game_id(), PlayerId::Database(u64::MAX - 1));
+    let (tx, mut rx) = mpsc::channel(4);
+    requests::register_spectator(game_id, spectator, Locale::default(), tx);
+    assert!(requests::is_spectating(game_id, spectator));
+    assert!(!requests::is_connected(spectator));
+
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    requests::update_spectators(g.database_mut(), game_id).await;
+    let commands = rx.try_recv().expect("Spectator update").expect("Error status");
+    let view = commands
+        .commands
+        .iter()
+        .find_map(|c| match &c.command {
+            Some(Command::UpdateGameView(update)) => update.game.as_ref(),
+            _ => None,
+        })
+        .expect("Game view");
+    assert!(view.main_controls.is_none());
+
+    drop(rx);
+    requests::update_spectators(g.database_mut(), game_id).await;
+    assert!(!requests::is_spectating(game_id, spectator));
+}
+
+#[test]
+fn disconnect_removes_channel() {
+    let g = new_game(Side::Champion, Args::default());
+    let (tx, _rx) = mpsc::channel(4);
+    requests::register_channel(g.user_id(), tx);
+    requests::disconnect(g.user_id());
+    assert!(!requests::is_connected(g.user_id()));
+}
+
+#[test]
+fn reconnect_discards_pending_animations() {
+    let mut g = new_game(Side::Champion, Args::default());
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    let (_, stale) = g.initiate_raid(ROOM_ID).opponent_response.expect("Opponent response");
+    assert!(stale.commands.iter().any(|c| matches!(c.command, Some(Command::VisitRoom(_)))));
+    agent_response::RESPONSES.push(stale.clone()).expect("Queue closed");
+
+    let commands = g.connect(g.opponent_id()).expect("Connection error");
+    while let Ok(queued) = agent_response::RESPONSES.pop() {
+        assert_ne!(stale, queued, "Stale animation remains queued");
+    }
+
+    assert!(commands
+        .commands
+        .iter()
+        .any(|c| matches!(c.command, Some(Command::UpdateGameView(_)))));
+    for command in commands.commands.iter().filter_map(|c| c.command.as_ref()) {
+        match command {
+            Command::UpdateGameView(update) => assert!(!update.animate),
+            Command::MoveGameObjects(move_objects) => assert!(move_objects.disable_animation),
+            Command::VisitRoom(_)
+            | Command::Delay(_)
+            | Command::CreateTokenCard(_)
+            | Command::FireProjectile(_)
+            | Command::PlayEffect(_)
+            | Command::DisplayGameMessage(_) => panic!("Unexpected animation {:?}", command),
+            _ => {}
+        }
+    }
+}
@@ -12,23 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use cards::initialize;
+use std::sync::{Arc, Barrier};
+use std::thread;
+use std::time::Duration;
+
+use cards::{decklists, initialize};
+use core_ui::actions::InterfaceAction;
 use data::card_name::CardName;
+use data::card_state::CardPosition;
 use data::deck::Deck;
-use data::game::MulliganDecision;
+use data::game::{
+    GameConfiguration, GamePhase, GameState, MulliganDecision, MulliganSelection, DEFAULT_SEED,
+    MAXIMUM_MULLIGAN_SELECTION,
+};
 use data::game_actions::{PromptAction, UserAction};
-use data::player_data::PlayerData;
-use data::player_name::PlayerId;
-use data::primitives::{GameId, Side};
+use data::player_data::{
+    CurrentGame, GameInvitation, NewGameRequest, PlayerCosmetics, PlayerData, SideboardSwap,
+};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::{CardId, GameId, Locale, Side, Sprite};
 use insta::assert_snapshot;
 use maplit::hashmap;
 use protos::spelldawn::game_action::Action;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::{
-    DeckIdentifier, NewGameAction, NewGameDebugOptions, PlayerIdentifier, PlayerName,
+    CommandList, DeckIdentifier, GameAction, GameIdentifier, GameRequest, NewGameAction,
+    NewGameDebugOptions, PlayerIdentifier, PlayerName,
 };
+use rules::{dispatch, mutations};
+use serde_json::{de, ser};
+use server::database::Database;
+use server::requests;
+use server::rest::{self, RestResponse};
 use test_utils::client::{HasText, TestSession};
-use test_utils::fake_database::FakeDatabase;
+use test_utils::fake_database::{FakeDatabase, SharedDatabase};
+use test_utils::mock_clock::MockClock;
 use test_utils::summarize::Summary;
 use test_utils::*;
 
@@ -39,19 +57,213 @@ static CHAMPION_DECK_ID: DeckIdentifier = DeckIdentifier { value: 1 };
 fn create_new_game() {
     let (game_id, overlord_id, champion_id) = generate_ids();
     let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
-    let response = session.perform_action(
+    let response = session
+        .perform_action(
+            Action::NewGame(NewGameAction {
+                deck: Some(OVERLORD_DECK_ID),
+                opponent_id: opponent_id(&session),
+                debug_options: Some(NewGameDebugOptions {
+                    deterministic: true,
+                    ..NewGameDebugOptions::default()
+                }),
+            }),
+            session.user_id(),
+        )
+        .unwrap();
+    assert_eq!(vec!["UpdatePanels", "TogglePanel"], command_names(&response.command_list));
+    assert_eq!(session.connect(overlord_id).unwrap().commands.len(), 0);
+    assert_eq!(
+        Some(GameInvitation {
+            opponent_id: champion_id,
+            deck_id: adapters::deck_id(OVERLORD_DECK_ID)
+        }),
+        session.database_mut().players[&overlord_id].invitation_to(champion_id)
+    );
+}
+
+#[test]
+fn accept_invitation_starts_game() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    session.perform(invite(OVERLORD_DECK_ID, champion_id), overlord_id);
+    assert!(!session.database_mut().has_game(game_id).unwrap());
+
+    let response =
+        session.perform_action(invite(CHAMPION_DECK_ID, overlord_id), champion_id).unwrap();
+    assert_eq!(vec!["LoadScene"], command_names(&response.command_list));
+    let (notified_id, opponent_commands) = response.opponent_response.expect("Opponent response");
+    assert_eq!(overlord_id, notified_id);
+    assert_eq!(vec!["LoadScene"], command_names(&opponent_commands));
+
+    assert_eq!(game_id, session.database_mut().game().id);
+    for player_id in [overlord_id, champion_id] {
+        let player = &session.database_mut().players[&player_id];
+        assert!(matches!(player.current_game, Some(CurrentGame::Playing(id)) if id == game_id));
+        assert!(player.pending_invitations.is_empty());
+    }
+}
+
+#[test]
+fn simultaneous_invitations_create_one_game() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    session.perform(invite(OVERLORD_DECK_ID, champion_id), overlord_id);
+    session.perform(invite(OVERLORD_DECK_ID, champion_id), overlord_id);
+    assert_eq!(1, session.database_mut().players[&overlord_id].pending_invitations.len());
+
+    session.perform(invite(CHAMPION_DECK_ID, overlord_id), champion_id);
+    assert_eq!(vec![game_id], session.database_mut().games_for_player(overlord_id).unwrap());
+    assert_eq!(vec![game_id], session.database_mut().games_for_player(champion_id).unwrap());
+    assert!(session.database_mut().players.values().all(|p| p.pending_invitations.is_empty()));
+    session.connect(overlord_id).unwrap();
+    session.connect(champion_id).unwrap();
+    assert!(session.user.interface.controls().has_text("Keep"));
+}
+
+#[test]
+fn cancel_invitation() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    session.perform(invite(OVERLORD_DECK_ID, champion_id), overlord_id);
+    let response = session.perform_action(cancel_new_game(), overlord_id).unwrap();
+    assert_eq!(vec!["TogglePanel"], command_names(&response.command_list));
+    assert!(session.database_mut().players[&overlord_id].pending_invitations.is_empty());
+
+    let response =
+        session.perform_action(invite(CHAMPION_DECK_ID, overlord_id), champion_id).unwrap();
+    assert_eq!(vec!["UpdatePanels", "TogglePanel"], command_names(&response.command_list));
+    assert!(response.opponent_response.is_none());
+    assert!(!session.database_mut().has_game(game_id).unwrap());
+    assert!(session.database_mut().players[&champion_id].invitation_to(overlord_id).is_some());
+}
+
+#[test]
+fn cancel_after_invitation_accepted() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    session.perform(invite(OVERLORD_DECK_ID, champion_id), overlord_id);
+    session.perform(invite(CHAMPION_DECK_ID, overlord_id), champion_id);
+    let response = session.perform_action(cancel_new_game(), overlord_id).unwrap();
+    assert!(response.command_list.commands.is_empty());
+    let player = &session.database_mut().players[&overlord_id];
+    assert!(matches!(player.current_game, Some(CurrentGame::Playing(id)) if id == game_id));
+}
+
+#[test]
+fn concurrent_invitations_create_one_game() {
+    for _ in 0..10 {
+        let (game_id, overlord_id, champion_id) = generate_ids();
+        let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+        let database = SharedDatabase::new(session.database_mut().clone());
+        let barrier = Arc::new(Barrier::new(2));
+        let threads = [
+            (OVERLORD_DECK_ID, overlord_id, champion_id),
+            (CHAMPION_DECK_ID, champion_id, overlord_id),
+        ]
+        .map(|(deck, user_id, opponent_id)| {
+            let (mut database, barrier) = (database.clone(), barrier.clone());
+            thread::spawn(move || {
+                barrier.wait();
+                requests::handle_request(
+                    &mut database,
+                    &GameRequest {
+                        action: Some(GameAction { action: Some(invite(deck, opponent_id)) }),
+                        player_id: Some(fake_database::to_player_identifier(user_id)),
+                    },
+                )
+                .expect("Request failed");
+            })
+        });
+        for thread in threads {
+            thread.join().expect("Thread panicked");
+        }
+
+        assert_eq!(1, database.games_written());
+        for player_id in [overlord_id, champion_id] {
+            let player = database.lock().players[&player_id].clone();
+            assert!(matches!(player.current_game, Some(CurrentGame::Playing(id)) if id == game_id));
+            assert!(player.pending_invitations.is_empty());
+        }
+    }
+}
+
+#[test]
+fn legacy_requested_game_is_ignored() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    let requested = Some(CurrentGame::Requested(NewGameRequest {
+        deck_id: adapters::deck_id(OVERLORD_DECK_ID),
+    }));
+    let player = session.database_mut().players.get_mut(&overlord_id).expect("Player");
+    player.current_game = de::from_str(&ser::to_string(&requested).unwrap()).unwrap();
+    assert_eq!(0, session.connect(overlord_id).unwrap().commands.len());
+
+    initiate_game(&mut session);
+    assert_eq!(game_id, session.database_mut().game().id);
+}
+
+#[test]
+fn create_game_with_starter_deck() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    session.database_mut().players.get_mut(&overlord_id).expect("Player").decks.clear();
+
+    let deck = session
+        .database_mut()
+        .deck(overlord_id, adapters::deck_id(OVERLORD_DECK_ID))
+        .expect("Starter deck");
+    assert_eq!(Side::Overlord, deck.side);
+    assert_eq!(overlord_id, deck.owner_id);
+    assert_ok(&decklists::validate(&deck));
+
+    initiate_game(&mut session);
+    let game = session.database_mut().game();
+    assert_eq!(game_id, game.id);
+    assert!(game
+        .cards(Side::Overlord)
+        .iter()
+        .all(|c| deck.cards.contains_key(&c.name) || c.name == deck.identity));
+}
+
+#[test]
+fn cannot_create_game_against_unknown_player() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let (_, unknown_id, _) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    assert_error(session.perform_action(
         Action::NewGame(NewGameAction {
             deck: Some(OVERLORD_DECK_ID),
-            opponent_id: opponent_id(&session),
-            debug_options: Some(NewGameDebugOptions {
-                deterministic: true,
-                ..NewGameDebugOptions::default()
-            }),
+            opponent_id: Some(fake_database::to_player_identifier(unknown_id)),
+            debug_options: None,
+        }),
+        session.user_id(),
+    ));
+    assert!(!session.database_mut().has_player(unknown_id).unwrap());
+}
+
+#[test]
+fn create_game_against_named_player() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let opponent_id = PlayerId::Named(NamedPlayer::TestNoAction);
+    let database =
+        make_overlord_test_session(game_id, overlord_id, champion_id).database_mut().clone();
+    let mut session = TestSession::new(database, overlord_id, opponent_id);
+    assert!(!session.database_mut().has_player(opponent_id).unwrap());
+
+    session.perform(
+        Action::NewGame(NewGameAction {
+            deck: Some(OVERLORD_DECK_ID),
+            opponent_id: Some(
+                adapters::named_player_identifier(NamedPlayer::TestNoAction).unwrap(),
+            ),
+            debug_options: None,
         }),
         session.user_id(),
     );
-    assert_eq!(session.connect(overlord_id).unwrap().commands.len(), 0);
-    assert_snapshot!(Summary::run(&response));
+
+    assert!(session.database_mut().has_player(opponent_id).unwrap());
+    assert_eq!(game_id, session.database_mut().game().id);
+    assert_eq!(opponent_id, session.database_mut().game().player(Side::Champion).id);
 }
 
 #[test]
@@ -137,6 +349,34 @@ fn mulligan_opening_hand() {
     assert_eq!(5, session.opponent.cards.revealed_cards().len());
 }
 
+#[test]
+fn mulligan_draws_configured_count() {
+    let mut game = GameState::new(
+        GameId::new(0),
+        decklists::CANONICAL_OVERLORD.clone(),
+        decklists::CANONICAL_CHAMPION.clone(),
+        GameConfiguration {
+            deterministic: true,
+            mulligan_hand_size: Some(5),
+            mulligan_draw_size: Some(7),
+            ..GameConfiguration::default()
+        },
+    );
+    dispatch::populate_delegate_cache(&mut game);
+    mutations::deal_opening_hands(&mut game).unwrap();
+    assert_eq!(5, game.hand(Side::Overlord).count());
+    assert_eq!(5, game.hand(Side::Champion).count());
+
+    actions::handle_user_action(
+        &mut game,
+        Side::Overlord,
+        UserAction::PromptAction(PromptAction::MulliganDecision(MulliganDecision::Mulligan)),
+    )
+    .unwrap();
+    assert_eq!(7, game.hand(Side::Overlord).count());
+    assert_eq!(5, game.hand(Side::Champion).count());
+}
+
 #[test]
 fn both_keep_opening_hands() {
     let (game_id, overlord_id, champion_id) = generate_ids();
@@ -160,6 +400,214 @@ fn both_keep_opening_hands() {
     assert!(session.dusk());
 }
 
+#[test]
+fn mulligan_timeout_keeps_opening_hand() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    initiate_game(&mut session);
+    session.database_mut().game_mut().data.config.mulligan_timeout = Some(Duration::from_secs(60));
+
+    session.click_on(overlord_id, "Keep");
+    session.database_mut().clock.advance(Duration::from_secs(59));
+    session.connect(overlord_id).expect("Connection error");
+    assert!(matches!(session.database_mut().game().data.phase, GamePhase::ResolveMulligans(_)));
+
+    session.database_mut().clock.advance(Duration::from_secs(1));
+    session.connect(overlord_id).expect("Connection error");
+    let game = session.database_mut().game();
+    assert!(matches!(game.data.phase, GamePhase::Play));
+    assert_eq!(5, game.hand(Side::Champion).count());
+    assert_eq!(3, session.user.this_player.actions());
+    assert!(session.user.this_player.can_take_action());
+}
+
+#[test]
+fn resign_during_mulligans() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    initiate_game(&mut session);
+    session.connect(champion_id).expect("Connection error");
+
+    session.perform(UserAction::Resign.as_game_action().unwrap(), champion_id);
+    assert!(session.is_victory_for_player(Side::Overlord));
+}
+
+#[test]
+fn partial_mulligan_zero_cards() {
+    let (mut session, original) = partial_mulligan_session();
+    perform_partial_mulligan(&mut session, &[]);
+    assert_eq!(original, overlord_hand(&mut session));
+}
+
+#[test]
+fn partial_mulligan_some_cards() {
+    let (mut session, original) = partial_mulligan_session();
+    perform_partial_mulligan(&mut session, &original[0..2]);
+
+    let hand = overlord_hand(&mut session);
+    assert_eq!(5, hand.len());
+    assert!(original[2..].iter().all(|card_id| hand.contains(card_id)));
+    for card_id in &original[0..2] {
+        assert!(!hand.contains(card_id));
+        assert_eq!(
+            CardPosition::DeckBottom(Side::Overlord),
+            session.database_mut().game().card(*card_id).position()
+        );
+    }
+}
+
+#[test]
+fn partial_mulligan_all_cards() {
+    let (mut session, original) = partial_mulligan_session();
+    perform_partial_mulligan(&mut session, &original);
+
+    let hand = overlord_hand(&mut session);
+    assert_eq!(5, hand.len());
+    assert!(original.iter().all(|card_id| !hand.contains(card_id)));
+}
+
+#[test]
+fn partial_mulligan_card_not_in_hand() {
+    let (mut session, _) = partial_mulligan_session();
+    let champion_card = session.database_mut().game().hand(Side::Champion).next().unwrap().id;
+    let selection = MulliganSelection::new(&[champion_card]).unwrap();
+    assert_error(
+        session.perform_action(
+            UserAction::PromptAction(PromptAction::MulliganDecision(MulliganDecision::Partial(
+                selection,
+            )))
+            .as_game_action()
+            .unwrap(),
+            session.user_id(),
+        ),
+    );
+}
+
+#[test]
+fn partial_mulligan_selection_prompt() {
+    let (mut session, original) = partial_mulligan_session();
+    assert!(!session.user.interface.controls().has_text("Replace 1"));
+
+    session.click_on(session.user_id(), "Select");
+    assert!(session.user.interface.controls().has_text("Deselect"));
+    session.click_on(session.user_id(), "Replace 1");
+
+    let hand = overlord_hand(&mut session);
+    assert_eq!(4, original.iter().filter(|card_id| hand.contains(card_id)).count());
+    assert!(matches!(
+        &session.database_mut().game().data.phase,
+        GamePhase::ResolveMulligans(mulligans) if mulligans.selected_cards.is_empty()
+    ));
+}
+
+#[test]
+fn partial_mulligan_selection_limit() {
+    let (mut session, _) = partial_mulligan_session();
+    let game = session.database_mut().game_mut();
+    game.data.config.mulligan_hand_size = Some(7);
+    let extra = game.deck(Side::Overlord).take(2).map(|c| c.id).collect::<Vec<_>>();
+    for card_id in extra {
+        game.move_card_internal(card_id, CardPosition::Hand(Side::Overlord));
+    }
+    session.connect(session.user_id()).unwrap();
+
+    let hand = overlord_hand(&mut session);
+    assert_eq!(7, hand.len());
+    for _ in 0..MAXIMUM_MULLIGAN_SELECTION {
+        session.click_on(session.user_id(), "Select");
+    }
+    assert!(!session.user.interface.controls().get_text().contains(&"Select".to_string()));
+
+    let unselected = hand
+        .iter()
+        .find(|card_id| {
+            !matches!(
+                &session.database_mut().game().data.phase,
+                GamePhase::ResolveMulligans(mulligans) if mulligans.selected_cards.contains(card_id)
+            )
+        })
+        .copied()
+        .unwrap();
+    assert_error(
+        session.perform_action(
+            UserAction::PromptAction(PromptAction::ToggleMulliganCard(unselected))
+                .as_game_action()
+                .unwrap(),
+            session.user_id(),
+        ),
+    );
+
+    session.click_on(session.user_id(), "Replace 5");
+    assert_eq!(7, overlord_hand(&mut session).len());
+}
+
+/// Creates a new game and returns the Overlord's opening hand.
+fn partial_mulligan_session() -> (TestSession, Vec<CardId>) {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    initiate_game(&mut session);
+    session.connect(overlord_id).expect("Connection error");
+    let hand = overlord_hand(&mut session);
+    assert_eq!(5, hand.len());
+    (session, hand)
+}
+
+fn overlord_hand(session: &mut TestSession) -> Vec<CardId> {
+    session.database_mut().game().hand(Side::Overlord).map(|c| c.id).collect()
+}
+
+fn perform_partial_mulligan(session: &mut TestSession, cards: &[CardId]) {
+    let selection = MulliganSelection::new(cards).unwrap();
+    session.perform(
+        UserAction::PromptAction(PromptAction::MulliganDecision(MulliganDecision::Partial(
+            selection,
+        )))
+        .as_game_action()
+        .unwrap(),
+        session.user_id(),
+    );
+}
+
+#[test]
+fn chosen_card_back_in_player_view() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_test_session_with_cosmetics(
+        game_id,
+        overlord_id,
+        champion_id,
+        PlayerCosmetics {
+            card_back: Some(Sprite::new("Test/CustomCardBack.png")),
+            portrait_frame: Some(Sprite::new("Test/CustomFrame.png")),
+        },
+    );
+    initiate_game(&mut session);
+
+    assert_eq!("Test/CustomCardBack.png", session.user.this_player.card_back());
+    assert_eq!("Test/CustomFrame.png", session.user.this_player.portrait_frame());
+    assert_eq!("Test/CustomCardBack.png", session.opponent.other_player.card_back());
+    assert_ne!("Test/CustomCardBack.png", session.user.other_player.card_back());
+    assert_ne!("Test/CustomFrame.png", session.user.other_player.portrait_frame());
+}
+
+fn invite(deck: DeckIdentifier, opponent_id: PlayerId) -> Action {
+    Action::NewGame(NewGameAction {
+        deck: Some(deck),
+        opponent_id: Some(fake_database::to_player_identifier(opponent_id)),
+        debug_options: Some(NewGameDebugOptions {
+            deterministic: true,
+            ..NewGameDebugOptions::default()
+        }),
+    })
+}
+
+fn cancel_new_game() -> Action {
+    UserAction::CancelNewGame.as_game_action().unwrap()
+}
+
+fn command_names(commands: &CommandList) -> Vec<&'static str> {
+    commands.commands.iter().map(requests::command_name).collect()
+}
+
 fn user_id(session: &TestSession) -> Option<PlayerIdentifier> {
     Some(fake_database::to_player_identifier(session.user_id()))
 }
@@ -174,6 +622,17 @@ fn make_overlord_test_session(
     game_id: GameId,
     overlord_id: PlayerId,
     champion_id: PlayerId,
+) -> TestSession {
+    make_test_session_with_cosmetics(game_id, overlord_id, champion_id, PlayerCosmetics::default())
+}
+
+/// Equivalent to [make_overlord_test_session] which assigns the provided
+/// [PlayerCosmetics] to the Overlord player.
+fn make_test_session_with_cosmetics(
+    game_id: GameId,
+    overlord_id: PlayerId,
+    champion_id: PlayerId,
+    overlord_cosmetics: PlayerCosmetics,
 ) -> TestSession {
     initialize::run();
     let overlord_deck = Deck {
@@ -197,15 +656,26 @@ fn make_overlord_test_session(
                 id: overlord_id,
                 current_game: None,
                 decks: vec![overlord_deck.clone(), champion_deck.clone()],
-                collection: hashmap! {}
+                collection: hashmap! {},
+                cosmetics: overlord_cosmetics,
+                sideboard: hashmap! {},
+                current_match: None,
+                locale: Locale::default(),
+                pending_invitations: vec![],
             },
             champion_id => PlayerData {
                 id: champion_id,
                 current_game: None,
                 decks: vec![overlord_deck, champion_deck],
-                collection: hashmap! {}
+                collection: hashmap! {},
+                cosmetics: PlayerCosmetics::default(),
+                sideboard: hashmap! {},
+                current_match: None,
+                locale: Locale::default(),
+                pending_invitations: vec![],
             }
         },
+        clock: MockClock::default(),
     };
 
     TestSession::new(database, overlord_id, champion_id)
@@ -245,3 +715,132 @@ fn initiate_game(session: &mut TestSession) {
     session.connect(session.user_id()).unwrap();
     session.connect(session.opponent_id()).unwrap();
 }
+
+#[test]
+fn same_seed_draws_same_opening_hand() {
+    let opening_hand = |seed: u64| {
+        let mut g = new_game(Side::Overlord, Args { seed: Some(seed), ..Args::default() });
+        let game = g.database_mut().game_mut();
+        mutations::draw_cards(game, Side::Overlord, 5).unwrap()
+    };
+
+    assert_eq!(opening_hand(1), opening_hand(1));
+    assert_eq!(opening_hand(DEFAULT_SEED), opening_hand(DEFAULT_SEED));
+    assert!((2..10).any(|seed| opening_hand(seed) != opening_hand(1)));
+}
+
+#[test]
+fn sideboard_swap_applies_to_next_game_in_match() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    let overlord = session.database_mut().players.get_mut(&overlord_id).expect("Player");
+    overlord.decks[0] = decklists::canonical_deck(overlord_id, Side::Overlord);
+    overlord.sideboard = hashmap! {CardName::TimeGolem => 1, CardName::GoldMine => 1};
+
+    initiate_game(&mut session);
+    assert_eq!(1, overlord_card_count(&mut session, CardName::TimeGolem));
+
+    let deck_id = adapters::deck_id(OVERLORD_DECK_ID);
+    let swap = |remove, add| {
+        UserAction::SideboardSwap(SideboardSwap { deck_id, remove, add }).as_game_action().unwrap()
+    };
+    session.perform(swap(CardName::GatheringDark, CardName::TimeGolem), overlord_id);
+    // The deck already contains the maximum number of copies of Gold Mine
+    assert_error(
+        session.perform_action(swap(CardName::GatheringDark, CardName::GoldMine), overlord_id),
+    );
+
+    initiate_game(&mut session);
+    assert_eq!(2, overlord_card_count(&mut session, CardName::TimeGolem));
+    assert_eq!(2, overlord_card_count(&mut session, CardName::GatheringDark));
+    assert_eq!(3, overlord_card_count(&mut session, CardName::GoldMine));
+}
+
+#[test]
+fn record_match_result() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    initiate_game(&mut session);
+    requests::handle_custom_action(session.database_mut(), overlord_id, Some(game_id), |g, s| {
+        mutations::game_over(g, s)
+    })
+    .expect("Error ending game");
+
+    let overlord_match =
+        session.database_mut().players[&overlord_id].current_match.clone().expect("Match");
+    assert_eq!((1, 0), (overlord_match.games_won, overlord_match.games_lost));
+    let champion_match =
+        session.database_mut().players[&champion_id].current_match.clone().expect("Match");
+    assert_eq!((0, 1), (champion_match.games_won, champion_match.games_lost));
+
+    // Simulates the game write failing after the result was recorded, so that
+    // recording is retried by the next request.
+    session.database_mut().game_mut().data.completed_at = None;
+    requests::handle_custom_action(session.database_mut(), overlord_id, Some(game_id), |_, _| {
+        Ok(())
+    })
+    .expect("Error handling action");
+    let overlord_match =
+        session.database_mut().players[&overlord_id].current_match.clone().expect("Match");
+    assert_eq!((1, 0), (overlord_match.games_won, overlord_match.games_lost));
+}
+
+#[test]
+fn create_game_with_rest_gateway() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    let response = rest_action(&mut session, champion_id, invite(CHAMPION_DECK_ID, overlord_id));
+    assert_eq!(200, response.status.as_u16());
+    let response = rest_action(&mut session, overlord_id, invite(OVERLORD_DECK_ID, champion_id));
+    assert_eq!(200, response.status.as_u16());
+    let commands: CommandList = de::from_slice(&response.body).unwrap();
+    assert!(matches!(commands.commands[0].command, Some(Command::LoadScene(_))));
+
+    let games: Vec<GameIdentifier> =
+        de::from_slice(&rest_player_request(&mut session, "/list_games", overlord_id).body)
+            .unwrap();
+    assert_eq!(vec![adapters::game_identifier(game_id)], games);
+
+    let keep = UserAction::PromptAction(PromptAction::MulliganDecision(MulliganDecision::Keep));
+    let legal_actions: Vec<UserAction> =
+        de::from_slice(&rest_player_request(&mut session, "/legal_actions", overlord_id).body)
+            .unwrap();
+    assert!(legal_actions.contains(&keep));
+
+    let response = rest_action(&mut session, overlord_id, keep.as_game_action().unwrap());
+    assert_eq!(200, response.status.as_u16());
+    assert!(matches!(
+        &session.database_mut().game().data.phase,
+        GamePhase::ResolveMulligans(mulligans) if mulligans.overlord == Some(MulliganDecision::Keep)
+    ));
+
+    let response = rest_action(&mut session, overlord_id, keep.as_game_action().unwrap());
+    assert_eq!(409, response.status.as_u16());
+}
+
+fn rest_action(session: &mut TestSession, player_id: PlayerId, action: Action) -> RestResponse {
+    let request = GameRequest {
+        action: Some(GameAction { action: Some(action) }),
+        player_id: Some(fake_database::to_player_identifier(player_id)),
+    };
+    rest::handle_rest_request(
+        session.database_mut(),
+        "/perform_action",
+        &ser::to_vec(&request).unwrap(),
+    )
+}
+
+fn rest_player_request(session: &mut TestSession, path: &str, player_id: PlayerId) -> RestResponse {
+    let identifier = fake_database::to_player_identifier(player_id);
+    rest::handle_rest_request(session.database_mut(), path, &ser::to_vec(&identifier).unwrap())
+}
+
+fn overlord_card_count(session: &mut TestSession, name: CardName) -> usize {
+    session
+        .database_mut()
+        .game()
+        .cards(Side::Overlord)
+        .iter()
+        .filter(|card| card.name == name)
+        .count()
+}
@@ -0,0 +1,61 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::card_name::CardName;
+use data::game_actions::{DebugAction, UserAction};
+use data::primitives::Side;
+use server::database::{Database, MemoryDatabase};
+use server::debug;
+use test_utils::*;
+
+#[test]
+fn export_and_import_mid_raid() {
+    let mut g = new_game(Side::Champion, Args::default());
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    let exported_id = g.game_id();
+    let json = debug::export_game(g.database_mut(), exported_id).unwrap();
+
+    let mut database = MemoryDatabase::new();
+    let game_id = debug::import_game(&mut database, &json).unwrap();
+    assert_ne!(exported_id, game_id);
+
+    let imported = database.game(game_id).unwrap();
+    assert_eq!(game_id, imported.id);
+    assert_eq!(ROOM_ID, imported.raid().unwrap().target);
+    assert_eq!(g.database_mut().game().raid().unwrap().raid_id, imported.raid().unwrap().raid_id);
+    assert!(!imported.delegate_cache.lookup.is_empty());
+}
+
+#[test]
+fn import_invalid_json() {
+    let mut database = MemoryDatabase::new();
+    assert!(debug::import_game(&mut database, "{").is_err());
+    assert!(database.game_ids().unwrap().is_empty());
+}
+
+#[test]
+fn set_resources() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let action = DebugAction::SetResources { side: Side::Champion, mana: 12, actions: 2, score: 4 };
+    g.perform(UserAction::Debug(action).as_game_action().unwrap(), g.user_id());
+
+    assert_eq!(12, g.user.other_player.mana());
+    assert_eq!(2, g.user.other_player.actions());
+    assert_eq!(4, g.user.other_player.score());
+    assert_eq!(12, g.opponent.this_player.mana());
+    assert_eq!(2, g.opponent.this_player.actions());
+    assert_eq!(4, g.opponent.this_player.score());
+}
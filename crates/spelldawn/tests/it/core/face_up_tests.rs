@@ -0,0 +1,195 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::primitives::{CardType, Side};
+use protos::spelldawn::game_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::game_object_identifier::Id;
+use protos::spelldawn::object_position::Position;
+use protos::spelldawn::{ClientRoomLocation, GameObjectIdentifier, PlayCardAction, PlayerName};
+use rules::{flags, mutations};
+use test_utils::*;
+
+#[test]
+fn enters_face_up_by_card_type() {
+    cards::initialize::run();
+    for (name, card_type, face_up) in [
+        (CardName::TestChampionSpell, CardType::ChampionSpell, true),
+        (CardName::TestOverlordSpell, CardType::OverlordSpell, true),
+        (CardName::TestWeapon2Attack, CardType::Weapon, true),
+        (CardName::TestManaBattery, CardType::Artifact, true),
+        (CardName::TestMinionEndRaid, CardType::Minion, false),
+        (CardName::TestProject2Cost, CardType::Project, false),
+        (CardName::TestScheme31, CardType::Scheme, false),
+    ] {
+        let definition = rules::get(name);
+        assert_eq!(card_type, definition.card_type, "Unexpected type for {:?}", name);
+        assert_eq!(face_up, definition.enters_face_up(), "Wrong face-up state for {:?}", name);
+    }
+}
+
+#[test]
+fn enters_play_face_up_flag() {
+    for_each_side(|side| {
+        let mut g = new_game(side, Args::default());
+        let name = match side {
+            Side::Overlord => CardName::TestMinionEndRaid,
+            Side::Champion => CardName::TestWeapon2Attack,
+        };
+        let card_id = server_card_id(g.add_to_hand(name));
+        let game = g.database_mut().game_mut();
+        assert_eq!(side == Side::Champion, flags::enters_play_face_up(game, card_id));
+        assert_eq!(CardPosition::Hand(side), game.card(card_id).position());
+    });
+}
+
+#[test]
+fn minion_enters_face_down() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(CardName::TestMinionEndRaid);
+    assert_identical(
+        vec![CardName::TestMinionEndRaid],
+        g.user.cards.room_cards(ROOM_ID, ClientRoomLocation::Front),
+    );
+    assert_eq!(vec![HIDDEN_CARD], g.opponent.cards.room_cards(ROOM_ID, ClientRoomLocation::Front));
+}
+
+#[test]
+fn spell_enters_face_up() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestChampionSpell);
+    assert_identical(
+        vec![CardName::TestChampionSpell],
+        g.user.cards.discard_pile(PlayerName::User),
+    );
+    assert_identical(
+        vec![CardName::TestChampionSpell],
+        g.opponent.cards.discard_pile(PlayerName::Opponent),
+    );
+}
+
+#[test]
+fn reveal_card_to_opponent() {
+    let mut g = new_game(Side::Overlord, Args { turn: Some(Side::Champion), ..Args::default() });
+    let card_id = g.add_to_hand(CardName::TestOverlordSpell);
+    assert_eq!(vec![HIDDEN_CARD], g.opponent.cards.hand(PlayerName::Opponent));
+
+    let spell_id = g.add_to_hand(CardName::TestChampionSpellRevealHand);
+    let response = g
+        .perform_action(
+            Action::PlayCard(PlayCardAction { card_id: Some(spell_id), target: None }),
+            g.opponent_id(),
+        )
+        .expect("Error playing card");
+
+    assert_identical(
+        vec![CardName::TestOverlordSpell],
+        g.opponent.cards.hand(PlayerName::Opponent),
+    );
+    assert!(response.command_list.commands.iter().any(|c| match &c.command {
+        Some(Command::MoveGameObjects(move_objects)) => move_objects.moves.iter().any(|m| {
+            m.id == Some(GameObjectIdentifier { id: Some(Id::CardId(card_id)) })
+                && matches!(
+                    m.position.as_ref().and_then(|p| p.position.as_ref()),
+                    Some(Position::Revealed(_))
+                )
+        }),
+        _ => false,
+    }));
+}
+
+#[test]
+fn when_revealed_triggers_once_per_reveal() {
+    let mut g = new_game(Side::Overlord, Args { turn: Some(Side::Champion), ..Args::default() });
+    g.add_to_hand(CardName::TestMinionGainManaWhenRevealed);
+    assert_eq!(STARTING_MANA, g.me().mana());
+
+    g.play_from_hand(CardName::TestChampionSpellRevealHand);
+    assert_eq!(STARTING_MANA + 1, g.me().mana());
+
+    g.play_from_hand(CardName::TestChampionSpellRevealHand);
+    assert_eq!(STARTING_MANA + 1, g.me().mana());
+}
+
+#[test]
+fn draw_revealed_minion() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { mana: 5, deck_top: Some(CardName::TestMinionEndRaid), ..Args::default() },
+    );
+    g.play_from_hand(CardName::TestOverlordSpellDrawRevealed);
+    assert_identical(
+        vec![CardName::TestMinionEndRaid],
+        g.opponent.cards.hand(PlayerName::Opponent),
+    );
+    assert_eq!(7, g.me().mana());
+}
+
+#[test]
+fn draw_revealed_non_minion() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { mana: 5, deck_top: Some(CardName::TestScheme31), ..Args::default() },
+    );
+    g.play_from_hand(CardName::TestOverlordSpellDrawRevealed);
+    assert_identical(vec![CardName::TestScheme31], g.opponent.cards.hand(PlayerName::Opponent));
+    assert_eq!(5, g.me().mana());
+}
+
+#[test]
+fn draw_revealed_fires_draw_card_action_event() {
+    let mut g = new_game(Side::Champion, Args { mana: 5, ..Args::default() });
+    g.play_from_hand(CardName::DarkGrimoire);
+    let hand_size = g.database_mut().game().hand(Side::Champion).count();
+
+    mutations::draw_revealed(g.database_mut().game_mut(), Side::Champion)
+        .expect("Error drawing card");
+    // Dark Grimoire draws an additional card in response to the event
+    assert_eq!(hand_size + 2, g.database_mut().game().hand(Side::Champion).count());
+}
+
+#[test]
+fn reveal_random_from_hand() {
+    let reveal = || {
+        let mut g = new_game(Side::Overlord, Args { hand_size: 5, ..Args::default() });
+        let revealed = mutations::reveal_random_from_hand(
+            g.database_mut().game_mut(),
+            Side::Overlord,
+            2,
+            Side::Champion,
+        )
+        .expect("Error revealing cards");
+        g.connect(g.user_id()).expect("Connection error");
+        g.connect(g.opponent_id()).expect("Connection error");
+        (g, revealed)
+    };
+
+    let (mut g, revealed) = reveal();
+    assert_eq!(2, revealed.len());
+    assert_eq!(revealed, reveal().1);
+
+    let opponent_view = g.opponent.cards.hand(PlayerName::Opponent);
+    assert_eq!(5, opponent_view.len());
+    assert_eq!(3, opponent_view.iter().filter(|title| *title == HIDDEN_CARD).count());
+    for card_id in &revealed {
+        let id = adapters::card_identifier(*card_id);
+        assert!(g.opponent.cards.get(id).revealed_to_me());
+        assert_eq!(
+            CardPosition::Hand(Side::Overlord),
+            g.database_mut().game().card(*card_id).position()
+        );
+    }
+}
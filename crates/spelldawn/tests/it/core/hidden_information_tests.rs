@@ -0,0 +1,238 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use adapters::response_builder::ResponseOptions;
+use adapters::ServerCardId;
+use cards::decklists;
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::game::GameState;
+use data::player_name::PlayerId;
+use data::primitives::{CardType, Locale, Side};
+use prost::Message;
+use protos::spelldawn::game_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::object_position::Position;
+use protos::spelldawn::{
+    CardIdentifier, CommandList, DrawCardAction, GameCommand, GameView, ObjectPositionHand,
+    PlayerName,
+};
+use rules::mutations;
+use server::requests;
+use test_utils::client::TestSession;
+use test_utils::*;
+
+/// Renders a full sync of `game` for both players and asserts that neither
+/// view contains card data the viewing player should not see.
+fn assert_no_hidden_information(game: &GameState) {
+    for_each_side(|side| {
+        let views = display::render::connect(game, side)
+            .expect("Error rendering game")
+            .into_iter()
+            .filter_map(|command| match command {
+                Command::UpdateGameView(update) => update.game,
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert!(!views.is_empty());
+
+        for card_view in views.iter().flat_map(|view| &view.cards) {
+            let identifier = card_view.card_id.expect("Card identifier");
+            let ServerCardId::CardId(card_id) =
+                adapters::server_card_id(identifier).expect("Invalid card identifier")
+            else {
+                assert_eq!(identifier.side, adapters::player_side(side));
+                continue;
+            };
+            let card = game.card(card_id);
+            assert!(
+                !card.position().shuffled_into_deck(),
+                "{:?} synced a card from inside a deck to {:?}",
+                card.name,
+                side
+            );
+
+            if !card.is_revealed_to(side) {
+                assert!(!card_view.revealed_to_viewer);
+                assert!(
+                    card_view.revealed_card.is_none(),
+                    "{:?} revealed to {:?}",
+                    card.name,
+                    side
+                );
+            }
+
+            if card_id.side != side
+                && rules::get(card.name).card_type == CardType::Minion
+                && !card.is_face_up()
+            {
+                assert!(card_view.revealed_card.is_none(), "Face-down minion title visible");
+            }
+        }
+    });
+}
+
+fn synced_card_ids(commands: &[Command]) -> Vec<CardIdentifier> {
+    commands
+        .iter()
+        .filter_map(|command| match command {
+            Command::UpdateGameView(update) => update.game.as_ref(),
+            _ => None,
+        })
+        .flat_map(|view| view.cards.iter().filter_map(|card| card.card_id))
+        .collect()
+}
+
+fn encoded_len(commands: Vec<Command>) -> usize {
+    CommandList {
+        commands: commands
+            .into_iter()
+            .map(|command| GameCommand { command: Some(command) })
+            .collect(),
+    }
+    .encoded_len()
+}
+
+#[test]
+fn canonical_game_hides_information() {
+    cards::initialize::run();
+    assert_no_hidden_information(&decklists::canonical_game().unwrap());
+}
+
+#[test]
+fn hands_hide_information() {
+    let mut g =
+        new_game(Side::Overlord, Args { hand_size: 5, opponent_hand_size: 5, ..Args::default() });
+    assert_no_hidden_information(g.database_mut().game());
+}
+
+#[test]
+fn face_down_cards_hide_information() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(CardName::TestScheme31);
+    g.play_from_hand(CardName::TestMinionEndRaid);
+    assert_no_hidden_information(g.database_mut().game());
+}
+
+#[test]
+fn raid_hides_information() {
+    let mut g = new_game(Side::Champion, Args::default());
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    assert_no_hidden_information(g.database_mut().game());
+}
+
+#[test]
+fn spectator_hides_information() {
+    let mut g =
+        new_game(Side::Overlord, Args { hand_size: 5, opponent_hand_size: 5, ..Args::default() });
+    g.play_from_hand(CardName::TestScheme31);
+    g.play_from_hand(CardName::TestMinionEndRaid);
+    let view = spectator_view(&mut g);
+    let game = g.database_mut().game();
+    assert!(view.main_controls.is_none());
+
+    for card_view in &view.cards {
+        let identifier = card_view.card_id.expect("Card identifier");
+        let ServerCardId::CardId(card_id) =
+            adapters::server_card_id(identifier).expect("Invalid card identifier")
+        else {
+            panic!("Ability card synced to spectator");
+        };
+        let card = game.card(card_id);
+        if !(card.is_revealed_to(Side::Overlord) && card.is_revealed_to(Side::Champion)) {
+            assert!(!card_view.revealed_to_viewer);
+            assert!(card_view.revealed_card.is_none(), "{:?} revealed to spectator", card.name);
+        }
+    }
+}
+
+#[test]
+fn reveal_card_to_champion() {
+    let mut g = new_game(
+        Side::Champion,
+        Args { opponent_deck_top: Some(CardName::TestMinionEndRaid), ..Args::default() },
+    );
+    let card_id = g
+        .database_mut()
+        .game()
+        .cards(Side::Overlord)
+        .iter()
+        .find(|c| c.position() == CardPosition::DeckTop(Side::Overlord))
+        .expect("Deck top")
+        .id;
+    let identifier = adapters::card_identifier(card_id);
+    assert!(!g.user.cards.card_map.contains_key(&identifier));
+
+    mutations::reveal_card(g.database_mut().game_mut(), card_id, Side::Champion)
+        .expect("Error revealing card");
+    g.connect(g.user_id()).expect("Connection error");
+    assert_eq!("Test Minion End Raid", g.user.get_card(identifier).title());
+
+    let view = spectator_view(&mut g);
+    assert!(view.cards.iter().all(|card_view| card_view.card_id != Some(identifier)));
+}
+
+#[test]
+fn omit_hidden_deck_cards() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { actions: 3, deck_top: Some(CardName::TestMinionEndRaid), ..Args::default() },
+    );
+    let deck_top = adapters::card_identifier(
+        g.database_mut()
+            .game()
+            .cards(Side::Overlord)
+            .iter()
+            .find(|c| c.position() == CardPosition::DeckTop(Side::Overlord))
+            .expect("Deck top")
+            .id,
+    );
+    let full = display::render::connect(g.database_mut().game(), Side::Champion).unwrap();
+    let omitted = display::render::connect_with_options(
+        g.database_mut().game(),
+        Side::Champion,
+        ResponseOptions { omit_hidden_deck_cards: true, ..ResponseOptions::default() },
+    )
+    .unwrap();
+    assert!(synced_card_ids(&full).contains(&deck_top));
+    assert!(!synced_card_ids(&omitted).contains(&deck_top));
+    assert!(encoded_len(omitted) < encoded_len(full));
+
+    g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
+    assert_eq!(vec!["Test Minion End Raid"], g.user.cards.hand(PlayerName::User));
+    assert_eq!(
+        Position::Hand(ObjectPositionHand { owner: PlayerName::User.into() }),
+        g.user.get_card(deck_top).position()
+    );
+    assert_eq!(1, g.opponent.cards.hand(PlayerName::Opponent).len());
+    assert_eq!(None, g.opponent.get_card(deck_top).title_option());
+}
+
+fn spectator_view(g: &mut TestSession) -> GameView {
+    let (game_id, spectator) = (g.game_id(), PlayerId::Database(u64::MAX));
+    let commands =
+        requests::handle_connect(g.database_mut(), spectator, Some(game_id), Locale::default())
+            .unwrap();
+    let mut views = commands
+        .commands
+        .into_iter()
+        .filter_map(|command| match command.command {
+            Some(Command::UpdateGameView(update)) => update.game,
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(1, views.len());
+    views.remove(0)
+}
@@ -0,0 +1,74 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::icons;
+use data::card_name::CardName;
+use data::primitives::{Locale, Side};
+use data::text::TextToken;
+use display::localization::STRINGS;
+use display::rules_text;
+use protos::spelldawn::game_action::Action;
+use protos::spelldawn::GainManaAction;
+use test_utils::*;
+
+#[test]
+fn translated_title() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let card_id = g.add_to_hand(CardName::ArcaneRecovery);
+    g.connect_with_locale(g.user_id(), Locale::Spanish).expect("Connection error");
+    assert_eq!("Recuperación Arcana", g.user.cards.get(card_id).title());
+}
+
+#[test]
+fn missing_translation_falls_back_to_english() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let card_id = g.add_to_hand(CardName::TestChampionSpell);
+    g.connect_with_locale(g.user_id(), Locale::Spanish).expect("Connection error");
+    assert_eq!("Test Champion Spell", g.user.cards.get(card_id).title());
+}
+
+#[test]
+fn locale_used_for_subsequent_updates() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let card_id = g.add_to_hand(CardName::ArcaneRecovery);
+    g.connect_with_locale(g.user_id(), Locale::Spanish).expect("Connection error");
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert_eq!("Recuperación Arcana", g.user.cards.get(card_id).title());
+}
+
+#[test]
+fn rules_text_falls_back_to_english() {
+    let tokens = [TextToken::Literal("Gain".to_string()), TextToken::Mana(9)];
+    assert!(STRINGS.rules_text(CardName::ArcaneRecovery, Locale::Spanish, &tokens).is_some());
+    assert!(STRINGS.rules_text(CardName::Meditation, Locale::Spanish, &tokens).is_none());
+    assert!(STRINGS.rules_text(CardName::ArcaneRecovery, Locale::English, &tokens).is_none());
+    assert_eq!("Arcane Recovery", STRINGS.title(CardName::ArcaneRecovery, Locale::English));
+}
+
+#[test]
+fn translated_rules_text_uses_definition_values() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let card_id = server_card_id(g.add_to_hand(CardName::ArcaneRecovery));
+    let game = g.database_mut().game();
+    let card = game.card(card_id);
+    let definition = rules::get(card.name);
+    assert_eq!(
+        format!("Gana 9{}", icons::MANA),
+        rules_text::build(game, card, definition, Locale::Spanish).text
+    );
+    assert_eq!(
+        format!("Gain 9{}", icons::MANA),
+        rules_text::build(game, card, definition, Locale::English).text
+    );
+}
@@ -0,0 +1,148 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use data::game::{GamePhase, GameState};
+use data::player_data::PlayerData;
+use data::player_name::PlayerId;
+use data::primitives::{GameId, Side};
+use protos::spelldawn::PlayerIdentifier;
+use server::clock::{Clock, SystemClock};
+use server::database::{CorruptedGameError, Database, MemoryDatabase};
+use server::{maintenance, requests};
+use test_utils::*;
+use tokio::sync::mpsc;
+
+const RETENTION: Duration = Duration::from_secs(60 * 60);
+
+fn write_game(database: &mut MemoryDatabase, game_over: bool, age: Option<Duration>) -> GameState {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let mut game = g.database_mut().game().clone();
+    game.id = database.generate_game_id().unwrap();
+    if game_over {
+        game.data.phase = GamePhase::GameOver { winner: Side::Overlord };
+    }
+    game.data.completed_at = age.map(|age| SystemClock.now() - age);
+    database.write_game(&game).unwrap();
+    game
+}
+
+/// [MemoryDatabase] which fails to load one game as if it were corrupted.
+struct CorruptedDatabase {
+    inner: MemoryDatabase,
+    corrupted: GameId,
+}
+
+impl Database for CorruptedDatabase {
+    fn generate_game_id(&self) -> Result<GameId> {
+        self.inner.generate_game_id()
+    }
+
+    fn has_game(&self, id: GameId) -> Result<bool> {
+        self.inner.has_game(id)
+    }
+
+    fn game_ids(&self) -> Result<Vec<GameId>> {
+        self.inner.game_ids()
+    }
+
+    fn game(&self, id: GameId) -> Result<GameState> {
+        if id == self.corrupted {
+            Err(CorruptedGameError { game_id: id, error: anyhow!("Corrupted") }.into())
+        } else {
+            self.inner.game(id)
+        }
+    }
+
+    fn write_game(&mut self, game: &GameState) -> Result<()> {
+        self.inner.write_game(game)
+    }
+
+    fn delete_game(&mut self, id: GameId) -> Result<()> {
+        self.inner.delete_game(id)
+    }
+
+    fn has_player(&self, player_id: PlayerId) -> Result<bool> {
+        self.inner.has_player(player_id)
+    }
+
+    fn player(&self, player_id: PlayerId) -> Result<Option<PlayerData>> {
+        self.inner.player(player_id)
+    }
+
+    fn write_player(&mut self, player: &PlayerData) -> Result<()> {
+        self.inner.write_player(player)
+    }
+
+    fn adapt_player_identifier(&mut self, identifier: &PlayerIdentifier) -> Result<PlayerId> {
+        self.inner.adapt_player_identifier(identifier)
+    }
+}
+
+#[test]
+fn sweep_removes_expired_games() {
+    let mut database = MemoryDatabase::new();
+    let expired = write_game(&mut database, true, Some(RETENTION * 2));
+    let recent = write_game(&mut database, true, Some(Duration::from_secs(60)));
+    let ongoing = write_game(&mut database, false, None);
+
+    let deleted = maintenance::sweep_finished_games(&mut database, RETENTION).unwrap();
+    assert_eq!(vec![expired.id], deleted);
+    assert!(!database.has_game(expired.id).unwrap());
+    assert!(database.has_game(recent.id).unwrap());
+    assert!(database.has_game(ongoing.id).unwrap());
+}
+
+#[test]
+fn sweep_stamps_completion_time() {
+    let mut database = MemoryDatabase::new();
+    let game = write_game(&mut database, true, None);
+
+    assert!(maintenance::sweep_finished_games(&mut database, RETENTION).unwrap().is_empty());
+    assert!(database.game(game.id).unwrap().data.completed_at.is_some());
+    assert!(maintenance::sweep_finished_games(&mut database, Duration::ZERO)
+        .unwrap()
+        .contains(&game.id));
+}
+
+#[test]
+fn sweep_skips_live_channels() {
+    let mut database = MemoryDatabase::new();
+    let game = write_game(&mut database, true, Some(RETENTION * 2));
+    let (tx, rx) = mpsc::channel(4);
+    requests::register_channel(game.champion.id, tx);
+
+    assert!(maintenance::sweep_finished_games(&mut database, RETENTION).unwrap().is_empty());
+    assert!(database.has_game(game.id).unwrap());
+
+    drop(rx);
+    assert_eq!(vec![game.id], maintenance::sweep_finished_games(&mut database, RETENTION).unwrap());
+    assert!(!requests::is_connected(game.champion.id));
+}
+
+#[test]
+fn sweep_skips_corrupted_games() {
+    let mut inner = MemoryDatabase::new();
+    let corrupted = write_game(&mut inner, true, Some(RETENTION * 2));
+    let expired = write_game(&mut inner, true, Some(RETENTION * 2));
+    let mut database = CorruptedDatabase { inner, corrupted: corrupted.id };
+
+    assert_eq!(
+        vec![expired.id],
+        maintenance::sweep_finished_games(&mut database, RETENTION).unwrap()
+    );
+    assert!(database.has_game(corrupted.id).unwrap());
+}
@@ -0,0 +1,101 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::player_name::PlayerId;
+use data::primitives::{GameId, Side};
+use server::database::{Database, MemoryDatabase};
+use server::requests;
+use test_utils::*;
+
+#[test]
+fn write_and_read_game() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let mut game = g.database_mut().game().clone();
+    let mut database = MemoryDatabase::new();
+    game.id = database.generate_game_id().unwrap();
+    assert!(!database.has_game(game.id).unwrap());
+
+    database.write_game(&game).unwrap();
+    assert!(database.has_game(game.id).unwrap());
+    assert_eq!(vec![game.id], database.game_ids().unwrap());
+    assert_eq!(
+        game.player(Side::Overlord).score,
+        database.game(game.id).unwrap().player(Side::Overlord).score
+    );
+}
+
+#[test]
+fn overwrite_game() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let mut game = g.database_mut().game().clone();
+    let mut database = MemoryDatabase::new();
+    game.id = database.generate_game_id().unwrap();
+    database.write_game(&game).unwrap();
+
+    game.player_mut(Side::Overlord).score = 3;
+    database.write_game(&game).unwrap();
+    assert_eq!(3, database.game(game.id).unwrap().player(Side::Overlord).score);
+    assert_eq!(1, database.game_ids().unwrap().len());
+}
+
+#[test]
+fn clones_share_storage() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let mut game = g.database_mut().game().clone();
+    let database = MemoryDatabase::new();
+    let mut clone = database.clone();
+    game.id = clone.generate_game_id().unwrap();
+    clone.write_game(&game).unwrap();
+    assert!(database.has_game(game.id).unwrap());
+    assert_ne!(game.id, database.generate_game_id().unwrap());
+}
+
+#[test]
+fn missing_game() {
+    let database = MemoryDatabase::new();
+    assert!(database.game(GameId::new(1)).is_err());
+}
+
+#[test]
+fn list_games_for_player() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let user_id = g.user_id();
+    let mut first = g.database_mut().game().clone();
+    let mut database = MemoryDatabase::new();
+    first.id = database.generate_game_id().unwrap();
+    database.write_game(&first).unwrap();
+
+    let mut second = first.clone();
+    second.id = database.generate_game_id().unwrap();
+    std::mem::swap(&mut second.overlord.id, &mut second.champion.id);
+    database.write_game(&second).unwrap();
+
+    let mut other = new_game(Side::Overlord, Args::default());
+    let other_id = other.user_id();
+    let mut unrelated = other.database_mut().game().clone();
+    unrelated.id = database.generate_game_id().unwrap();
+    database.write_game(&unrelated).unwrap();
+
+    let mut games = requests::list_games(&database, user_id).unwrap();
+    games.sort();
+    assert_eq!(
+        vec![adapters::game_identifier(first.id), adapters::game_identifier(second.id)],
+        games
+    );
+    assert_eq!(
+        vec![adapters::game_identifier(unrelated.id)],
+        requests::list_games(&database, other_id).unwrap()
+    );
+    assert!(database.games_for_player(PlayerId::Database(999_999_999)).unwrap().is_empty());
+}
@@ -0,0 +1,90 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::primitives::{GameId, RoomId, Side};
+use protos::spelldawn::game_action::Action;
+use protos::spelldawn::GainManaAction;
+use server::database::{self, CorruptedGameError};
+use server::migrations;
+use test_utils::*;
+
+const GAME_V1: &[u8] = include_bytes!("data/game_v1.json");
+
+#[test]
+fn deserialize_v1_game() {
+    let game = migrations::deserialize_game(GAME_V1).unwrap();
+    assert_eq!(GameId::new(1_000_000), game.id);
+    assert!(game.room_state.contains_key(&RoomId::RoomA));
+    assert!(game.data.turn_timer.is_none());
+    assert!(game.data.turn_history.is_empty());
+    assert!(!game.data.config.disclose_on_game_over);
+    assert!(game.overlord.cosmetics.card_back.is_none());
+    assert!(game.overlord.mana_state.restricted_mana.is_empty());
+    let raid = game.data.raid.as_ref().expect("raid");
+    assert_eq!(RoomId::RoomA, raid.target);
+    assert!(raid.queued_rooms.is_empty());
+    assert!(raid.current_room.is_none());
+    assert!(game.all_cards().all(|card| !card.data.is_token && card.data.damage == 0));
+}
+
+#[test]
+fn round_trip_current_version() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let game = g.database_mut().game();
+    let serialized = migrations::serialize_game(game).unwrap();
+    let deserialized = migrations::deserialize_game(&serialized).unwrap();
+    assert_eq!(game.id, deserialized.id);
+    assert_eq!(game.cards(Side::Overlord).len(), deserialized.cards(Side::Overlord).len());
+}
+
+#[test]
+fn round_trip_undo_history() {
+    let mut g = new_game(Side::Overlord, Args { mana: 5, ..Args::default() });
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    let game = g.database_mut().game();
+    assert_eq!(1, game.undo_history.len());
+
+    let serialized = migrations::serialize_game(game).unwrap();
+    let deserialized = migrations::deserialize_game(&serialized).unwrap();
+    assert_eq!(1, deserialized.undo_history.len());
+    let step = &deserialized.undo_history[0];
+    assert_eq!(Side::Overlord, step.side);
+    assert_eq!(5, step.snapshot.player(Side::Overlord).mana_state.base_mana);
+    assert!(step.snapshot.undo_history.is_empty());
+}
+
+#[test]
+fn reject_unknown_version() {
+    let payload = format!(
+        "{{\"version\":{},\"game\":{}}}",
+        migrations::CURRENT_SCHEMA_VERSION + 1,
+        std::str::from_utf8(GAME_V1).unwrap()
+    );
+    assert!(migrations::deserialize_game(payload.as_bytes()).is_err());
+}
+
+#[test]
+fn quarantine_corrupted_game() {
+    let db = sled::Config::new().temporary(true).open().unwrap();
+    let games = db.open_tree("games").unwrap();
+    let quarantine = db.open_tree("quarantined_games").unwrap();
+    let id = GameId::new(1);
+    games.insert(id.key(), b"not a game".as_ref()).unwrap();
+
+    let error = database::read_game(&games, &quarantine, id).unwrap_err();
+    let corrupted = error.downcast_ref::<CorruptedGameError>().unwrap();
+    assert_eq!(id, corrupted.game_id);
+    assert!(!games.contains_key(id.key()).unwrap());
+    assert!(quarantine.contains_key(id.key()).unwrap());
+}
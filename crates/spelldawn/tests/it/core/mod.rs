@@ -13,5 +13,25 @@
 // limitations under the License.
 
 mod action_tests;
+mod agent_tests;
+mod asset_tests;
+mod card_grid_tests;
+mod confirmation_tests;
+mod connection_tests;
 mod create_game_tests;
+mod debug_tests;
+mod face_up_tests;
+mod hidden_information_tests;
+mod localization_tests;
+mod maintenance_tests;
+mod memory_database_tests;
+mod migration_tests;
+mod play_obstacle_tests;
+mod player_name_tests;
 mod raid_tests;
+mod rest_tests;
+mod rules_text_tests;
+mod token_tests;
+mod turn_banner_tests;
+mod turn_timer_tests;
+mod visit_room_tests;
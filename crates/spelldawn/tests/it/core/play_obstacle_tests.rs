@@ -0,0 +1,97 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cards::test_cards::WEAPON_COST;
+use data::card_name::CardName;
+use data::primitives::{RoomId, Side};
+use rules::flags;
+use rules::flags::PlayObstacle;
+use test_utils::client::TestSession;
+use test_utils::*;
+
+fn obstacle(g: &mut TestSession, card_name: CardName) -> Option<PlayObstacle> {
+    let card_id = server_card_id(g.add_to_hand(card_name));
+    flags::play_obstacle(g.database_mut().game(), Side::Champion, card_id)
+}
+
+#[test]
+fn playable() {
+    let mut g = new_game(Side::Champion, Args::default());
+    assert_eq!(None, obstacle(&mut g, CardName::TestChampionSpell));
+}
+
+#[test]
+fn not_your_turn() {
+    let mut g = new_game(Side::Champion, Args { turn: Some(Side::Overlord), ..Args::default() });
+    assert_eq!(Some(PlayObstacle::NotYourTurn), obstacle(&mut g, CardName::TestChampionSpell));
+}
+
+#[test]
+fn not_enough_mana() {
+    let mut g = new_game(Side::Champion, Args { mana: 1, ..Args::default() });
+    assert_eq!(
+        Some(PlayObstacle::NotEnoughMana { short_by: WEAPON_COST - 1 }),
+        obstacle(&mut g, CardName::TestWeapon3Attack12Boost3Cost)
+    );
+}
+
+#[test]
+fn no_action_points() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.database_mut().game_mut().player_mut(Side::Champion).actions = 0;
+    assert_eq!(Some(PlayObstacle::NoActionPoints), obstacle(&mut g, CardName::TestChampionSpell));
+}
+
+#[test]
+fn no_legal_target() {
+    let mut g = new_game(Side::Champion, Args::default());
+    assert_eq!(
+        Some(PlayObstacle::NoLegalTarget),
+        obstacle(&mut g, CardName::TestChampionSpellBanishDiscard)
+    );
+}
+
+#[test]
+fn wrong_phase() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.initiate_raid(RoomId::Vault);
+    assert_eq!(Some(PlayObstacle::WrongPhase), obstacle(&mut g, CardName::TestChampionSpell));
+}
+
+#[test]
+fn card_not_in_hand() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let card_id = server_card_id(g.play_from_hand(CardName::TestWeapon2Attack));
+    assert_eq!(
+        Some(PlayObstacle::PlayConditionUnmet),
+        flags::play_obstacle(g.database_mut().game(), Side::Champion, card_id)
+    );
+}
+
+#[test]
+fn play_condition_unmet() {
+    let mut g = new_game(Side::Champion, Args::default());
+    assert_eq!(
+        Some(PlayObstacle::PlayConditionUnmet),
+        obstacle(&mut g, CardName::TestChampionSpellRequiresMinion)
+    );
+}
+
+#[test]
+fn play_condition_met() {
+    let mut g = new_game(Side::Champion, Args { turn: Some(Side::Overlord), ..Args::default() });
+    g.play_with_target_room(CardName::TestMinionEndRaid, RoomId::RoomA);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert_eq!(None, obstacle(&mut g, CardName::TestChampionSpellRequiresMinion));
+}
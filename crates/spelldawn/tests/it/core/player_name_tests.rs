@@ -0,0 +1,91 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::primitives::Side;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::game_object_identifier::Id;
+use protos::spelldawn::{CardView, CommandList, GameObjectIdentifier, PlayerName};
+use server::requests::GameResponse;
+use test_utils::*;
+
+fn assert_specified(player_name: i32) {
+    assert_ne!(player_name, PlayerName::Unspecified as i32);
+}
+
+fn assert_identifier_specified(identifier: &Option<GameObjectIdentifier>) {
+    match identifier.as_ref().and_then(|identifier| identifier.id.as_ref()) {
+        Some(Id::Deck(player)) | Some(Id::DiscardPile(player)) | Some(Id::Identity(player)) => {
+            assert_specified(*player)
+        }
+        Some(Id::CardId(_)) => {}
+        None => panic!("Missing game object identifier"),
+    }
+}
+
+fn assert_cards_specified(cards: &[CardView]) {
+    for card in cards {
+        assert_specified(card.owning_player);
+    }
+}
+
+/// Asserts that no [GameObjectIdentifier] or [CardView] in `commands` has an
+/// unspecified [PlayerName].
+fn assert_player_names_specified(commands: &CommandList) {
+    for command in commands.commands.iter().filter_map(|c| c.command.as_ref()) {
+        match command {
+            Command::UpdateGameView(update) => {
+                assert_cards_specified(&update.game.as_ref().expect("Game").cards)
+            }
+            Command::DisplayRewards(rewards) => assert_cards_specified(&rewards.rewards),
+            Command::MoveGameObjects(move_objects) => {
+                for game_object_move in &move_objects.moves {
+                    assert_identifier_specified(&game_object_move.id);
+                }
+            }
+            Command::FireProjectile(projectile) => {
+                assert_identifier_specified(&projectile.source_id);
+                assert_identifier_specified(&projectile.target_id);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn assert_response_specified(response: &GameResponse) {
+    assert_player_names_specified(&response.command_list);
+    if let Some((_, commands)) = &response.opponent_response {
+        assert_player_names_specified(commands);
+    }
+}
+
+#[test]
+fn player_names_specified() {
+    for_each_side(|side| {
+        let mut g = new_game(side, Args { turn: Some(Side::Champion), ..Args::default() });
+        assert_player_names_specified(&g.connect(g.user_id()).expect("Connection error"));
+        assert_player_names_specified(&g.connect(g.opponent_id()).expect("Connection error"));
+    });
+}
+
+#[test]
+fn player_names_specified_during_raid() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    assert_response_specified(&g.initiate_raid(ROOM_ID));
+    assert_response_specified(&g.click_on(g.user_id(), "Test Weapon"));
+    assert_player_names_specified(&g.connect(g.user_id()).expect("Connection error"));
+    assert_player_names_specified(&g.connect(g.opponent_id()).expect("Connection error"));
+}
@@ -12,21 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
+use actions::legal_actions;
 use cards::test_cards::WEAPON_COST;
 use core_ui::icons;
 use data::card_name::CardName;
-use data::game_actions::{AccessPhaseAction, EncounterAction, PromptAction, UserAction};
-use data::primitives::{RoomId, Side};
+use data::card_state::CardPosition;
+use data::game::{GameState, InternalRaidPhase, RaidJumpRequest};
+use data::game_actions::{
+    AccessPhaseAction, ActivationPhaseAction, CardTarget, EncounterAction, PossibleResponse,
+    PromptAction, UserAction,
+};
+use data::primitives::{AbilityId, CardId, DefenderPosition, RoomId, RoomLocation, Side};
+use data::random;
+use data::updates::InitiatedBy;
 use insta::assert_snapshot;
+use panels::panel_address::PanelAddress;
 use protos::spelldawn::game_action::Action;
+use protos::spelldawn::game_command::Command;
 use protos::spelldawn::game_object_identifier::Id;
 use protos::spelldawn::object_position::Position;
 use protos::spelldawn::{
-    ClientRoomLocation, GainManaAction, InitiateRaidAction, ObjectPositionBrowser,
-    ObjectPositionDiscardPile, ObjectPositionIdentity, ObjectPositionIdentityContainer,
-    ObjectPositionRaid, ObjectPositionRoom, PlayerName, SpendActionPointAction,
+    ClientRoomLocation, FetchPanelAction, GainManaAction, InitiateRaidAction,
+    ObjectPositionBrowser, ObjectPositionDiscardPile, ObjectPositionIdentity,
+    ObjectPositionIdentityContainer, ObjectPositionRaid, ObjectPositionRoom, PlayerName,
+    SpendActionPointAction,
 };
-use test_utils::client::HasText;
+use rules::{mana, mutations, queries};
+use serde_json::{de, ser};
+use server::rest;
+use test_utils::client::{HasText, TestSession};
+use test_utils::fake_database;
 use test_utils::summarize::Summary;
 use test_utils::*;
 
@@ -82,7 +99,8 @@ fn initiate_raid() {
                     server_card_id(minion_id)
                 )
             )),
-            UserAction::PromptAction(PromptAction::EncounterAction(EncounterAction::NoWeapon))
+            UserAction::PromptAction(PromptAction::EncounterAction(EncounterAction::NoWeapon)),
+            UserAction::PromptAction(PromptAction::AbortRaid)
         ]
     );
 
@@ -128,6 +146,195 @@ fn use_weapon() {
     assert_snapshot!(Summary::summarize(&response));
 }
 
+#[test]
+fn combat_preview() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let boost_id = g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    let strong_id = g.play_from_hand(CardName::TestWeapon5Attack);
+    let weak_id = g.play_from_hand(CardName::TestWeapon2Attack);
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+
+    let preview = |card_id| {
+        g.user
+            .interface
+            .card_anchors()
+            .iter()
+            .find(|anchor| anchor.card_id == Some(card_id))
+            .and_then(|anchor| anchor.node.as_ref())
+            .expect("Combat preview")
+            .get_text()
+    };
+    assert_eq!(vec![format!("Defeat for 1{}", icons::MANA)], preview(boost_id));
+    assert_eq!(vec!["Defeat"], preview(strong_id));
+    assert_eq!(vec!["Cannot defeat"], preview(weak_id));
+}
+
+#[test]
+fn game_log() {
+    let mut g = new_game(Side::Champion, Args::default());
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+
+    let response = g
+        .perform_action(
+            Action::FetchPanel(FetchPanelAction {
+                panel_address: Some(PanelAddress::GameLog.into()),
+            }),
+            g.user_id(),
+        )
+        .expect("Request failed");
+    let node = match response.command_list.commands[0].command.as_ref() {
+        Some(Command::UpdatePanels(update)) => update.panels[0].node.clone().expect("Node"),
+        _ => panic!("Expected UpdatePanels command"),
+    };
+    assert!(node.has_text(format!("Champion initiated raid on {:?}", ROOM_ID)));
+}
+
+#[test]
+fn abort_raid() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let (scheme_id, minion_id) = setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    assert!(g.user.interface.controls().has_text("Retreat"));
+
+    g.click_on(g.user_id(), "Retreat");
+    assert_eq!(2, g.me().actions());
+    assert!(!g.user.data.raid_active());
+    assert!(!g.opponent.data.raid_active());
+    assert_eq!(
+        g.user.data.object_position(Id::CardId(minion_id)),
+        Position::Room(ObjectPositionRoom {
+            room_id: CLIENT_ROOM_ID.into(),
+            room_location: ClientRoomLocation::Front.into()
+        })
+    );
+    assert_eq!(
+        g.user.data.object_position(Id::CardId(scheme_id)),
+        Position::Room(ObjectPositionRoom {
+            room_id: CLIENT_ROOM_ID.into(),
+            room_location: ClientRoomLocation::Back.into()
+        })
+    );
+
+    let game = g.database_mut().game();
+    assert!(!game.card(server_card_id(scheme_id)).is_revealed_to(Side::Champion));
+    assert!(game.room_state[&ROOM_ID].last_raided.is_some());
+    assert!(game.data.log.iter().any(|entry| entry.contains("aborted raid")));
+}
+
+#[test]
+fn raid_two_rooms() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let weapon_id = server_card_id(g.play_from_hand(CardName::TestWeapon5Attack));
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    g.play_from_hand(CardName::TestScheme31);
+    let first_id = server_card_id(g.play_from_hand(CardName::TestMinionEndRaid));
+    let second_id =
+        server_card_id(g.play_with_target_room(CardName::TestMinionEndRaid, RoomId::RoomB));
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert!(g.dawn());
+
+    let game = g.database_mut().game_mut();
+    initiate_with_added_room(game, RoomId::RoomB);
+    assert_eq!(first_id, game.raid_defender().expect("Defender"));
+    assert_eq!(ROOM_ID, game.raid().expect("Raid").encounter_room());
+    defeat_minion(game, weapon_id, first_id);
+
+    assert_eq!(second_id, game.raid_defender().expect("Defender"));
+    assert_eq!(RoomId::RoomB, game.raid().expect("Raid").encounter_room());
+    defeat_minion(game, weapon_id, second_id);
+
+    let raid = game.raid().expect("Raid");
+    assert_eq!(InternalRaidPhase::Access, raid.internal_phase);
+    assert_eq!(ROOM_ID, raid.target);
+    assert_eq!(ROOM_ID, raid.encounter_room());
+}
+
+#[test]
+fn raid_added_room_without_defenders() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let weapon_id = server_card_id(g.play_from_hand(CardName::TestWeapon5Attack));
+    let (_, minion_id) = setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+
+    let game = g.database_mut().game_mut();
+    initiate_with_added_room(game, RoomId::RoomB);
+    assert_eq!(server_card_id(minion_id), game.raid_defender().expect("Defender"));
+    defeat_minion(game, weapon_id, server_card_id(minion_id));
+
+    let raid = game.raid().expect("Raid");
+    assert_eq!(InternalRaidPhase::Access, raid.internal_phase);
+    assert_eq!(ROOM_ID, raid.encounter_room());
+    assert!(raid.queued_rooms.is_empty());
+}
+
+#[test]
+fn move_defender_out_of_raided_room() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let weapon_id = server_card_id(g.play_from_hand(CardName::TestWeapon5Attack));
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    let inner_id = server_card_id(g.play_from_hand(CardName::TestMinionEndRaid));
+    let middle_id = server_card_id(g.play_from_hand(CardName::TestMinionEndRaid));
+    let outer_id = server_card_id(g.play_from_hand(CardName::TestMinionEndRaid));
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert!(g.dawn());
+
+    let game = g.database_mut().game_mut();
+    raids::initiate(game, ROOM_ID, InitiatedBy::Card, |_, _| {}).expect("Error initiating raid");
+    assert_eq!(outer_id, game.raid_defender().expect("Defender"));
+
+    mutations::move_defender(game, middle_id, RoomId::RoomB, DefenderPosition::Front)
+        .expect("Error moving defender");
+    assert_eq!(Some((RoomId::RoomB, 0)), queries::minion_position(game, middle_id));
+    assert_eq!(outer_id, game.raid_defender().expect("Defender"));
+
+    defeat_minion(game, weapon_id, outer_id);
+    assert_eq!(inner_id, game.raid_defender().expect("Defender"));
+    defeat_minion(game, weapon_id, inner_id);
+    assert_eq!(InternalRaidPhase::Access, game.raid().expect("Raid").internal_phase);
+}
+
+#[test]
+fn move_encountered_defender() {
+    let mut g = new_game(Side::Champion, Args::default());
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    g.play_from_hand(CardName::TestMinionEndRaid);
+    let outer_id = server_card_id(g.play_from_hand(CardName::TestMinionEndRaid));
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+
+    let game = g.database_mut().game_mut();
+    raids::initiate(game, ROOM_ID, InitiatedBy::Card, |_, _| {}).expect("Error initiating raid");
+    assert_eq!(outer_id, game.raid_defender().expect("Defender"));
+
+    mutations::move_defender(game, outer_id, RoomId::RoomB, DefenderPosition::Front)
+        .expect("Error moving defender");
+    assert!(matches!(
+        game.raid().expect("Raid").jump_request,
+        Some(RaidJumpRequest::SkipEncounter { room_id: ROOM_ID, less_than: 1 })
+    ));
+    assert!(
+        mutations::move_defender(game, outer_id, RoomId::RoomB, DefenderPosition::Back).is_err()
+    );
+}
+
+fn initiate_with_added_room(game: &mut GameState, room_id: RoomId) {
+    raids::initiate(game, ROOM_ID, InitiatedBy::Card, |game, _| {
+        game.raid_mut().expect("Raid").jump_request = Some(RaidJumpRequest::AddRoom(room_id));
+    })
+    .expect("Error initiating raid");
+}
+
+fn defeat_minion(game: &mut GameState, weapon_id: CardId, minion_id: CardId) {
+    actions::handle_user_action(
+        game,
+        Side::Champion,
+        UserAction::PromptAction(PromptAction::EncounterAction(EncounterAction::UseWeaponAbility(
+            weapon_id, minion_id,
+        ))),
+    )
+    .expect("Error using weapon");
+}
+
 #[test]
 fn minion_with_shield() {
     let mut g = new_game(Side::Champion, Args::default());
@@ -230,6 +437,41 @@ fn score_scheme_card() {
     assert_snapshot!(Summary::summarize(&response));
 }
 
+#[test]
+fn random_actions_complete_raid() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let weapon_id = g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    let (_, minion_id) = setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+
+    let encounter = g.legal_actions(Side::Champion);
+    for action in [
+        EncounterAction::UseWeaponAbility(server_card_id(weapon_id), server_card_id(minion_id)),
+        EncounterAction::NoWeapon,
+    ] {
+        assert!(
+            encounter.contains(&UserAction::PromptAction(PromptAction::EncounterAction(action)))
+        );
+    }
+
+    let mut game = g.database_mut().game().clone();
+    for _ in 0..100 {
+        if game.data.raid.is_none() {
+            break;
+        }
+        let side = if actions::can_take_action(&game, Side::Champion) {
+            Side::Champion
+        } else {
+            Side::Overlord
+        };
+        let legal =
+            legal_actions::evaluate(&game, side).expect("Legal actions").collect::<Vec<_>>();
+        let action = random::choose(&mut game, legal.into_iter()).expect("No legal actions");
+        actions::handle_user_action(&mut game, side, action).expect("Error handling action");
+    }
+    assert!(game.data.raid.is_none());
+}
+
 #[test]
 fn complete_raid() {
     let mut g = new_game(Side::Champion, Args::default());
@@ -589,3 +831,386 @@ fn raid_add_defender() {
     let response = g.click_on(g.user_id(), "Test Weapon");
     assert_snapshot!(Summary::summarize(&response));
 }
+
+#[test]
+fn raid_state_machine() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let weapon_id = server_card_id(g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost));
+    let (scheme_id, minion_id) = setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    let (scheme_id, minion_id) = (server_card_id(scheme_id), server_card_id(minion_id));
+
+    // Begin -> Encounter
+    g.initiate_raid(ROOM_ID);
+    assert_raid_prompts(
+        &g,
+        vec![
+            PromptAction::EncounterAction(EncounterAction::UseWeaponAbility(weapon_id, minion_id)),
+            PromptAction::EncounterAction(EncounterAction::NoWeapon),
+            PromptAction::AbortRaid,
+        ],
+    );
+
+    // Encounter -> Access
+    g.click_on(g.user_id(), "Test Weapon");
+    assert_raid_prompts(
+        &g,
+        vec![
+            PromptAction::AccessPhaseAction(AccessPhaseAction::ScoreCard(scheme_id)),
+            PromptAction::AccessPhaseAction(AccessPhaseAction::EndRaid),
+        ],
+    );
+
+    // Access -> Access
+    click_on_score(&mut g);
+    assert_raid_prompts(&g, vec![PromptAction::AccessPhaseAction(AccessPhaseAction::EndRaid)]);
+
+    // Access -> End
+    click_on_end_raid(&mut g);
+    assert_raid_ended(&g);
+}
+
+#[test]
+fn raid_state_machine_no_defenders() {
+    let mut g = new_game(
+        Side::Champion,
+        Args { turn: Some(Side::Overlord), actions: 1, ..Args::default() },
+    );
+    let scheme_id = server_card_id(g.play_from_hand(CardName::TestScheme31));
+
+    // Begin -> Access
+    g.initiate_raid(ROOM_ID);
+    assert_raid_prompts(
+        &g,
+        vec![
+            PromptAction::AccessPhaseAction(AccessPhaseAction::ScoreCard(scheme_id)),
+            PromptAction::AccessPhaseAction(AccessPhaseAction::EndRaid),
+        ],
+    );
+
+    // Access -> End
+    click_on_end_raid(&mut g);
+    assert_raid_ended(&g);
+}
+
+#[test]
+fn raid_state_machine_jump_to_encounter() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.add_to_hand(CardName::TestMinionEndRaid);
+    g.play_from_hand(CardName::TemporalStalker);
+
+    // Begin -> Encounter
+    set_up_minion_combat(&mut g);
+    assert!(g.legal_actions(Side::Champion).iter().all(|action| matches!(
+        action,
+        UserAction::PromptAction(
+            PromptAction::EncounterAction(EncounterAction::CardAction(_)) | PromptAction::AbortRaid
+        )
+    )));
+
+    // Encounter -> Encounter, via a jump request to the summoned minion
+    g.click_on(g.opponent_id(), format!("Pay 2{}", icons::ACTION));
+    assert_raid_prompts(
+        &g,
+        vec![PromptAction::EncounterAction(EncounterAction::NoWeapon), PromptAction::AbortRaid],
+    );
+
+    // Encounter -> End
+    click_on_continue(&mut g);
+    assert_raid_ended(&g);
+}
+
+#[test]
+fn encounter_prompt_timeout_continues_raid() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let (scheme_id, _) = setup_raid_target(&mut g, CardName::TestMinionDealDamage);
+    let scheme_id = server_card_id(scheme_id);
+    g.database_mut().game_mut().data.config.prompt_timeout = Some(Duration::from_secs(30));
+
+    let encounter_prompts =
+        vec![PromptAction::EncounterAction(EncounterAction::NoWeapon), PromptAction::AbortRaid];
+    g.initiate_raid(ROOM_ID);
+    assert_raid_prompts(&g, encounter_prompts.clone());
+
+    g.database_mut().clock.advance(Duration::from_secs(10));
+    g.connect(g.user_id()).expect("Connection error");
+    assert_raid_prompts(&g, encounter_prompts);
+
+    // Timing out continues the raid rather than retreating from it
+    assert!(!PromptAction::AbortRaid.is_safe_default());
+    g.database_mut().clock.advance(Duration::from_secs(30));
+    g.connect(g.user_id()).expect("Connection error");
+    assert_raid_prompts(
+        &g,
+        vec![
+            PromptAction::AccessPhaseAction(AccessPhaseAction::ScoreCard(scheme_id)),
+            PromptAction::AccessPhaseAction(AccessPhaseAction::EndRaid),
+        ],
+    );
+}
+
+/// Asserts that the Champion is the active raid player with exactly the
+/// `expected` prompts available, and that the Overlord cannot act.
+fn assert_raid_prompts(g: &TestSession, expected: Vec<PromptAction>) {
+    assert!(g.user.data.raid_active());
+    assert!(g.opponent.data.raid_active());
+    assert_eq!(
+        g.legal_actions(Side::Champion),
+        expected.into_iter().map(UserAction::PromptAction).collect::<Vec<_>>()
+    );
+    assert!(g.legal_actions_result(Side::Overlord).is_err());
+}
+
+fn assert_raid_ended(g: &TestSession) {
+    assert!(!g.user.data.raid_active());
+    assert!(!g.opponent.data.raid_active());
+    if let Ok(mut actions) = g.legal_actions_result(Side::Champion) {
+        assert!(!actions.any(|action| matches!(action, UserAction::PromptAction(_))));
+    }
+}
+
+#[test]
+fn raid_specific_mana() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    mana::set(g.database_mut().game_mut(), Side::Champion, 0);
+    mana::gain_for_current_raid(g.database_mut().game_mut(), Side::Champion, 5)
+        .expect("Error gaining mana");
+    g.connect(g.user_id()).expect("Connection error");
+    g.connect(g.opponent_id()).expect("Connection error");
+
+    assert_eq!(5, g.me().bonus_mana());
+    assert_eq!(5, g.opponent.other_player.bonus_mana());
+    assert_eq!(0, g.you().bonus_mana());
+    assert_eq!(0, g.user.other_player.bonus_mana());
+
+    g.click_on(g.user_id(), "Test Weapon");
+    assert!(!g.user.interface.controls().has_text("Raid Mana"));
+    assert_eq!(0, g.me().mana());
+    assert_eq!(4, g.me().bonus_mana());
+
+    click_on_end_raid(&mut g);
+    assert!(!g.user.data.raid_active());
+    assert_eq!(0, g.me().mana());
+    assert_eq!(0, g.me().bonus_mana());
+    assert!(g.database_mut().game().player(Side::Champion).mana_state.specific_raid_mana.is_none());
+}
+
+#[test]
+fn choose_raid_specific_mana_source() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    mana::gain_for_current_raid(g.database_mut().game_mut(), Side::Champion, 5)
+        .expect("Error gaining mana");
+    g.connect(g.user_id()).expect("Connection error");
+    g.connect(g.opponent_id()).expect("Connection error");
+
+    let base_mana = g.me().mana();
+    g.click_on(g.user_id(), "Test Weapon");
+    assert!(g.user.interface.controls().has_text(format!("Pay 1{}", icons::MANA)));
+    assert!(g.user.interface.controls().has_text("Base Mana"));
+    assert_eq!(base_mana, g.me().mana());
+    assert_eq!(5, g.me().bonus_mana());
+
+    g.click_on(g.user_id(), "Raid Mana");
+    assert_eq!(base_mana, g.me().mana());
+    assert_eq!(4, g.me().bonus_mana());
+}
+
+#[test]
+fn choose_base_mana_source() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    mana::gain_for_current_raid(g.database_mut().game_mut(), Side::Champion, 5)
+        .expect("Error gaining mana");
+    g.connect(g.user_id()).expect("Connection error");
+    g.connect(g.opponent_id()).expect("Connection error");
+
+    let base_mana = g.me().mana();
+    g.click_on(g.user_id(), "Test Weapon");
+    g.click_on(g.user_id(), "Base Mana");
+    assert_eq!(base_mana - 1, g.me().mana());
+    assert_eq!(5, g.me().bonus_mana());
+    assert!(!g.user.interface.controls().has_text("Raid Mana"));
+}
+
+#[test]
+fn weapon_options_sorted_by_cost() {
+    let mut g = new_game(Side::Champion, Args { mana: 13, ..Args::default() });
+    let boost = g.play_from_hand(CardName::TestWeapon4Attack12Boost);
+    let expensive = g.play_from_hand(CardName::TestWeapon2Attack12Boost);
+    let free = g.play_from_hand(CardName::TestWeapon5Attack);
+    let (_, minion_id) = setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.play_from_hand(CardName::TestWeapon2Attack);
+    g.initiate_raid(ROOM_ID);
+
+    let game = g.database_mut().game();
+    assert_eq!(1, game.champion.mana_state.base_mana);
+    assert_eq!(
+        vec![(server_card_id(free), 0), (server_card_id(boost), 1)],
+        queries::weapon_options(game, server_card_id(minion_id))
+    );
+    assert_eq!(
+        Some(2),
+        queries::cost_to_defeat_target(game, server_card_id(expensive), server_card_id(minion_id))
+    );
+}
+
+#[test]
+fn raid_ids_unique_and_increasing() {
+    let mut g = new_game(Side::Champion, Args { actions: 10, ..Args::default() });
+    let mut raid_ids = vec![];
+    for _ in 0..10 {
+        g.initiate_raid(RoomId::Vault);
+        raid_ids.push(g.database_mut().game().raid().expect("Raid").raid_id);
+        g.click_on(g.user_id(), "End Raid");
+    }
+
+    assert!(raid_ids.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn raid_id_follows_test_raid() {
+    let mut g = new_game(Side::Champion, Args { add_raid: true, ..Args::default() });
+    g.database_mut().game_mut().data.raid = None;
+    g.initiate_raid(RoomId::Vault);
+    assert!(g.database_mut().game().raid().expect("Raid").raid_id.0 > RAID_ID.0);
+}
+
+#[test]
+fn raid_id_overflow() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.database_mut().game_mut().data.next_raid_id = u32::MAX;
+    assert_error(g.perform_action(
+        Action::InitiateRaid(InitiateRaidAction {
+            room_id: adapters::room_identifier(RoomId::Vault),
+        }),
+        g.user_id(),
+    ));
+}
+
+#[test]
+fn overlord_response_ability_during_raid() {
+    let mut g = new_game(Side::Overlord, Args { turn: Some(Side::Champion), ..Args::default() });
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    let project_id = g.add_to_hand(CardName::TestProjectRaidResponse);
+    let game = g.database_mut().game_mut();
+    let card_id = server_card_id(project_id);
+    mutations::move_card(game, card_id, CardPosition::Room(RoomId::RoomB, RoomLocation::Occupant))
+        .expect("Error moving card");
+    game.card_mut(card_id).turn_face_up();
+    g.connect(g.user_id()).expect("Connection error");
+    g.connect(g.opponent_id()).expect("Connection error");
+
+    g.initiate_raid(ROOM_ID);
+    let ability_id = AbilityId::new(card_id, 0);
+    assert_contents_equal(
+        g.legal_actions(Side::Overlord),
+        vec![
+            UserAction::PromptAction(PromptAction::ActivationPhaseAction(
+                ActivationPhaseAction::ContinueRaid,
+            )),
+            UserAction::ActivateAbility(ability_id, CardTarget::None),
+        ],
+    );
+    assert!(g.legal_actions_result(Side::Champion).is_err());
+
+    let mana = g.me().mana();
+    g.activate_ability(project_id, 0);
+    assert_eq!(mana + 1, g.me().mana());
+    assert!(g.user.interface.controls().has_text("Continue Raid"));
+
+    g.click_on(g.user_id(), "Continue Raid");
+    assert!(g.legal_actions_result(Side::Overlord).is_err());
+    assert!(g.opponent.interface.controls().has_text("Continue"));
+    click_on_continue(&mut g);
+    assert_raid_ended(&g);
+}
+
+#[test]
+fn preview_possible_overlord_responses() {
+    let mut g = new_game(Side::Overlord, Args { turn: Some(Side::Champion), ..Args::default() });
+    let (scheme_id, minion_id) = setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    let project_id = g.add_to_hand(CardName::TestProjectRaidResponse);
+    let card_id = server_card_id(project_id);
+    let ability_id = AbilityId::new(card_id, 0);
+    let game = g.database_mut().game_mut();
+    mutations::move_card(game, card_id, CardPosition::Room(RoomId::RoomB, RoomLocation::Occupant))
+        .expect("Error moving card");
+
+    let identifier = fake_database::to_player_identifier(g.opponent_id());
+    let response = rest::handle_rest_request(
+        g.database_mut(),
+        "/possible_responses",
+        &ser::to_vec(&identifier).unwrap(),
+    );
+    assert_eq!(200, response.status.as_u16());
+    let responses: Vec<PossibleResponse> = de::from_slice(&response.body).unwrap();
+    assert_contents_equal(
+        responses,
+        vec![
+            PossibleResponse::HiddenCard(server_card_id(scheme_id)),
+            PossibleResponse::HiddenCard(server_card_id(minion_id)),
+            PossibleResponse::HiddenCard(card_id),
+        ],
+    );
+
+    g.database_mut().game_mut().card_mut(card_id).turn_face_up();
+    let responses = queries::possible_opponent_responses(g.database_mut().game(), Side::Champion);
+    assert!(responses.contains(&PossibleResponse::ActivateAbility(ability_id)));
+    assert!(!responses.contains(&PossibleResponse::HiddenCard(card_id)));
+
+    mana::set(g.database_mut().game_mut(), Side::Overlord, 0);
+    let responses = queries::possible_opponent_responses(g.database_mut().game(), Side::Champion);
+    assert!(!responses.contains(&PossibleResponse::ActivateAbility(ability_id)));
+}
+
+#[test]
+fn overlord_without_response_abilities_is_not_prompted() {
+    let mut g = new_game(Side::Overlord, Args { turn: Some(Side::Champion), ..Args::default() });
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    assert!(!queries::can_respond(g.database_mut().game(), Side::Overlord));
+    assert!(!g.user.interface.controls().has_text("Continue Raid"));
+    assert!(g.legal_actions_result(Side::Overlord).is_err());
+    assert!(g.opponent.interface.controls().has_text("Continue"));
+}
+
+#[test]
+fn overlord_with_response_ability_can_respond() {
+    let mut g = new_game(Side::Overlord, Args { turn: Some(Side::Champion), ..Args::default() });
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    let project_id = g.add_to_hand(CardName::TestProjectRaidResponse);
+    let game = g.database_mut().game_mut();
+    let card_id = server_card_id(project_id);
+    mutations::move_card(game, card_id, CardPosition::Room(RoomId::RoomB, RoomLocation::Occupant))
+        .expect("Error moving card");
+    game.card_mut(card_id).turn_face_up();
+    assert!(!queries::can_respond(g.database_mut().game(), Side::Overlord));
+
+    g.initiate_raid(ROOM_ID);
+    assert!(queries::can_respond(g.database_mut().game(), Side::Overlord));
+    assert!(!queries::can_respond(g.database_mut().game(), Side::Champion));
+    assert!(g.user.interface.controls().has_text("Continue Raid"));
+}
+
+#[test]
+fn add_defender_at_front_and_back() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let [first, second, third] =
+        [CardName::TestMinionEndRaid; 3].map(|name| server_card_id(g.add_to_hand(name)));
+    let game = g.database_mut().game_mut();
+    mutations::add_defender(game, first, ROOM_ID, DefenderPosition::Front).unwrap();
+    mutations::add_defender(game, second, ROOM_ID, DefenderPosition::Front).unwrap();
+    mutations::add_defender(game, third, ROOM_ID, DefenderPosition::Back).unwrap();
+
+    // Defenders are encountered from the end of the defender list to the start
+    let mut encounter_order = game.defender_list(ROOM_ID);
+    encounter_order.reverse();
+    assert_eq!(vec![second, first, third], encounter_order);
+}
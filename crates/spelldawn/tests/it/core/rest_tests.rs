@@ -0,0 +1,166 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::{SocketAddr, TcpListener};
+use std::time::Duration;
+
+use cards::decklists;
+use core_ui::actions::InterfaceAction;
+use data::game::MulliganDecision;
+use data::game_actions::{PromptAction, UserAction};
+use data::player_data::{PlayerCosmetics, PlayerData};
+use data::player_name::NamedPlayer;
+use data::primitives::{Locale, Side};
+use protos::spelldawn::game_action::Action;
+use protos::spelldawn::player_identifier::PlayerIdentifierType;
+use protos::spelldawn::{
+    DeckIdentifier, GameAction, GameIdentifier, GameRequest, NewGameAction, PlayerIdentifier,
+};
+use serde_json::{de, ser};
+use server::database::{self, Database, SledDatabase};
+use server::rest;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Response to a raw HTTP request sent by [send].
+struct HttpResponse {
+    status: u16,
+    content_type: Option<String>,
+    body: Vec<u8>,
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn serve_rest_gateway() {
+    cards::initialize::run();
+    let path = std::env::temp_dir().join(format!("spelldawn-rest-test-{}", std::process::id()));
+    database::override_path(path.to_string_lossy().to_string());
+    let identifier = PlayerIdentifier {
+        player_identifier_type: Some(PlayerIdentifierType::DeviceIdentifier(
+            "rest-test".to_string(),
+        )),
+    };
+    let mut db = SledDatabase { flush_on_write: false };
+    let player_id = db.adapt_player_identifier(&identifier).unwrap();
+    db.write_player(&PlayerData {
+        id: player_id,
+        current_game: None,
+        decks: vec![decklists::canonical_deck(player_id, Side::Overlord)],
+        collection: HashMap::new(),
+        cosmetics: PlayerCosmetics::default(),
+        sideboard: HashMap::new(),
+        current_match: None,
+        locale: Locale::default(),
+        pending_invitations: vec![],
+    })
+    .unwrap();
+
+    let address = unused_address();
+    tokio::spawn(rest::serve(address));
+
+    let response = send(address, "GET", "/list_games", b"").await;
+    assert_eq!(405, response.status);
+    assert_eq!(Some("application/json"), response.content_type.as_deref());
+
+    let response = send(address, "POST", "/unknown", b"{}").await;
+    assert_eq!(404, response.status);
+
+    let response = send(address, "POST", "/list_games", b"not json").await;
+    assert_eq!(400, response.status);
+
+    let body = ser::to_vec(&identifier).unwrap();
+    let response = send(address, "POST", "/list_games", &body).await;
+    assert_eq!(200, response.status);
+    assert_eq!(Some("application/json"), response.content_type.as_deref());
+    assert!(de::from_slice::<Vec<GameIdentifier>>(&response.body).unwrap().is_empty());
+
+    let new_game = Action::NewGame(NewGameAction {
+        deck: Some(DeckIdentifier { value: 0 }),
+        opponent_id: Some(adapters::named_player_identifier(NamedPlayer::TestScripted).unwrap()),
+        debug_options: None,
+    });
+    let response = perform(address, &identifier, new_game).await;
+    assert_eq!(200, response.status);
+    let keep = UserAction::PromptAction(PromptAction::MulliganDecision(MulliganDecision::Keep));
+    let response = perform(address, &identifier, keep.as_game_action().unwrap()).await;
+    assert_eq!(200, response.status);
+
+    // The agent's mulligan decision is scheduled in the background after each
+    // request, after which it is the user's turn.
+    let mut legal_actions = vec![];
+    for _ in 0..100 {
+        let response = send(address, "POST", "/legal_actions", &body).await;
+        assert_eq!(200, response.status);
+        legal_actions = de::from_slice::<Vec<UserAction>>(&response.body).unwrap();
+        if legal_actions.contains(&UserAction::GainMana) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(legal_actions.contains(&UserAction::GainMana));
+    std::fs::remove_dir_all(path).ok();
+}
+
+/// Returns a local address which is not currently in use.
+fn unused_address() -> SocketAddr {
+    TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap()
+}
+
+async fn perform(
+    address: SocketAddr,
+    identifier: &PlayerIdentifier,
+    action: Action,
+) -> HttpResponse {
+    let request = GameRequest {
+        action: Some(GameAction { action: Some(action) }),
+        player_id: Some(identifier.clone()),
+    };
+    send(address, "POST", "/perform_action", &ser::to_vec(&request).unwrap()).await
+}
+
+/// Sends an HTTP/1.1 request to `address`, retrying the connection until the
+/// server has started.
+async fn send(address: SocketAddr, method: &str, path: &str, body: &[u8]) -> HttpResponse {
+    let mut stream = None;
+    for _ in 0..100 {
+        if let Ok(connected) = TcpStream::connect(address).await {
+            stream = Some(connected);
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let mut stream = stream.expect("Server did not start");
+
+    let head = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        method,
+        path,
+        address,
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await.unwrap();
+    stream.write_all(body).await.unwrap();
+    let mut response = vec![];
+    stream.read_to_end(&mut response).await.unwrap();
+
+    let split = response.windows(4).position(|w| w == b"\r\n\r\n").expect("Missing headers");
+    let head = String::from_utf8(response[..split].to_vec()).unwrap();
+    let mut lines = head.lines();
+    let status = lines.next().unwrap().split(' ').nth(1).unwrap().parse().unwrap();
+    let content_type = lines.find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.eq_ignore_ascii_case("content-type").then(|| value.trim().to_string())
+    });
+    HttpResponse { status, content_type, body: response[split + 4..].to_vec() }
+}
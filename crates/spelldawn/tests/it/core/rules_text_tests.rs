@@ -0,0 +1,44 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::primitives::{Locale, Side};
+use display::rules_text;
+use test_utils::*;
+
+#[test]
+fn shield_keyword() {
+    let definitions = rules_text::keyword_definitions("Shield 2");
+    assert_eq!(1, definitions.len());
+    assert!(definitions[0].starts_with("<b>Shield:</b>"));
+}
+
+#[test]
+fn keywords_case_insensitive_and_unique() {
+    let definitions =
+        rules_text::keyword_definitions("Initiate a RAID. If it succeeds, raid again face-up.");
+    assert_eq!(2, definitions.len());
+    assert!(definitions[0].starts_with("<b>Raid:</b>"));
+    assert!(definitions[1].starts_with("<b>Face Up:</b>"));
+}
+
+#[test]
+fn vanilla_card_has_no_keywords() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let card_id = server_card_id(g.add_to_hand(CardName::TestWeapon2Attack));
+    let game = g.database_mut().game();
+    let card = game.card(card_id);
+    let text = rules_text::build(game, card, rules::get(card.name), Locale::English).text;
+    assert!(rules_text::keyword_definitions(&text).is_empty());
+}
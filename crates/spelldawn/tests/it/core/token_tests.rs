@@ -0,0 +1,65 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::primitives::{RoomId, RoomLocation, Side};
+use data::updates::{UpdateTracker, Updates};
+use protos::spelldawn::game_command::Command;
+use rules::{mutations, queries};
+use test_utils::*;
+
+#[test]
+fn copy_minion() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion_id = server_card_id(g.play_from_hand(CardName::TestMinionShield1Infernal));
+    let game = g.database_mut().game_mut();
+    game.card_mut(minion_id).turn_face_up();
+    let position = CardPosition::Room(RoomId::RoomB, RoomLocation::Defender);
+    game.updates = UpdateTracker::new(Updates::Push);
+
+    let token_id = mutations::create_token_copy(game, minion_id, position).unwrap();
+    assert_ne!(minion_id, token_id);
+    let token = game.card(token_id);
+    assert_eq!(CardName::TestMinionShield1Infernal, token.name);
+    assert_eq!(position, token.position());
+    assert!(token.data.is_token);
+    assert!(token.is_face_up());
+    assert_eq!(queries::health(game, minion_id), queries::health(game, token_id));
+    assert_eq!(queries::shield(game, minion_id), queries::shield(game, token_id));
+
+    let commands = display::render::render_updates(game, Side::Champion).unwrap();
+    assert!(commands.iter().any(|command| matches!(
+        command,
+        Command::CreateTokenCard(create) if create.card.as_ref().and_then(|c| c.card_id)
+            == Some(adapters::card_identifier(token_id))
+    )));
+}
+
+#[test]
+fn token_banished_when_leaving_play() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion_id = server_card_id(g.play_from_hand(CardName::TestMinionEndRaid));
+    let game = g.database_mut().game_mut();
+    let token_id = mutations::create_token_copy(
+        game,
+        minion_id,
+        CardPosition::Room(RoomId::RoomB, RoomLocation::Defender),
+    )
+    .unwrap();
+
+    mutations::sacrifice_card(game, token_id).unwrap();
+    assert_eq!(CardPosition::Banished(Side::Overlord), game.card(token_id).position());
+    g.connect(g.user_id()).expect("Connection error");
+}
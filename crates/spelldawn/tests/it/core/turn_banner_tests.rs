@@ -0,0 +1,29 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use test_utils::client::HasText;
+use test_utils::*;
+
+#[test]
+fn shows_active_side() {
+    for_each_side(|side| {
+        let mut g = new_game(side, Args::default());
+        assert!(g.user.interface.turn_banner().has_text("Your turn"));
+        assert!(g.opponent.interface.turn_banner().has_text("Opponent's turn"));
+
+        spend_actions_until_turn_over(&mut g, side);
+        assert!(g.user.interface.turn_banner().has_text("Opponent's turn"));
+        assert!(g.opponent.interface.turn_banner().has_text("Your turn"));
+    });
+}
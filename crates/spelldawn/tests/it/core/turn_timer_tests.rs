@@ -0,0 +1,119 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use data::game::{GamePhase, TurnTimer, TurnTimerConfiguration};
+use data::primitives::Side;
+use protos::spelldawn::game_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{CommandList, GainManaAction, TurnTimerView};
+use server::maintenance;
+use test_utils::client::TestSession;
+use test_utils::*;
+
+fn enable_turn_timer(g: &mut TestSession, resign_on_timeout: bool) {
+    let config = TurnTimerConfiguration {
+        initial: Duration::from_secs(60),
+        increment: Duration::from_secs(5),
+        resign_on_timeout,
+    };
+    let game = g.database_mut().game_mut();
+    game.data.config.turn_timer = Some(config);
+    game.data.turn_timer = Some(TurnTimer::new(config));
+}
+
+fn remaining(g: &mut TestSession, side: Side) -> Duration {
+    g.database_mut().game().data.turn_timer.as_ref().expect("Turn timer").remaining(side)
+}
+
+fn user_turn_timer(commands: &CommandList) -> TurnTimerView {
+    commands
+        .commands
+        .iter()
+        .find_map(|c| match &c.command {
+            Some(Command::UpdateGameView(update)) => update.game.as_ref(),
+            _ => None,
+        })
+        .and_then(|game| game.user.as_ref())
+        .and_then(|user| user.turn_timer.clone())
+        .expect("Turn timer view")
+}
+
+#[test]
+fn deducts_elapsed_time() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    enable_turn_timer(&mut g, false);
+
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert_eq!(Duration::from_secs(65), remaining(&mut g, Side::Overlord));
+
+    g.database_mut().clock.advance(Duration::from_secs(10));
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert_eq!(Duration::from_secs(60), remaining(&mut g, Side::Overlord));
+    assert_eq!(Duration::from_secs(60), remaining(&mut g, Side::Champion));
+
+    let commands = g.connect(g.user_id()).expect("Connection error");
+    let view = user_turn_timer(&commands);
+    assert_eq!(60_000, view.remaining_milliseconds);
+    assert!(view.running);
+}
+
+#[test]
+fn timeout_ends_turn() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    enable_turn_timer(&mut g, false);
+
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    g.database_mut().clock.advance(Duration::from_secs(70));
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+
+    let game = g.database_mut().game();
+    assert_eq!(Side::Champion, game.data.turn.side);
+    assert_eq!(0, game.player(Side::Overlord).actions);
+    assert_eq!(Duration::from_secs(5), remaining(&mut g, Side::Overlord));
+    assert!(!g.user.this_player.can_take_action());
+    assert!(g.opponent.this_player.can_take_action());
+}
+
+#[test]
+fn timeout_resigns() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    enable_turn_timer(&mut g, true);
+
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    g.database_mut().clock.advance(Duration::from_secs(70));
+    g.connect(g.user_id()).expect("Connection error");
+    assert!(matches!(
+        g.database_mut().game().data.phase,
+        GamePhase::GameOver { winner: Side::Champion }
+    ));
+}
+
+#[test]
+fn periodic_check_ends_turn() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    enable_turn_timer(&mut g, false);
+
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert!(maintenance::expire_timers(g.database_mut()).unwrap().is_empty());
+    assert_eq!(Side::Overlord, g.database_mut().game().data.turn.side);
+
+    g.database_mut().clock.advance(Duration::from_secs(70));
+    let responses = maintenance::expire_timers(g.database_mut()).unwrap();
+    let player_ids = responses.iter().map(|(player_id, _)| *player_id).collect::<Vec<_>>();
+    assert_eq!(vec![g.user_id(), g.opponent_id()], player_ids);
+    assert_eq!(Side::Champion, g.database_mut().game().data.turn.side);
+    assert!(maintenance::expire_timers(g.database_mut()).unwrap().is_empty());
+}
@@ -0,0 +1,75 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::primitives::Side;
+use protos::spelldawn::game_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{
+    CommandList, LevelUpRoomAction, PlayerName, RoomVisitType, VisitRoomCommand,
+};
+use test_utils::*;
+
+fn visit_room_commands(commands: &CommandList) -> Vec<VisitRoomCommand> {
+    commands
+        .commands
+        .iter()
+        .filter_map(|c| match &c.command {
+            Some(Command::VisitRoom(visit)) => Some(visit.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn initiate_raid_visits_room() {
+    let mut g = new_game(Side::Champion, Args::default());
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    let response = g.initiate_raid(ROOM_ID);
+
+    // The initiating player's client animates this optimistically
+    assert!(visit_room_commands(&response.command_list).is_empty());
+    let (_, opponent_commands) = response.opponent_response.expect("Opponent response");
+    assert_eq!(
+        vec![VisitRoomCommand {
+            initiator: PlayerName::Opponent.into(),
+            room_id: CLIENT_ROOM_ID.into(),
+            visit_type: RoomVisitType::InitiateRaid.into(),
+        }],
+        visit_room_commands(&opponent_commands)
+    );
+}
+
+#[test]
+fn level_up_room_visits_room() {
+    let mut g = new_game(Side::Overlord, Args { mana: 10, ..Args::default() });
+    g.play_from_hand(CardName::TestScheme31);
+    let response = g
+        .perform_action(
+            Action::LevelUpRoom(LevelUpRoomAction { room_id: CLIENT_ROOM_ID.into() }),
+            g.user_id(),
+        )
+        .expect("Error leveling up room");
+
+    assert!(visit_room_commands(&response.command_list).is_empty());
+    let (_, opponent_commands) = response.opponent_response.expect("Opponent response");
+    assert_eq!(
+        vec![VisitRoomCommand {
+            initiator: PlayerName::Opponent.into(),
+            room_id: CLIENT_ROOM_ID.into(),
+            visit_type: RoomVisitType::LevelUpRoom.into(),
+        }],
+        visit_room_commands(&opponent_commands)
+    );
+}
@@ -19,7 +19,7 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 
 use actions::legal_actions;
-use adapters;
+use adapters::{self, ServerCardId};
 use anyhow::Result;
 use data::card_name::CardName;
 use data::card_state::{CardPosition, CardState};
@@ -27,7 +27,7 @@ use data::game::GameState;
 use data::game_actions::UserAction;
 use data::player_name::PlayerId;
 use data::primitives::{
-    ActionCount, CardId, CardType, GameId, ManaValue, PointsValue, RoomId, Side,
+    ActionCount, CardId, CardType, GameId, Locale, ManaValue, PointsValue, RoomId, Side,
 };
 use protos::spelldawn::card_targeting::Targeting;
 use protos::spelldawn::game_action::Action;
@@ -40,9 +40,10 @@ use protos::spelldawn::{
     GameMessageType, GameObjectIdentifier, GameRequest, InitiateRaidAction, NoTargeting, Node,
     NodeType, ObjectPosition, ObjectPositionBrowser, ObjectPositionDiscardPile, ObjectPositionHand,
     ObjectPositionItem, ObjectPositionRevealedCards, ObjectPositionRoom, PlayCardAction,
-    PlayInRoom, PlayerName, PlayerView, RevealedCardView, RevealedCardsBrowserSize, RoomIdentifier,
+    PlayInRoom, PlayerName, PlayerScalarsUpdate, PlayerView, RevealedCardView,
+    RevealedCardsBrowserSize, RoomIdentifier,
 };
-use rules::dispatch;
+use rules::{dispatch, mutations};
 use server::requests;
 use server::requests::GameResponse;
 use with_error::WithError;
@@ -87,6 +88,12 @@ impl TestSession {
         self.database.game().id
     }
 
+    /// Provides direct access to the underlying database, e.g. to simulate
+    /// server-side operations which do not originate from a client.
+    pub fn database_mut(&mut self) -> &mut FakeDatabase {
+        &mut self.database
+    }
+
     pub fn user_id(&self) -> PlayerId {
         self.user.id
     }
@@ -111,7 +118,17 @@ impl TestSession {
     ///
     /// Returns the commands which would be sent to the client when connected.
     pub fn connect(&mut self, user_id: PlayerId) -> Result<CommandList> {
-        let result = requests::handle_connect(&mut self.database, user_id)?;
+        self.connect_with_locale(user_id, Locale::default())
+    }
+
+    /// Equivalent to [Self::connect] for a client which requests text in the
+    /// given [Locale].
+    pub fn connect_with_locale(
+        &mut self,
+        user_id: PlayerId,
+        locale: Locale,
+    ) -> Result<CommandList> {
+        let result = requests::handle_connect(&mut self.database, user_id, None, locale)?;
         let to_update = match () {
             _ if user_id == self.user.id => &mut self.user,
             _ if user_id == self.opponent.id => &mut self.opponent,
@@ -269,6 +286,18 @@ impl TestSession {
         );
     }
 
+    /// Removes the card with the given ID from the game via
+    /// [mutations::banish], bypassing the normal game rules.
+    pub fn banish(&mut self, card_id: CardIdentifier) {
+        let Ok(ServerCardId::CardId(card_id)) = adapters::server_card_id(card_id) else {
+            panic!("Expected a CardId");
+        };
+        mutations::banish(self.database.game_mut(), card_id).expect("Error banishing card");
+
+        self.connect(self.user.id).expect("User connection error");
+        self.connect(self.opponent.id).expect("Opponent connection error");
+    }
+
     /// Locate a button containing the provided `text` in the provided player's
     /// interface controls and invoke its registered action.
     pub fn click_on(&mut self, player_id: PlayerId, text: impl Into<String>) -> GameResponse {
@@ -515,7 +544,7 @@ impl ClientGameData {
                     &non_card.opponent_identity,
                 );
                 self.insert_position(discard_id(PlayerName::User), &non_card.user_discard);
-                self.insert_position(discard_id(PlayerName::Opponent), &non_card.opponent_deck);
+                self.insert_position(discard_id(PlayerName::Opponent), &non_card.opponent_discard);
             }
             Command::MoveGameObjects(move_objects) => {
                 for move_object in move_objects.moves {
@@ -533,6 +562,17 @@ impl ClientGameData {
                     card.card_position.clone().expect("position"),
                 );
             }
+            Command::UpdateCards(update_cards) => {
+                for card in &update_cards.cards {
+                    self.object_positions.insert(
+                        card_object_id(card.card_id),
+                        card.card_position.clone().expect("position"),
+                    );
+                }
+            }
+            Command::DestroyCard(destroy_card) => {
+                self.object_positions.remove(&card_object_id(destroy_card.card_id));
+            }
             _ => {}
         }
     }
@@ -567,6 +607,9 @@ pub struct ClientPlayer {
     actions: Option<ActionCount>,
     score: Option<PointsValue>,
     can_take_action: Option<bool>,
+    available_undo_count: Option<u32>,
+    card_back: Option<String>,
+    portrait_frame: Option<String>,
 }
 
 impl ClientPlayer {
@@ -578,6 +621,9 @@ impl ClientPlayer {
             actions: None,
             score: None,
             can_take_action: None,
+            available_undo_count: None,
+            card_back: None,
+            portrait_frame: None,
         }
     }
 
@@ -601,13 +647,56 @@ impl ClientPlayer {
         self.can_take_action.expect("can_take_action")
     }
 
+    pub fn available_undo_count(&self) -> u32 {
+        self.available_undo_count.expect("available_undo_count")
+    }
+
+    pub fn card_back(&self) -> String {
+        self.card_back.clone().expect("card_back")
+    }
+
+    pub fn portrait_frame(&self) -> String {
+        self.portrait_frame.clone().expect("portrait_frame")
+    }
+
     fn update(&mut self, command: Command) {
-        if let Command::UpdateGameView(update) = command {
-            self.update_with_player(if self.name == PlayerName::User {
-                update.game.unwrap().user
-            } else {
-                update.game.unwrap().opponent
-            });
+        match command {
+            Command::UpdateGameView(update) => {
+                self.update_with_player(if self.name == PlayerName::User {
+                    update.game.unwrap().user
+                } else {
+                    update.game.unwrap().opponent
+                });
+            }
+            Command::UpdatePlayerScalars(update) => {
+                self.update_with_scalars(if self.name == PlayerName::User {
+                    update.user
+                } else {
+                    update.opponent
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn update_with_scalars(&mut self, scalars: Option<PlayerScalarsUpdate>) {
+        if let Some(s) = scalars {
+            if let Some(mana) = s.mana {
+                self.mana = Some(mana.base_mana);
+                self.bonus_mana = Some(mana.bonus_mana);
+            }
+            if let Some(actions) = s.action_tracker {
+                self.actions = Some(actions.available_action_count);
+            }
+            if let Some(score) = s.score {
+                self.score = Some(score.score);
+            }
+            if let Some(can_take_action) = s.can_take_action {
+                self.can_take_action = Some(can_take_action);
+            }
+            if let Some(available_undo_count) = s.available_undo_count {
+                self.available_undo_count = Some(available_undo_count);
+            }
         }
     }
 
@@ -618,6 +707,11 @@ impl ClientPlayer {
             self.actions = Some(p.action_tracker.clone().expect("actions").available_action_count);
             self.score = Some(p.score.clone().expect("score").score);
             self.can_take_action = Some(p.can_take_action);
+            self.available_undo_count = Some(p.available_undo_count);
+            if let Some(info) = p.player_info {
+                self.card_back = info.card_back.map(|sprite| sprite.address);
+                self.portrait_frame = info.portrait_frame.map(|sprite| sprite.address);
+            }
         }
     }
 }
@@ -627,6 +721,7 @@ impl ClientPlayer {
 pub struct ClientInterface {
     main_controls: Option<Node>,
     card_anchors: Vec<CardAnchorNode>,
+    turn_banner: Option<Node>,
 }
 
 impl ClientInterface {
@@ -638,6 +733,10 @@ impl ClientInterface {
         self.main_controls.as_ref().expect("MainControls Node")
     }
 
+    pub fn turn_banner(&self) -> &Node {
+        self.turn_banner.as_ref().expect("TurnBanner Node")
+    }
+
     pub fn card_anchors(&self) -> &Vec<CardAnchorNode> {
         &self.card_anchors
     }
@@ -655,9 +754,11 @@ impl ClientInterface {
 
     fn update(&mut self, command: Command) {
         if let Command::UpdateGameView(update) = command {
-            let controls = update.game.as_ref().expect("game").main_controls.as_ref();
+            let game = update.game.as_ref().expect("game");
+            let controls = game.main_controls.as_ref();
             self.main_controls = controls.and_then(|c| c.node.clone());
             self.card_anchors = controls.map_or(vec![], |c| c.card_anchor_nodes.clone());
+            self.turn_banner = game.turn_banner.clone();
         }
     }
 }
@@ -813,6 +914,25 @@ impl ClientCards {
         }))
     }
 
+    /// Returns the titles of cards in a player's discard pile ordered by their
+    /// sorting key, i.e. with the most recently discarded card last.
+    pub fn discard_pile_by_recency(&self, player: PlayerName) -> Vec<String> {
+        let mut cards = self
+            .in_position(Position::DiscardPile(ObjectPositionDiscardPile { owner: player.into() }))
+            .collect::<Vec<_>>();
+        cards.sort_by(|a, b| a.partial_cmp(b).expect("sorting_key"));
+        cards
+            .into_iter()
+            .map(|c| c.title_option().unwrap_or_else(|| crate::HIDDEN_CARD.to_string()))
+            .collect()
+    }
+
+    /// Returns the title of the card displayed on top of a player's discard
+    /// pile, if any.
+    pub fn discard_pile_top(&self, player: PlayerName) -> Option<String> {
+        self.discard_pile_by_recency(player).pop()
+    }
+
     /// Returns left items in play
     pub fn left_items(&self) -> Vec<String> {
         self.names_in_position(Position::Item(ObjectPositionItem {
@@ -884,6 +1004,14 @@ impl ClientCards {
                 let card = create_token.card.as_ref().expect("card");
                 self.card_map.insert(card.card_id.expect("card_id"), ClientCard::new(card));
             }
+            Command::UpdateCards(update_cards) => {
+                for card in &update_cards.cards {
+                    self.card_map.get_mut(&card.card_id.expect("card_id")).unwrap().update(card);
+                }
+            }
+            Command::DestroyCard(destroy_card) => {
+                self.card_map.remove(&destroy_card.card_id.expect("card_id"));
+            }
             _ => {}
         }
     }
@@ -899,6 +1027,7 @@ pub struct ClientCard {
     is_face_up: Option<bool>,
     can_play: Option<bool>,
     valid_rooms: Option<Vec<RoomIdentifier>>,
+    on_release_position: Option<Position>,
     arena_icon: Option<String>,
     top_left_icon: Option<String>,
     top_right_icon: Option<String>,
@@ -944,6 +1073,12 @@ impl ClientCard {
         self.valid_rooms.as_ref().expect("valid_rooms").clone()
     }
 
+    /// Position to which this card should be previewed as moving when it is
+    /// released during a drag-to-play interaction.
+    pub fn on_release_position(&self) -> Position {
+        self.on_release_position.clone().expect("on_release_position")
+    }
+
     pub fn arena_icon(&self) -> String {
         self.arena_icon.clone().expect("arena_icon")
     }
@@ -1010,6 +1145,12 @@ impl ClientCard {
                 Some(valid_rooms.iter().map(|i| RoomIdentifier::from_i32(*i).unwrap()).collect())
         }
 
+        if let Some(position) =
+            revealed.on_release_position.as_ref().and_then(|p| p.position.clone())
+        {
+            self.on_release_position = Some(position);
+        }
+
         if let Some(title) = revealed.clone().title.map(|title| title.text) {
             self.title = Some(title);
         }
@@ -13,6 +13,10 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
 
 use anyhow::Result;
 use data::game::GameState;
@@ -21,13 +25,17 @@ use data::player_name::PlayerId;
 use data::primitives::GameId;
 use protos::spelldawn::player_identifier::PlayerIdentifierType;
 use protos::spelldawn::PlayerIdentifier;
+use server::clock::Clock;
 use server::database::Database;
 
+use crate::mock_clock::MockClock;
+
 #[derive(Clone, Debug, Default)]
 pub struct FakeDatabase {
     pub generated_game_id: Option<GameId>,
     pub game: Option<GameState>,
     pub players: HashMap<PlayerId, PlayerData>,
+    pub clock: MockClock,
 }
 
 impl FakeDatabase {
@@ -49,6 +57,10 @@ impl Database for FakeDatabase {
         Ok(matches!(&self.game, Some(game) if game.id == id))
     }
 
+    fn game_ids(&self) -> Result<Vec<GameId>> {
+        Ok(self.game.iter().map(|game| game.id).collect())
+    }
+
     fn game(&self, _id: GameId) -> Result<GameState> {
         Ok(self.game.clone().expect("game"))
     }
@@ -58,8 +70,19 @@ impl Database for FakeDatabase {
         Ok(())
     }
 
+    fn delete_game(&mut self, id: GameId) -> Result<()> {
+        if matches!(&self.game, Some(game) if game.id == id) {
+            self.game = None;
+        }
+        Ok(())
+    }
+
+    fn has_player(&self, player_id: PlayerId) -> Result<bool> {
+        Ok(self.players.contains_key(&player_id))
+    }
+
     fn player(&self, player_id: PlayerId) -> Result<Option<PlayerData>> {
-        Ok(Some(self.players[&player_id].clone()))
+        Ok(self.players.get(&player_id).cloned())
     }
 
     fn write_player(&mut self, player: &PlayerData) -> Result<()> {
@@ -70,11 +93,102 @@ impl Database for FakeDatabase {
     fn adapt_player_identifier(&mut self, identifier: &PlayerIdentifier) -> Result<PlayerId> {
         match identifier.player_identifier_type.clone().unwrap() {
             PlayerIdentifierType::ServerIdentifier(bytes) => {
-                Ok(PlayerId::Database(u64::from_be_bytes(bytes.try_into().unwrap())))
+                if let Ok(value) = bytes.as_slice().try_into() {
+                    Ok(PlayerId::Database(u64::from_be_bytes(value)))
+                } else {
+                    adapters::named_player_id(&bytes)
+                }
             }
             _ => panic!("Unsupported identifier type"),
         }
     }
+
+    fn clock(&self) -> &dyn Clock {
+        &self.clock
+    }
+}
+
+/// A [FakeDatabase] which can be used from several threads at once, for
+/// testing concurrent requests. Each call is applied atomically, but waits
+/// briefly first so that calls made by different threads are likely to
+/// interleave.
+#[derive(Clone, Debug, Default)]
+pub struct SharedDatabase {
+    database: Arc<Mutex<FakeDatabase>>,
+    games_written: Arc<AtomicUsize>,
+    clock: MockClock,
+}
+
+impl SharedDatabase {
+    pub fn new(database: FakeDatabase) -> Self {
+        Self {
+            clock: database.clock.clone(),
+            database: Arc::new(Mutex::new(database)),
+            ..Self::default()
+        }
+    }
+
+    /// Locks and returns the underlying [FakeDatabase].
+    pub fn lock(&self) -> MutexGuard<'_, FakeDatabase> {
+        self.database.lock().expect("Database lock poisoned")
+    }
+
+    fn lock_after_delay(&self) -> MutexGuard<'_, FakeDatabase> {
+        thread::sleep(Duration::from_millis(1));
+        self.lock()
+    }
+
+    /// Number of times [Database::write_game] has been called.
+    pub fn games_written(&self) -> usize {
+        self.games_written.load(Ordering::SeqCst)
+    }
+}
+
+impl Database for SharedDatabase {
+    fn generate_game_id(&self) -> Result<GameId> {
+        self.lock_after_delay().generate_game_id()
+    }
+
+    fn has_game(&self, id: GameId) -> Result<bool> {
+        self.lock_after_delay().has_game(id)
+    }
+
+    fn game_ids(&self) -> Result<Vec<GameId>> {
+        self.lock_after_delay().game_ids()
+    }
+
+    fn game(&self, id: GameId) -> Result<GameState> {
+        Database::game(&*self.lock_after_delay(), id)
+    }
+
+    fn write_game(&mut self, game: &GameState) -> Result<()> {
+        self.games_written.fetch_add(1, Ordering::SeqCst);
+        self.lock_after_delay().write_game(game)
+    }
+
+    fn delete_game(&mut self, id: GameId) -> Result<()> {
+        self.lock_after_delay().delete_game(id)
+    }
+
+    fn has_player(&self, player_id: PlayerId) -> Result<bool> {
+        self.lock_after_delay().has_player(player_id)
+    }
+
+    fn player(&self, player_id: PlayerId) -> Result<Option<PlayerData>> {
+        self.lock_after_delay().player(player_id)
+    }
+
+    fn write_player(&mut self, player: &PlayerData) -> Result<()> {
+        self.lock_after_delay().write_player(player)
+    }
+
+    fn adapt_player_identifier(&mut self, identifier: &PlayerIdentifier) -> Result<PlayerId> {
+        self.lock_after_delay().adapt_player_identifier(identifier)
+    }
+
+    fn clock(&self) -> &dyn Clock {
+        &self.clock
+    }
 }
 
 pub fn to_player_identifier(id: PlayerId) -> PlayerIdentifier {
@@ -19,6 +19,7 @@
 
 pub mod client;
 pub mod fake_database;
+pub mod mock_clock;
 pub mod summarize;
 pub mod test_games;
 
@@ -35,10 +36,10 @@ use data::card_name::CardName;
 use data::card_state::{CardPosition, CardPositionKind};
 use data::deck::Deck;
 use data::game::{GameConfiguration, GamePhase, GameState, InternalRaidPhase, RaidData, TurnData};
-use data::player_data::{CurrentGame, PlayerData};
+use data::player_data::{CurrentGame, PlayerCosmetics, PlayerData};
 use data::player_name::PlayerId;
 use data::primitives::{
-    ActionCount, CardId, GameId, Lineage, ManaValue, PointsValue, RaidId, RoomId, Side,
+    ActionCount, CardId, GameId, Lineage, Locale, ManaValue, PointsValue, RaidId, RoomId, Side,
 };
 use maplit::hashmap;
 use prost::Message;
@@ -51,6 +52,7 @@ use rules::{dispatch, mana};
 
 use crate::client::TestSession;
 use crate::fake_database::FakeDatabase;
+use crate::mock_clock::MockClock;
 
 pub static NEXT_ID: AtomicU64 = AtomicU64::new(1_000_000);
 /// The title returned for hidden cards
@@ -95,7 +97,7 @@ pub fn new_game(user_side: Side, args: Args) -> TestSession {
         game_id,
         overlord_deck,
         champion_deck,
-        GameConfiguration { deterministic: true, ..GameConfiguration::default() },
+        GameConfiguration { deterministic: true, seed: args.seed, ..GameConfiguration::default() },
     );
     dispatch::populate_delegate_cache(&mut game);
 
@@ -121,7 +123,10 @@ pub fn new_game(user_side: Side, args: Args) -> TestSession {
             encounter: None,
             accessed: vec![],
             jump_request: None,
-        })
+            queued_rooms: vec![],
+            current_room: None,
+        });
+        game.data.next_raid_id = RAID_ID.0 + 1;
     }
 
     let database = FakeDatabase {
@@ -132,15 +137,26 @@ pub fn new_game(user_side: Side, args: Args) -> TestSession {
                 id: overlord_user,
                 current_game: Some(CurrentGame::Playing(game_id)),
                 decks: vec![],
-                collection: hashmap! {}
+                collection: hashmap! {},
+                cosmetics: PlayerCosmetics::default(),
+                sideboard: hashmap! {},
+                current_match: None,
+                locale: Locale::default(),
+                pending_invitations: vec![],
             },
             champion_user => PlayerData {
                 id: champion_user,
                 current_game: Some(CurrentGame::Playing(game_id)),
                 decks: vec![],
-                collection: hashmap! {}
+                collection: hashmap! {},
+                cosmetics: PlayerCosmetics::default(),
+                sideboard: hashmap! {},
+                current_match: None,
+                locale: Locale::default(),
+                pending_invitations: vec![],
             }
         },
+        clock: MockClock::default(),
     };
 
     let mut session = TestSession::new(database, user_id, opponent_id);
@@ -212,6 +228,9 @@ pub struct Args {
     /// If false, will not attempt to automatically connect to this game.
     /// Defaults to true.
     pub connect: bool,
+    /// Seed for the game's random number generator. Defaults to
+    /// [data::game::DEFAULT_SEED].
+    pub seed: Option<u64>,
 }
 
 impl Default for Args {
@@ -231,6 +250,7 @@ impl Default for Args {
             opponent_discard: None,
             add_raid: false,
             connect: true,
+            seed: None,
         }
     }
 }
@@ -253,8 +273,8 @@ fn set_discard_pile(game: &mut GameState, side: Side, discard: Option<CardName>)
         let target_id = game
             .cards(side)
             .iter()
-            .filter(|c| c.position().kind() == CardPositionKind::DeckUnknown)
-            .last() // Take last to avoid overwriting deck top
+            // Take last to avoid overwriting deck top
+            .rfind(|c| c.position().kind() == CardPositionKind::DeckUnknown)
             .expect("No cards in deck")
             .id;
         client::overwrite_card(game, target_id, discard);
@@ -412,6 +432,27 @@ pub fn assert_error<T: Debug, E: Debug>(result: Result<T, E>) {
     assert!(result.is_err(), "Expected an error, got {:?}", result)
 }
 
+/// Runs `test` once for each [Side], so that a single test body can verify
+/// behavior from both the Overlord and Champion perspective.
+pub fn for_each_side(test: impl Fn(Side)) {
+    for side in [Side::Overlord, Side::Champion] {
+        test(side);
+    }
+}
+
+/// Asserts that the user and opponent clients in this session agree on the
+/// mana, action points, and score of both players.
+pub fn assert_player_views_match(session: &TestSession) {
+    for (this, other) in [
+        (session.me(), &session.opponent.other_player),
+        (session.you(), &session.user.other_player),
+    ] {
+        assert_eq!(this.mana(), other.mana(), "Mana mismatch for {:?}", this);
+        assert_eq!(this.actions(), other.actions(), "Actions mismatch for {:?}", this);
+        assert_eq!(this.score(), other.score(), "Score mismatch for {:?}", this);
+    }
+}
+
 /// Creates a [CardIdentifier] representing the ability with the provided
 /// `index` of this `card_id`.
 pub fn ability_id(card_id: CardIdentifier, ability: u32) -> CardIdentifier {
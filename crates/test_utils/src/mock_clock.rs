@@ -0,0 +1,38 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test implementation of [Clock] which only advances when explicitly
+//! requested.
+
+use std::time::Duration;
+
+use server::clock::Clock;
+
+#[derive(Clone, Debug, Default)]
+pub struct MockClock {
+    now: Duration,
+}
+
+impl MockClock {
+    /// Moves the current time forward by `duration`.
+    pub fn advance(&mut self, duration: Duration) {
+        self.now += duration;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        self.now
+    }
+}
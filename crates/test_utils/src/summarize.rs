@@ -25,16 +25,17 @@ use protos::spelldawn::play_effect_position::EffectPosition;
 use protos::spelldawn::{
     node_type, ActionTrackerView, AnchorCorner, ArrowTargetRoom, AudioClipAddress, CardAnchor,
     CardAnchorNode, CardCreationAnimation, CardIcon, CardIcons, CardIdentifier, CardTargeting,
-    CardTitle, CardView, CommandList, CreateTokenCardCommand, DelayCommand,
+    CardTitle, CardView, CommandList, CreateTokenCardCommand, DelayCommand, DestroyCardCommand,
     DisplayGameMessageCommand, DisplayRewardsCommand, EffectAddress, FireProjectileCommand,
     GameCommand, GameMessageType, GameObjectIdentifier, GameObjectMove, GameObjectPositions,
     GameView, InterfaceMainControls, InterfacePanel, InterfacePanelAddress, LoadSceneCommand,
     ManaView, MoveGameObjectsCommand, MusicState, NoTargeting, Node, NodeType, ObjectPosition,
     PlayEffectCommand, PlayEffectPosition, PlayInRoom, PlaySoundCommand, PlayerInfo, PlayerName,
-    PlayerSide, PlayerView, ProjectileAddress, RevealedCardView, RoomIdentifier, RoomVisitType,
-    RulesText, RunInParallelCommand, SceneLoadMode, ScoreView, SetGameObjectsEnabledCommand,
-    SetMusicCommand, SpriteAddress, TimeValue, TogglePanelCommand, UpdateGameViewCommand,
-    UpdatePanelsCommand, VisitRoomCommand,
+    PlayerScalarsUpdate, PlayerSide, PlayerView, ProjectileAddress, RevealedCardView,
+    RoomIdentifier, RoomVisitType, RulesText, RunInParallelCommand, SceneLoadMode, ScoreView,
+    SetGameObjectsEnabledCommand, SetMusicCommand, SpriteAddress, TimeValue, TogglePanelCommand,
+    TurnTimerView, UpdateCardsCommand, UpdateGameViewCommand, UpdatePanelsCommand,
+    UpdatePlayerScalarsCommand, VisitRoomCommand,
 };
 use server::requests::GameResponse;
 
@@ -148,6 +149,12 @@ impl Summarize for u32 {
     }
 }
 
+impl Summarize for u64 {
+    fn summarize(self, summary: &mut Summary) {
+        summary.primitive(self)
+    }
+}
+
 impl Summarize for String {
     fn summarize(self, summary: &mut Summary) {
         summary.primitive(self)
@@ -272,6 +279,9 @@ impl Summarize for Command {
             Self::DisplayRewards(v) => summary.child_node("DisplayRewards", v),
             Self::LoadScene(v) => summary.child_node("LoadScene", v),
             Self::CreateTokenCard(v) => summary.child_node("CreateTokenCard", v),
+            Self::UpdatePlayerScalars(v) => summary.child_node("UpdatePlayerScalars", v),
+            Self::UpdateCards(v) => summary.child_node("UpdateCards", v),
+            Self::DestroyCard(v) => summary.child_node("DestroyCard", v),
         }
     }
 }
@@ -369,6 +379,8 @@ impl Summarize for PlayerView {
         summary.child("action_tracker", self.action_tracker);
         summary.child("score", self.score);
         summary.child_node("can_take_action", self.can_take_action);
+        summary.child("turn_timer", self.turn_timer);
+        summary.child_node("available_undo_count", self.available_undo_count);
     }
 }
 
@@ -403,6 +415,13 @@ impl Summarize for ActionTrackerView {
     }
 }
 
+impl Summarize for TurnTimerView {
+    fn summarize(self, summary: &mut Summary) {
+        summary.child_node("remaining_milliseconds", self.remaining_milliseconds);
+        summary.child_node("running", self.running);
+    }
+}
+
 impl Summarize for ScoreView {
     fn summarize(self, summary: &mut Summary) {
         summary.primitive(self.score);
@@ -657,3 +676,32 @@ impl Summarize for CreateTokenCardCommand {
         summary.child("card", self.card);
     }
 }
+
+impl Summarize for DestroyCardCommand {
+    fn summarize(self, summary: &mut Summary) {
+        summary.child("card_id", self.card_id);
+    }
+}
+
+impl Summarize for UpdatePlayerScalarsCommand {
+    fn summarize(self, summary: &mut Summary) {
+        summary.child("user", self.user);
+        summary.child("opponent", self.opponent);
+    }
+}
+
+impl Summarize for UpdateCardsCommand {
+    fn summarize(self, summary: &mut Summary) {
+        summary.children("cards", self.cards);
+    }
+}
+
+impl Summarize for PlayerScalarsUpdate {
+    fn summarize(self, summary: &mut Summary) {
+        summary.child("mana", self.mana);
+        summary.child("action_tracker", self.action_tracker);
+        summary.child("score", self.score);
+        summary.child("can_take_action", self.can_take_action);
+        summary.child("available_undo_count", self.available_undo_count);
+    }
+}